@@ -0,0 +1,160 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::validator::EcmaRegexValidator;
+
+/// Syntax that is only valid because of the web compatibility grammar of Annex B.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum AnnexB {
+    /// An octal escape like `\01` or `\7`, or a backreference to a group that does not exist.
+    LegacyOctalEscape,
+    /// `\8` or `\9` without as many groups, which matches the digit itself.
+    NonOctalDecimalEscape,
+    /// An escaped identifier character that is no escape sequence, like `\a` or `\é`.
+    IdentityEscape,
+    /// `\k` in a pattern without named groups, which matches `k`.
+    KEscape,
+    /// A `]`, `{` or `}` that matches itself.
+    LoneBracket,
+    /// A quantified lookahead like `(?=a)*`.
+    QuantifiedLookahead,
+    /// `\c` without a control letter, which matches the backslash.
+    LoneControlEscape,
+    /// `\c` with a digit or `_` in a character class, like `[\c1]`.
+    ClassControlDigit,
+    /// A character class escape as the bound of a range, like `[\w-z]`.
+    ClassEscapeInRange,
+}
+
+impl fmt::Display for AnnexB {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            AnnexB::LegacyOctalEscape => "legacy octal escape",
+            AnnexB::NonOctalDecimalEscape => "\\8 or \\9 escape",
+            AnnexB::IdentityEscape => "unnecessary escape",
+            AnnexB::KEscape => "\\k without named groups",
+            AnnexB::LoneBracket => "unescaped bracket",
+            AnnexB::QuantifiedLookahead => "quantified lookahead",
+            AnnexB::LoneControlEscape => "\\c without a control letter",
+            AnnexB::ClassControlDigit => "\\c with a digit or _",
+            AnnexB::ClassEscapeInRange => "character class escape in a range",
+        })
+    }
+}
+
+/// A place where a pattern relies on Annex B.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct AnnexBUsage {
+    pub kind: AnnexB,
+    /// The position in the pattern, counted in UTF-16 code units.
+    pub span: Range<usize>,
+}
+
+impl EcmaRegexValidator {
+    /// Validates the regular expression literal `/source/flags` and lists every place where
+    /// it relies on the web compatibility syntax of Annex B, in the order they appear.
+    /// Patterns with the `u` flag never rely on Annex B, so the list is empty for them.
+    pub fn find_annex_b(&mut self, source: &str, flags: &str) -> Result<Vec<AnnexBUsage>, String> {
+        self.validate_flags(flags)?;
+        self.annex_b = Some(Vec::new());
+        let result = self.validate_pattern(source, flags.contains('u'));
+        let mut usages = self.annex_b.take().unwrap_or_default();
+        result?;
+        usages.sort_by_key(|usage| (usage.span.start, usage.span.end));
+        return Ok(usages);
+    }
+
+    /// Records that the characters from `start` to the current index rely on Annex B,
+    /// if `find_annex_b` asked for it.
+    pub(crate) fn record_annex_b(&mut self, kind: AnnexB, start: usize) {
+        let end = self.index();
+        if let Some(usages) = &mut self.annex_b {
+            usages.push(AnnexBUsage {
+                kind,
+                span: start..end,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EcmaVersion;
+
+    fn kinds(pattern: &str) -> Vec<(AnnexB, Range<usize>)> {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let usages = validator.find_annex_b(pattern, "").unwrap();
+        usages.into_iter().map(|u| (u.kind, u.span)).collect()
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(
+            kinds("\\01\\7"),
+            vec![
+                (AnnexB::LegacyOctalEscape, 0..3),
+                (AnnexB::LegacyOctalEscape, 3..5),
+            ]
+        );
+        assert_eq!(kinds("(a)\\2"), vec![(AnnexB::LegacyOctalEscape, 3..5)]);
+        assert_eq!(kinds("(a)\\1"), vec![]);
+        assert_eq!(
+            kinds("\\8\\9"),
+            vec![
+                (AnnexB::NonOctalDecimalEscape, 0..2),
+                (AnnexB::NonOctalDecimalEscape, 2..4),
+            ]
+        );
+        assert_eq!(kinds("\\a\\.\\/"), vec![(AnnexB::IdentityEscape, 0..2)]);
+        assert_eq!(kinds("\\-\\:\\,"), vec![]);
+        assert_eq!(kinds("\\\u{e9}"), vec![(AnnexB::IdentityEscape, 0..2)]);
+        assert_eq!(kinds("\\k<a>"), vec![(AnnexB::KEscape, 0..2)]);
+        assert_eq!(kinds("\\c"), vec![(AnnexB::LoneControlEscape, 0..1)]);
+        assert_eq!(kinds("\\cA"), vec![]);
+    }
+
+    #[test]
+    fn brackets_and_quantifiers() {
+        assert_eq!(
+            kinds("]{}"),
+            vec![
+                (AnnexB::LoneBracket, 0..1),
+                (AnnexB::LoneBracket, 1..2),
+                (AnnexB::LoneBracket, 2..3),
+            ]
+        );
+        assert_eq!(kinds("a{1}"), vec![]);
+        assert_eq!(kinds("(?=a)+b"), vec![(AnnexB::QuantifiedLookahead, 0..6)]);
+        assert_eq!(kinds("(?=a)b"), vec![]);
+    }
+
+    #[test]
+    fn classes() {
+        assert_eq!(
+            kinds("[\\c1\\c]"),
+            vec![
+                (AnnexB::ClassControlDigit, 1..4),
+                (AnnexB::LoneControlEscape, 4..5),
+            ]
+        );
+        assert_eq!(kinds("[\\w-z]"), vec![(AnnexB::ClassEscapeInRange, 1..5)]);
+        assert_eq!(kinds("[\\-]"), vec![]);
+        assert_eq!(kinds("[\\a]"), vec![(AnnexB::IdentityEscape, 1..3)]);
+        assert_eq!(kinds("[a-z\\d]"), vec![]);
+    }
+
+    #[test]
+    fn unicode_mode() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        assert_eq!(validator.find_annex_b("\\.[\\-]", "u"), Ok(vec![]));
+        assert!(validator.find_annex_b("\\a", "u").is_err());
+        assert!(validator.find_annex_b("a", "uu").is_err());
+        assert_eq!(validator.validate_pattern("\\a", false), Ok(()));
+    }
+}
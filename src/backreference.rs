@@ -0,0 +1,264 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::tree::{GroupRef, Node};
+use crate::validator::EcmaRegexValidator;
+
+/// Why a backreference can never refer to a captured value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum BackreferenceProblem {
+    /// The backreference is inside the group it refers to, like `(a\1)`.
+    Nested,
+    /// The group is in another alternative, like `(a)|\1`.
+    Disjoint,
+    /// The group comes after the backreference, like `\1(a)`.
+    Forward,
+    /// In a lookbehind, which matches from right to left, the group comes before the
+    /// backreference, like `(?<=(a)\1)`.
+    Backward,
+    /// The group is in a negative lookaround that does not contain the backreference, like
+    /// `(?!(a))\1`. Its captures are discarded when the lookaround succeeds.
+    NegativeLookaround,
+}
+
+impl fmt::Display for BackreferenceProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BackreferenceProblem::Nested => "backreference is inside the group it refers to",
+            BackreferenceProblem::Disjoint => "group is in another alternative",
+            BackreferenceProblem::Forward => "group comes after the backreference",
+            BackreferenceProblem::Backward => {
+                "group is matched after the backreference in a lookbehind"
+            }
+            BackreferenceProblem::NegativeLookaround => "group is in a negative lookaround",
+        })
+    }
+}
+
+/// A backreference that always matches the empty string.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct UselessBackreference {
+    pub problem: BackreferenceProblem,
+    /// The position of the backreference, counted in UTF-16 code units.
+    pub span: Range<usize>,
+    /// The position of the group it refers to.
+    pub group: Range<usize>,
+}
+
+/// A step from a node to one of its children.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Step {
+    Alternative(usize),
+    Element(usize),
+    Body,
+    Lookaround { behind: bool, negated: bool },
+}
+
+struct Group<'a> {
+    index: u32,
+    name: Option<&'a str>,
+    span: Range<usize>,
+    path: Vec<Step>,
+}
+
+struct Reference<'a> {
+    group: &'a GroupRef,
+    span: Range<usize>,
+    path: Vec<Step>,
+}
+
+#[derive(Default)]
+struct Collector<'a> {
+    path: Vec<Step>,
+    groups: Vec<Group<'a>>,
+    references: Vec<Reference<'a>>,
+}
+
+impl<'a> Collector<'a> {
+    fn visit(&mut self, node: &'a Node) {
+        match node {
+            Node::Alternation(nodes) => self.visit_children(nodes, Step::Alternative),
+            Node::Sequence(nodes) => self.visit_children(nodes, Step::Element),
+            Node::Quantified { body, .. } => self.visit_child(body, Step::Body),
+            Node::Lookaround {
+                body,
+                behind,
+                negated,
+                ..
+            } => self.visit_child(
+                body,
+                Step::Lookaround {
+                    behind: *behind,
+                    negated: *negated,
+                },
+            ),
+            Node::Group {
+                body,
+                index,
+                name,
+                span,
+            } => {
+                if let Some(index) = index {
+                    self.groups.push(Group {
+                        index: *index,
+                        name: name.as_deref(),
+                        span: span.clone(),
+                        path: self.path.clone(),
+                    });
+                }
+                self.visit_child(body, Step::Body);
+            }
+            Node::Backreference { group, span } => self.references.push(Reference {
+                group,
+                span: span.clone(),
+                path: self.path.clone(),
+            }),
+            _ => {}
+        }
+    }
+
+    fn visit_child(&mut self, node: &'a Node, step: Step) {
+        self.path.push(step);
+        self.visit(node);
+        self.path.pop();
+    }
+
+    fn visit_children(&mut self, nodes: &'a [Node], step: fn(usize) -> Step) {
+        for (i, node) in nodes.iter().enumerate() {
+            self.visit_child(node, step(i));
+        }
+    }
+}
+
+/// Returns why `reference` can never refer to a value captured by `group`, if it can not.
+fn problem(reference: &Reference, group: &Group) -> Option<BackreferenceProblem> {
+    let common = reference
+        .path
+        .iter()
+        .zip(&group.path)
+        .take_while(|(a, b)| a == b)
+        .count();
+    if common == group.path.len() {
+        return Some(BackreferenceProblem::Nested);
+    }
+    let (at_reference, at_group) = (reference.path.get(common)?, &group.path[common]);
+    if let (Step::Alternative(_), Step::Alternative(_)) = (at_reference, at_group) {
+        return Some(BackreferenceProblem::Disjoint);
+    }
+    let in_negative_lookaround = group.path[common..]
+        .iter()
+        .any(|step| matches!(step, Step::Lookaround { negated: true, .. }));
+    if in_negative_lookaround {
+        return Some(BackreferenceProblem::NegativeLookaround);
+    }
+    // The innermost lookaround around both decides the direction of matching.
+    let backward = reference.path[..common]
+        .iter()
+        .rev()
+        .find_map(|step| match step {
+            Step::Lookaround { behind, .. } => Some(*behind),
+            _ => None,
+        });
+    match (at_reference, at_group, backward.unwrap_or(false)) {
+        (Step::Element(r), Step::Element(g), false) if r < g => Some(BackreferenceProblem::Forward),
+        (Step::Element(r), Step::Element(g), true) if r > g => Some(BackreferenceProblem::Backward),
+        _ => None,
+    }
+}
+
+impl EcmaRegexValidator {
+    /// Validates the regular expression literal `/source/flags` and finds the backreferences
+    /// that can never refer to a captured value and so always match the empty string, in the
+    /// order they appear.
+    pub fn find_useless_backreferences(
+        &mut self,
+        source: &str,
+        flags: &str,
+    ) -> Result<Vec<UselessBackreference>, String> {
+        let tree = self.parse_tree(source, flags)?;
+        let mut collector = Collector::default();
+        collector.visit(&tree);
+
+        let mut useless = Vec::new();
+        for reference in &collector.references {
+            let group = collector.groups.iter().find(|group| match reference.group {
+                GroupRef::Index(index) => group.index == *index,
+                GroupRef::Name(name) => group.name == Some(name.as_str()),
+            });
+            if let Some(group) = group {
+                if let Some(problem) = problem(reference, group) {
+                    useless.push(UselessBackreference {
+                        problem,
+                        span: reference.span.clone(),
+                        group: group.span.clone(),
+                    });
+                }
+            }
+        }
+        return Ok(useless);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EcmaVersion;
+
+    fn problems(source: &str) -> Vec<BackreferenceProblem> {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let useless = validator.find_useless_backreferences(source, "").unwrap();
+        useless.into_iter().map(|u| u.problem).collect()
+    }
+
+    #[test]
+    fn useless() {
+        use BackreferenceProblem::*;
+        assert_eq!(problems("(a\\1)"), vec![Nested]);
+        assert_eq!(problems("(a)|\\1"), vec![Disjoint]);
+        assert_eq!(problems("(?:(a)|b\\1)c"), vec![Disjoint]);
+        assert_eq!(problems("\\1(a)"), vec![Forward]);
+        assert_eq!(problems("\\k<x>(?<x>a)"), vec![Forward]);
+        assert_eq!(problems("\\k<a>(?<\\u0061>a)"), vec![Forward]);
+        assert_eq!(problems("(?<=(a)\\1)"), vec![Backward]);
+        assert_eq!(problems("(?!(a))\\1"), vec![NegativeLookaround]);
+        assert_eq!(problems("(?=\\1)(a)"), vec![Forward]);
+    }
+
+    #[test]
+    fn useful() {
+        assert_eq!(problems("(a)\\1"), vec![]);
+        assert_eq!(problems("(?<x>a)\\k<x>"), vec![]);
+        assert_eq!(problems("(?<=\\1(a))"), vec![]);
+        // The lookbehind matches the group first, then looks ahead.
+        assert_eq!(problems("(?<=(?=\\1)(a))"), vec![]);
+        assert_eq!(problems("(?=(a))\\1"), vec![]);
+        assert_eq!(problems("(?!(a)\\1)"), vec![]);
+        assert_eq!(problems("((a)|b)\\2"), vec![]);
+        assert_eq!(problems("(a)*\\1"), vec![]);
+        assert_eq!(problems("\\1"), vec![]);
+    }
+
+    #[test]
+    fn spans() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let useless = validator
+            .find_useless_backreferences("x\\1(a)", "u")
+            .unwrap();
+        assert_eq!(
+            useless,
+            vec![UselessBackreference {
+                problem: BackreferenceProblem::Forward,
+                span: 1..3,
+                group: 3..6,
+            }]
+        );
+        assert!(validator
+            .find_useless_backreferences("\\2(a)", "u")
+            .is_err());
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::validator::EcmaRegexValidator;
+
+impl EcmaRegexValidator {
+    /// Validates a batch of regular expression literals, given as `(pattern, flags)` pairs.
+    /// The pattern is validated in unicode mode if the flags contain `u`.
+    ///
+    /// All literals are validated with this validator, so its internal buffers are
+    /// reused instead of being allocated anew for every literal.
+    /// Returns one result per literal, in the order of the input.
+    pub fn validate_many<'a, I>(&mut self, regexes: I) -> Vec<Result<(), String>>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        regexes
+            .into_iter()
+            .map(|(pattern, flags)| self.validate_literal(pattern, flags))
+            .collect()
+    }
+
+    /// Validates a batch of regular expression literals in parallel.
+    /// Behaves like `validate_many`, but spreads the literals over the rayon thread pool.
+    /// Each rayon job creates one validator with the `EcmaVersion` and `UnicodeVersion` of
+    /// this one and reuses it for all literals it processes.
+    #[cfg(feature = "rayon")]
+    pub fn par_validate_many(&self, regexes: &[(&str, &str)]) -> Vec<Result<(), String>> {
+        let ecma_version = self.ecma_version();
+        let unicode_version = self.unicode_version();
+        regexes
+            .par_iter()
+            .map_init(
+                || {
+                    let mut validator = EcmaRegexValidator::new(ecma_version);
+                    validator.set_unicode_version(unicode_version);
+                    validator
+                },
+                |validator, (pattern, flags)| validator.validate_literal(pattern, flags),
+            )
+            .collect()
+    }
+
+    /// Validates one literal of a batch. Nothing of the previous literal has to be reset
+    /// here: `validate_pattern` rewinds the reader and `consume_pattern` clears the group
+    /// names, backreference names and recorded findings before it parses.
+    pub(crate) fn validate_literal(&mut self, pattern: &str, flags: &str) -> Result<(), String> {
+        self.validate_flags(flags)?;
+        self.validate_pattern(pattern, flags.contains('u'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EcmaRegexValidator, EcmaVersion};
+
+    const REGEXES: [(&str, &str); 6] = [
+        ("\\s+", "g"),
+        ("^\\d+$", ""),
+        ("(?<year>\\d{4})-\\k<year>", "u"),
+        ("\\p{Script=Greek}", "u"),
+        ("a**", ""),
+        ("foo", "gg"),
+    ];
+
+    #[test]
+    fn validate_many_test() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let property = if cfg!(feature = "unicode-properties") {
+            Ok(())
+        } else {
+            Err("Property escapes are not supported in this build".to_string())
+        };
+        assert_eq!(
+            validator.validate_many(REGEXES.iter().cloned()),
+            vec![
+                Ok(()),
+                Ok(()),
+                Ok(()),
+                property,
+                Err("Nothing to repeat".to_string()),
+                Err("Duplicated flag g".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_many_matches_single_validation() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2015);
+        let results = validator.validate_many(REGEXES.iter().cloned());
+        for ((pattern, flags), result) in REGEXES.iter().zip(results) {
+            let mut single = EcmaRegexValidator::new(EcmaVersion::ES2015);
+            let expected = single
+                .validate_flags(flags)
+                .and_then(|_| single.validate_pattern(pattern, flags.contains('u')));
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_validate_many_test() {
+        let validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let regexes: Vec<(&str, &str)> = REGEXES.iter().cycle().take(600).cloned().collect();
+        let mut sequential = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        assert_eq!(
+            validator.par_validate_many(&regexes),
+            sequential.validate_many(regexes.iter().cloned())
+        );
+    }
+}
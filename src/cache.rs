@@ -0,0 +1,303 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+use crate::unicode::UnicodeVersion;
+use crate::validator::{EcmaRegexValidator, EcmaVersion};
+
+const NIL: usize = usize::MAX;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct CacheKey {
+    ecma_version: EcmaVersion,
+    unicode_version: UnicodeVersion,
+    flags: String,
+    pattern: String,
+}
+
+impl CacheKey {
+    fn matches(&self, versions: (EcmaVersion, UnicodeVersion), pattern: &str, flags: &str) -> bool {
+        (self.ecma_version, self.unicode_version) == versions
+            && self.flags == flags
+            && self.pattern == pattern
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    key: CacheKey,
+    hash: u64,
+    result: Result<(), String>,
+    prev: usize,
+    next: usize,
+}
+
+/// Hit and miss counters of a `ValidationCache`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+}
+
+impl CacheStats {
+    /// Returns the fraction of lookups that were answered from the cache.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            return 0.0;
+        }
+        self.hits as f64 / lookups as f64
+    }
+}
+
+/// A bounded cache of validation results, evicting the least recently used entry when full.
+///
+/// Entries are keyed by pattern, flags and the `EcmaVersion` and `UnicodeVersion` of the
+/// validator, so one cache can be shared between validators for different versions.
+/// Lookups hash the borrowed pattern and flags, so a hit does not allocate.
+#[derive(Debug)]
+pub struct ValidationCache {
+    capacity: usize,
+    hasher: RandomState,
+    /// The entries with each key hash. There is more than one only if hashes collide.
+    map: HashMap<u64, Vec<usize>>,
+    entries: Vec<Entry>,
+    head: usize,
+    tail: usize,
+    stats: CacheStats,
+}
+
+impl ValidationCache {
+    /// Creates a cache holding at most `capacity` validation results.
+    pub fn new(capacity: usize) -> Self {
+        ValidationCache {
+            capacity,
+            hasher: RandomState::new(),
+            map: HashMap::with_capacity(capacity),
+            entries: Vec::with_capacity(capacity),
+            head: NIL,
+            tail: NIL,
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// Validates flags and pattern of a regular expression literal, like
+    /// `EcmaRegexValidator::validate_many` does for a single literal.
+    /// Returns the cached result if the same literal was validated for the same
+    /// `EcmaVersion` and `UnicodeVersion` before, and runs `validator` otherwise.
+    pub fn validate(
+        &mut self,
+        validator: &mut EcmaRegexValidator,
+        pattern: &str,
+        flags: &str,
+    ) -> Result<(), String> {
+        let versions = (validator.ecma_version(), validator.unicode_version());
+        let hash = self.hasher.hash_one((versions, flags, pattern));
+
+        let found = self.map.get(&hash).and_then(|slots| {
+            slots
+                .iter()
+                .copied()
+                .find(|&i| self.entries[i].key.matches(versions, pattern, flags))
+        });
+        if let Some(i) = found {
+            self.stats.hits += 1;
+            self.unlink(i);
+            self.push_front(i);
+            return self.entries[i].result.clone();
+        }
+
+        self.stats.misses += 1;
+        let result = validator.validate_literal(pattern, flags);
+        if self.capacity > 0 {
+            let key = CacheKey {
+                ecma_version: versions.0,
+                unicode_version: versions.1,
+                flags: flags.to_string(),
+                pattern: pattern.to_string(),
+            };
+            self.insert(key, hash, result.clone());
+        }
+        result
+    }
+
+    /// Returns the hit and miss counters since creation or the last call to `clear`.
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Returns the number of cached results.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no results are cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the maximum number of cached results.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Removes all cached results and resets the statistics.
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.entries.clear();
+        self.head = NIL;
+        self.tail = NIL;
+        self.stats = CacheStats::default();
+    }
+
+    fn insert(&mut self, key: CacheKey, hash: u64, result: Result<(), String>) {
+        let i = if self.entries.len() < self.capacity {
+            self.entries.push(Entry {
+                key,
+                hash,
+                result,
+                prev: NIL,
+                next: NIL,
+            });
+            self.entries.len() - 1
+        } else {
+            // reuse the slot of the least recently used entry
+            let i = self.tail;
+            self.unlink(i);
+            let evicted = self.entries[i].hash;
+            if let Some(slots) = self.map.get_mut(&evicted) {
+                slots.retain(|&slot| slot != i);
+                if slots.is_empty() {
+                    self.map.remove(&evicted);
+                }
+            }
+            self.stats.evictions += 1;
+            self.entries[i].key = key;
+            self.entries[i].hash = hash;
+            self.entries[i].result = result;
+            i
+        };
+        self.map.entry(hash).or_default().push(i);
+        self.push_front(i);
+    }
+
+    fn unlink(&mut self, i: usize) {
+        let (prev, next) = (self.entries[i].prev, self.entries[i].next);
+        if prev == NIL {
+            self.head = next;
+        } else {
+            self.entries[prev].next = next;
+        }
+        if next == NIL {
+            self.tail = prev;
+        } else {
+            self.entries[next].prev = prev;
+        }
+    }
+
+    fn push_front(&mut self, i: usize) {
+        self.entries[i].prev = NIL;
+        self.entries[i].next = self.head;
+        if self.head != NIL {
+            self.entries[self.head].prev = i;
+        }
+        self.head = i;
+        if self.tail == NIL {
+            self.tail = i;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_hits_and_misses() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let mut cache = ValidationCache::new(8);
+        assert_eq!(cache.validate(&mut validator, "\\s+", "g"), Ok(()));
+        assert_eq!(cache.validate(&mut validator, "\\s+", "g"), Ok(()));
+        assert_eq!(cache.validate(&mut validator, "\\s+", "gu"), Ok(()));
+        assert_eq!(
+            cache.validate(&mut validator, "a**", ""),
+            Err("Nothing to repeat".to_string())
+        );
+        assert_eq!(
+            cache.validate(&mut validator, "a**", ""),
+            Err("Nothing to repeat".to_string())
+        );
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 2,
+                misses: 3,
+                evictions: 0
+            }
+        );
+        assert_eq!(cache.len(), 3);
+        assert!((cache.stats().hit_rate() - 0.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn cache_is_keyed_by_version() {
+        let mut es5 = EcmaRegexValidator::new(EcmaVersion::ES5);
+        let mut es2018 = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let mut cache = ValidationCache::new(8);
+        assert_eq!(cache.validate(&mut es2018, "(?<=a)b", "s"), Ok(()));
+        assert_ne!(cache.validate(&mut es5, "(?<=a)b", "s"), Ok(()));
+        assert_eq!(cache.stats().misses, 2);
+    }
+
+    #[cfg(feature = "unicode-properties")]
+    #[test]
+    fn cache_is_keyed_by_unicode_version() {
+        let mut es2018 = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let mut cache = ValidationCache::new(8);
+        let mut unicode15 = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        unicode15.set_unicode_version(UnicodeVersion::V15_0);
+        assert_ne!(cache.validate(&mut es2018, "\\p{Script=Kawi}", "u"), Ok(()));
+        assert_eq!(
+            cache.validate(&mut unicode15, "\\p{Script=Kawi}", "u"),
+            Ok(())
+        );
+        assert_eq!(cache.stats().misses, 2);
+    }
+
+    #[test]
+    fn cache_evicts_least_recently_used() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let mut cache = ValidationCache::new(2);
+        cache.validate(&mut validator, "a", "").unwrap();
+        cache.validate(&mut validator, "b", "").unwrap();
+        cache.validate(&mut validator, "a", "").unwrap();
+        cache.validate(&mut validator, "c", "").unwrap();
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.stats().evictions, 1);
+
+        // "b" was evicted, "a" and "c" are still cached
+        cache.validate(&mut validator, "a", "").unwrap();
+        cache.validate(&mut validator, "c", "").unwrap();
+        assert_eq!(cache.stats().hits, 3);
+        cache.validate(&mut validator, "b", "").unwrap();
+        assert_eq!(cache.stats().misses, 4);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.stats(), CacheStats::default());
+    }
+
+    #[test]
+    fn zero_capacity_cache() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let mut cache = ValidationCache::new(0);
+        cache.validate(&mut validator, "a", "").unwrap();
+        cache.validate(&mut validator, "a", "").unwrap();
+        assert!(cache.is_empty());
+        assert_eq!(cache.stats().misses, 2);
+    }
+}
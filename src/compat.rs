@@ -0,0 +1,522 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::features::{literal_features, Feature};
+use crate::unicode::UnicodeVersion;
+
+/// A JavaScript engine, or the browser or runtime that embeds it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Engine {
+    /// Chrome and other browsers with V8, including Edge from version 79.
+    Chrome,
+    /// Edge, with EdgeHTML before version 79.
+    Edge,
+    /// Firefox with SpiderMonkey.
+    Firefox,
+    /// Safari with JavaScriptCore, on macOS and iOS.
+    Safari,
+    /// Node.js with V8.
+    Node,
+    /// Deno with V8.
+    Deno,
+    /// Hermes, the engine of React Native.
+    Hermes,
+    /// QuickJS, versioned by its release date.
+    QuickJs,
+}
+
+impl Engine {
+    /// The browserslist-style name of the engine.
+    pub fn name(self) -> &'static str {
+        match self {
+            Engine::Chrome => "chrome",
+            Engine::Edge => "edge",
+            Engine::Firefox => "firefox",
+            Engine::Safari => "safari",
+            Engine::Node => "node",
+            Engine::Deno => "deno",
+            Engine::Hermes => "hermes",
+            Engine::QuickJs => "quickjs",
+        }
+    }
+
+    /// Returns the version of this engine that added `feature`, or `None` if no version
+    /// supports it yet. For Unicode data this is the first version whose property escapes
+    /// know the given Unicode version.
+    pub fn added_in(self, feature: Feature) -> Option<(u32, u32, u32)> {
+        let engines = match feature {
+            Feature::UnicodeData(unicode) => UNICODE_DATA.iter().find(|(u, _)| *u == unicode)?.1,
+            _ => SUPPORT.iter().find(|(f, _)| *f == feature)?.1,
+        };
+        let (_, version) = engines.iter().find(|(engine, _)| *engine == self)?;
+        return parse_version(self, version);
+    }
+}
+
+/// The engine versions that added each feature, following MDN's browser compatibility data.
+/// Engines that are missing from a list do not support the feature.
+const SUPPORT: &[(Feature, &[(Engine, &str)])] = &[
+    (
+        Feature::UnicodeFlag,
+        &[
+            (Engine::Chrome, "50"),
+            (Engine::Edge, "12"),
+            (Engine::Firefox, "46"),
+            (Engine::Safari, "10"),
+            (Engine::Node, "6.0.0"),
+            (Engine::Deno, "1.0"),
+            (Engine::Hermes, "0.1"),
+            (Engine::QuickJs, "2019-07-09"),
+        ],
+    ),
+    (
+        Feature::StickyFlag,
+        &[
+            (Engine::Chrome, "49"),
+            (Engine::Edge, "13"),
+            (Engine::Firefox, "3"),
+            (Engine::Safari, "10"),
+            (Engine::Node, "6.0.0"),
+            (Engine::Deno, "1.0"),
+            (Engine::Hermes, "0.1"),
+            (Engine::QuickJs, "2019-07-09"),
+        ],
+    ),
+    (
+        Feature::DotAllFlag,
+        &[
+            (Engine::Chrome, "62"),
+            (Engine::Edge, "79"),
+            (Engine::Firefox, "78"),
+            (Engine::Safari, "11.1"),
+            (Engine::Node, "8.10.0"),
+            (Engine::Deno, "1.0"),
+            (Engine::Hermes, "0.1"),
+            (Engine::QuickJs, "2019-07-09"),
+        ],
+    ),
+    (
+        Feature::HasIndicesFlag,
+        &[
+            (Engine::Chrome, "90"),
+            (Engine::Edge, "90"),
+            (Engine::Firefox, "88"),
+            (Engine::Safari, "15"),
+            (Engine::Node, "16.0.0"),
+            (Engine::Deno, "1.9"),
+            (Engine::QuickJs, "2021-03-27"),
+        ],
+    ),
+    (
+        Feature::NamedCaptureGroups,
+        &[
+            (Engine::Chrome, "64"),
+            (Engine::Edge, "79"),
+            (Engine::Firefox, "78"),
+            (Engine::Safari, "11.1"),
+            (Engine::Node, "10.0.0"),
+            (Engine::Deno, "1.0"),
+            (Engine::Hermes, "0.1"),
+            (Engine::QuickJs, "2019-07-09"),
+        ],
+    ),
+    (
+        Feature::Lookbehind,
+        &[
+            (Engine::Chrome, "62"),
+            (Engine::Edge, "79"),
+            (Engine::Firefox, "78"),
+            (Engine::Safari, "16.4"),
+            (Engine::Node, "8.10.0"),
+            (Engine::Deno, "1.0"),
+            (Engine::Hermes, "0.1"),
+            (Engine::QuickJs, "2019-07-09"),
+        ],
+    ),
+    (
+        Feature::UnicodePropertyEscapes,
+        &[
+            (Engine::Chrome, "64"),
+            (Engine::Edge, "79"),
+            (Engine::Firefox, "78"),
+            (Engine::Safari, "11.1"),
+            (Engine::Node, "10.0.0"),
+            (Engine::Deno, "1.0"),
+            (Engine::QuickJs, "2019-07-09"),
+        ],
+    ),
+];
+
+/// The engine versions that first shipped the data of each Unicode version, following the
+/// ICU version bundled with V8 and SpiderMonkey and the release notes of the others.
+/// Engines without property escapes are missing from every list.
+const UNICODE_DATA: &[(UnicodeVersion, &[(Engine, &str)])] = &[
+    (
+        UnicodeVersion::V9_0,
+        &[
+            (Engine::Chrome, "64"),
+            (Engine::Edge, "79"),
+            (Engine::Firefox, "78"),
+            (Engine::Safari, "11.1"),
+            (Engine::Node, "10.0.0"),
+            (Engine::Deno, "1.0"),
+            (Engine::QuickJs, "2019-07-09"),
+        ],
+    ),
+    (
+        UnicodeVersion::V10_0,
+        &[
+            (Engine::Chrome, "64"),
+            (Engine::Edge, "79"),
+            (Engine::Firefox, "78"),
+            (Engine::Safari, "11.1"),
+            (Engine::Node, "10.0.0"),
+            (Engine::Deno, "1.0"),
+            (Engine::QuickJs, "2019-07-09"),
+        ],
+    ),
+    (
+        UnicodeVersion::V11_0,
+        &[
+            (Engine::Chrome, "70"),
+            (Engine::Edge, "79"),
+            (Engine::Firefox, "78"),
+            (Engine::Safari, "12.1"),
+            (Engine::Node, "11.0.0"),
+            (Engine::Deno, "1.0"),
+            (Engine::QuickJs, "2019-07-09"),
+        ],
+    ),
+    (
+        UnicodeVersion::V12_0,
+        &[
+            (Engine::Chrome, "75"),
+            (Engine::Edge, "79"),
+            (Engine::Firefox, "78"),
+            (Engine::Safari, "13"),
+            (Engine::Node, "12.3.0"),
+            (Engine::Deno, "1.0"),
+            (Engine::QuickJs, "2019-07-09"),
+        ],
+    ),
+    (
+        UnicodeVersion::V12_1,
+        &[
+            (Engine::Chrome, "76"),
+            (Engine::Edge, "79"),
+            (Engine::Firefox, "78"),
+            (Engine::Safari, "13"),
+            (Engine::Node, "13.0.0"),
+            (Engine::Deno, "1.0"),
+            (Engine::QuickJs, "2019-07-09"),
+        ],
+    ),
+    (
+        UnicodeVersion::V13_0,
+        &[
+            (Engine::Chrome, "84"),
+            (Engine::Edge, "84"),
+            (Engine::Firefox, "79"),
+            (Engine::Safari, "14"),
+            (Engine::Node, "14.5.0"),
+            (Engine::Deno, "1.0"),
+            (Engine::QuickJs, "2020-11-08"),
+        ],
+    ),
+    (
+        UnicodeVersion::V14_0,
+        &[
+            (Engine::Chrome, "97"),
+            (Engine::Edge, "97"),
+            (Engine::Firefox, "96"),
+            (Engine::Safari, "15.4"),
+            (Engine::Node, "17.2.0"),
+            (Engine::Deno, "1.17"),
+            (Engine::QuickJs, "2024-01-13"),
+        ],
+    ),
+    (
+        UnicodeVersion::V15_0,
+        &[
+            (Engine::Chrome, "110"),
+            (Engine::Edge, "110"),
+            (Engine::Firefox, "109"),
+            (Engine::Safari, "16.4"),
+            (Engine::Node, "19.1.0"),
+            (Engine::Deno, "1.30"),
+            (Engine::QuickJs, "2024-01-13"),
+        ],
+    ),
+    (
+        UnicodeVersion::V15_1,
+        &[
+            (Engine::Chrome, "120"),
+            (Engine::Edge, "120"),
+            (Engine::Firefox, "120"),
+            (Engine::Safari, "17.4"),
+            (Engine::Node, "21.5.0"),
+            (Engine::Deno, "1.39"),
+            (Engine::QuickJs, "2024-01-13"),
+        ],
+    ),
+    (
+        UnicodeVersion::V16_0,
+        &[
+            (Engine::Chrome, "132"),
+            (Engine::Edge, "132"),
+            (Engine::Firefox, "134"),
+            (Engine::Safari, "18.4"),
+            (Engine::Node, "23.3.0"),
+            (Engine::Deno, "2.1"),
+            (Engine::QuickJs, "2025-04-26"),
+        ],
+    ),
+];
+
+/// A version of an engine to check regular expressions against.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Target {
+    pub engine: Engine,
+    /// The version as `(major, minor, patch)`, or `(year, month, day)` for QuickJS.
+    pub version: (u32, u32, u32),
+}
+
+impl Target {
+    /// Returns whether this target supports `feature`.
+    pub fn supports(&self, feature: Feature) -> bool {
+        self.engine
+            .added_in(feature)
+            .is_some_and(|added| added <= self.version)
+    }
+}
+
+impl FromStr for Target {
+    type Err = String;
+
+    /// Parses a browserslist query result like `chrome 90`, `ios_saf 16.4-16.5` or
+    /// `node 18.12.0`. Of a version range the lower bound is used.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let (name, version) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(name), Some(version), None) => (name, version),
+            _ => return Err(format!("Invalid target {}", s)),
+        };
+        let engine = match name.to_ascii_lowercase().as_str() {
+            "chrome" | "and_chr" | "opera" => Engine::Chrome,
+            "edge" => Engine::Edge,
+            "firefox" | "and_ff" => Engine::Firefox,
+            "safari" | "ios_saf" => Engine::Safari,
+            "node" => Engine::Node,
+            "deno" => Engine::Deno,
+            "hermes" => Engine::Hermes,
+            "quickjs" => Engine::QuickJs,
+            _ => return Err(format!("Unknown engine {}", name)),
+        };
+        let version = parse_version(engine, version)
+            .ok_or_else(|| format!("Invalid version {} of {}", version, name))?;
+        // Opera is versioned like Chrome 13 releases later.
+        let version = if name.eq_ignore_ascii_case("opera") {
+            (version.0 + 13, version.1, version.2)
+        } else {
+            version
+        };
+        Ok(Target { engine, version })
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.engine.name(),
+            format_version(self.engine, self.version)
+        )
+    }
+}
+
+/// A feature of a regular expression that a target does not support.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Incompatibility {
+    pub feature: Feature,
+    pub target: Target,
+}
+
+impl fmt::Display for Incompatibility {
+    /// Formats the incompatibility like
+    /// "safari 16.3 does not support lookbehind assertions (added in 16.4)".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} does not support {}", self.target, self.feature)?;
+        match self.target.engine.added_in(self.feature) {
+            Some(added) => write!(
+                f,
+                " (added in {})",
+                format_version(self.target.engine, added)
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns every feature of the regular expression literal `/pattern/flags` that one of
+/// `targets` does not support, ordered by feature and then by target.
+///
+/// Property values and group names are looked up in the newest Unicode data the validator
+/// knows, so that engines with newer data than the newest edition can be checked too.
+///
+/// Returns an error if the regular expression is invalid.
+pub fn check_compatibility(
+    pattern: &str,
+    flags: &str,
+    targets: &[Target],
+) -> Result<Vec<Incompatibility>, String> {
+    let features = literal_features(pattern, flags, UnicodeVersion::LATEST)?;
+    let mut incompatibilities = Vec::new();
+    for &feature in &features {
+        for &target in targets {
+            if !target.supports(feature) {
+                incompatibilities.push(Incompatibility { feature, target });
+            }
+        }
+    }
+    return Ok(incompatibilities);
+}
+
+fn parse_version(engine: Engine, version: &str) -> Option<(u32, u32, u32)> {
+    let (version, separator) = match engine {
+        Engine::QuickJs => (version, '-'),
+        _ => (version.split('-').next()?, '.'),
+    };
+    let mut numbers = version
+        .split(separator)
+        .map(|part| part.parse::<u32>().ok());
+    let major = numbers.next()??;
+    let minor = numbers.next().unwrap_or(Some(0))?;
+    let patch = numbers.next().unwrap_or(Some(0))?;
+    if numbers.next().is_some() {
+        return None;
+    }
+    Some((major, minor, patch))
+}
+
+fn format_version(engine: Engine, (major, minor, patch): (u32, u32, u32)) -> String {
+    match engine {
+        Engine::QuickJs => format!("{:04}-{:02}-{:02}", major, minor, patch),
+        _ if patch != 0 => format!("{}.{}.{}", major, minor, patch),
+        _ if minor != 0 => format!("{}.{}", major, minor),
+        _ => format!("{}", major),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn targets(names: &[&str]) -> Vec<Target> {
+        names.iter().map(|name| name.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn parse_targets() {
+        let target: Target = "ios_saf 16.4-16.5".parse().unwrap();
+        assert_eq!(target.engine, Engine::Safari);
+        assert_eq!(target.version, (16, 4, 0));
+        assert_eq!(target.to_string(), "safari 16.4");
+        let target: Target = "quickjs 2021-03-27".parse().unwrap();
+        assert_eq!(target.to_string(), "quickjs 2021-03-27");
+        assert_eq!("opera 50".parse::<Target>().unwrap().version, (63, 0, 0));
+        assert_eq!(
+            "ie 11".parse::<Target>(),
+            Err("Unknown engine ie".to_string())
+        );
+        assert_eq!(
+            "safari TP".parse::<Target>(),
+            Err("Invalid version TP of safari".to_string())
+        );
+    }
+
+    #[test]
+    fn lookbehind_in_safari() {
+        let targets = targets(&["chrome 90", "safari 16.3", "ios_saf 16.4", "node 8.9.4"]);
+        let result = check_compatibility("(?<=\\$)\\d+", "", &targets).unwrap();
+        assert_eq!(
+            result,
+            vec![
+                Incompatibility {
+                    feature: Feature::Lookbehind,
+                    target: targets[1],
+                },
+                Incompatibility {
+                    feature: Feature::Lookbehind,
+                    target: targets[3],
+                },
+            ]
+        );
+        assert_eq!(
+            result[0].to_string(),
+            "safari 16.3 does not support lookbehind assertions (added in 16.4)"
+        );
+        assert_eq!(check_compatibility("\\d+", "g", &targets), Ok(vec![]));
+    }
+
+    #[test]
+    fn unicode_data() {
+        let unicode13 = Feature::UnicodeData(UnicodeVersion::V13_0);
+        assert!(!targets(&["chrome 83"])[0].supports(unicode13));
+        assert!(targets(&["chrome 84"])[0].supports(unicode13));
+        assert!(!targets(&["hermes 0.12"])[0].supports(unicode13));
+        assert_eq!(Engine::Safari.added_in(unicode13), Some((14, 0, 0)));
+    }
+
+    #[cfg(feature = "unicode-properties")]
+    #[test]
+    fn unicode_data_of_pattern() {
+        let targets = targets(&["chrome 74", "chrome 75"]);
+        let result = check_compatibility("\\p{Script=Elymaic}", "u", &targets).unwrap();
+        assert_eq!(
+            result,
+            vec![Incompatibility {
+                feature: Feature::UnicodeData(UnicodeVersion::V12_0),
+                target: targets[0],
+            }]
+        );
+        assert_eq!(
+            result[0].to_string(),
+            "chrome 74 does not support Unicode 12.0 data (added in 75)"
+        );
+    }
+
+    #[cfg(feature = "unicode-properties")]
+    #[test]
+    fn unicode_data_newer_than_editions() {
+        let targets = targets(&["chrome 109", "chrome 120"]);
+        let result = check_compatibility("\\p{Script=Kawi}", "u", &targets).unwrap();
+        let result: Vec<String> = result.iter().map(|i| i.to_string()).collect();
+        assert_eq!(
+            result,
+            vec!["chrome 109 does not support Unicode 15.0 data (added in 110)"]
+        );
+    }
+
+    #[test]
+    fn missing_in_hermes() {
+        let targets = targets(&["hermes 0.12", "edge 18"]);
+        let result = check_compatibility("(?<x>a)", "d", &targets).unwrap();
+        let result: Vec<String> = result.iter().map(|i| i.to_string()).collect();
+        assert_eq!(
+            result,
+            vec![
+                "hermes 0.12 does not support the d flag",
+                "edge 18 does not support the d flag (added in 90)",
+                "edge 18 does not support named capture groups (added in 79)",
+            ]
+        );
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::validator::EcmaRegexValidator;
+
+/// A control character (U+0000 to U+001F) in a pattern, written as itself or as a `\x`, `\u`,
+/// `\c` or legacy octal escape.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ControlCharacter {
+    /// The decoded code point.
+    pub value: u32,
+    /// The position of the character or its escape, counted in UTF-16 code units.
+    pub span: Range<usize>,
+}
+
+impl fmt::Display for ControlCharacter {
+    /// Formats the control character like "control character \x1f".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "control character \\x{:02x}", self.value)
+    }
+}
+
+impl EcmaRegexValidator {
+    /// Validates the regular expression literal `/source/flags` and finds its control
+    /// characters, like ESLint's `no-control-regex`. Legacy octal escapes like `\01` or `\37`
+    /// are reported, but `\0` not followed by a digit and the control escapes `\t`, `\n`,
+    /// `\v`, `\f` and `\r` are not, nor are the characters in `allowed`, e.g.
+    /// `&['\t', '\n', '\r']` to accept tabs and line breaks however they are written.
+    pub fn find_control_characters(
+        &mut self,
+        source: &str,
+        flags: &str,
+        allowed: &[char],
+    ) -> Result<Vec<ControlCharacter>, String> {
+        self.validate_flags(flags)?;
+        self.control_characters = Some(Vec::new());
+        let result = self.validate_pattern(source, flags.contains('u'));
+        let mut found = self.control_characters.take().unwrap_or_default();
+        result?;
+        found.retain(|c| !allowed.iter().any(|&a| a as u32 == c.value));
+        found.sort_by_key(|c| c.span.start);
+        return Ok(found);
+    }
+
+    /// Records a control character that ends at the current index, if `value` is one and
+    /// `find_control_characters` asked for it.
+    pub(crate) fn record_control_character(&mut self, value: i64, start: usize) {
+        let end = self.index();
+        if let Some(found) = &mut self.control_characters {
+            if (0..0x20).contains(&value) {
+                found.push(ControlCharacter {
+                    value: value as u32,
+                    span: start..end,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EcmaVersion;
+    use alloc::string::ToString;
+
+    fn find(source: &str, flags: &str, allowed: &[char]) -> Vec<(u32, Range<usize>)> {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let found = validator
+            .find_control_characters(source, flags, allowed)
+            .unwrap();
+        found.into_iter().map(|c| (c.value, c.span)).collect()
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(
+            find("\\x0c\\u000b\\cA", "", &[]),
+            vec![(0x0c, 0..4), (0x0b, 4..10), (0x01, 10..13)]
+        );
+        assert_eq!(find("\\u{1f}", "u", &[]), vec![(0x1f, 0..6)]);
+        assert_eq!(
+            find("[\\x1f-\\x20\\c1]", "", &[]),
+            vec![(0x1f, 1..5), (0x11, 10..13)]
+        );
+        assert_eq!(find("\\x20\\t\\n\\0\\u0041", "", &[]), vec![]);
+    }
+
+    #[test]
+    fn legacy_octal_escapes() {
+        assert_eq!(
+            find("\\01\\00[\\37]\\40", "", &[]),
+            vec![(0o1, 0..3), (0, 3..6), (0o37, 7..10)]
+        );
+        // Without groups `\1` is an octal escape, with one it is a backreference.
+        assert_eq!(find("\\1", "", &[]), vec![(1, 0..2)]);
+        assert_eq!(find("(a)\\1", "", &[]), vec![]);
+        assert_eq!(find("\\0", "u", &[]), vec![]);
+    }
+
+    #[test]
+    fn raw_characters() {
+        assert_eq!(find("a\u{1}[\u{2}]", "", &[]), vec![(1, 1..2), (2, 3..4)]);
+        assert_eq!(find("{\u{1b}", "", &[]), vec![(0x1b, 1..2)]);
+    }
+
+    #[test]
+    fn allowed() {
+        let allowed = ['\t', '\n', '\r'];
+        assert_eq!(find("\\x09\t\\u000a\\x0d", "", &allowed), vec![]);
+        assert_eq!(find("\\x09\\x0b", "", &allowed), vec![(0x0b, 4..8)]);
+    }
+
+    #[test]
+    fn display() {
+        let c = ControlCharacter {
+            value: 0x1f,
+            span: 0..4,
+        };
+        assert_eq!(c.to_string(), "control character \\x1f");
+    }
+}
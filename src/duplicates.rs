@@ -0,0 +1,434 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::empty::Suggestion;
+use crate::tree::{class_escape_set, CharSet, Node};
+use crate::validator::EcmaRegexValidator;
+
+/// What kind of part of a pattern is a duplicate.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum DuplicateKind {
+    /// A character, range or escape in a character class, like the second `a` in `[aa]`.
+    ClassMember,
+    /// An alternative that can only match what earlier ones match, like `1` in `\d|1`.
+    Alternative,
+}
+
+impl fmt::Display for DuplicateKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DuplicateKind::ClassMember => "character class member is covered by other members",
+            DuplicateKind::Alternative => "alternative is covered by earlier alternatives",
+        })
+    }
+}
+
+/// A part of a pattern that other parts already cover.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Duplicate {
+    pub kind: DuplicateKind,
+    /// The position of the duplicate, counted in UTF-16 code units.
+    pub span: Range<usize>,
+    /// The positions of the members or alternatives that cover it.
+    pub covered_by: Vec<Range<usize>>,
+    /// For class members the class in its minimal form, for alternatives their removal.
+    pub fix: Suggestion,
+}
+
+/// A character, range or escape in a character class, as the validator reads it.
+#[derive(Clone, Debug)]
+pub(crate) struct ClassMember {
+    /// The code points of a character or range, or -1 for a class escape.
+    pub(crate) min: i64,
+    pub(crate) max: i64,
+    pub(crate) span: Range<usize>,
+}
+
+/// A character class with its members, as the validator reads it.
+#[derive(Clone, Debug)]
+pub(crate) struct ClassMembers {
+    pub(crate) span: Range<usize>,
+    pub(crate) members: Vec<ClassMember>,
+    /// Whether the members are simple enough to analyse.
+    pub(crate) analysable: bool,
+}
+
+fn is_subset(a: &CharSet, b: &CharSet) -> bool {
+    a.intersect(b) == *a
+}
+
+impl EcmaRegexValidator {
+    /// Validates the regular expression literal `/source/flags` and finds the members of
+    /// character classes that other members cover, like in `[a-za-f]` or `[\w_]`, and the
+    /// alternatives that earlier ones cover, like in `a|a` or `\d|1`, in the order they
+    /// appear.
+    ///
+    /// Property escapes only cover themselves.
+    pub fn find_duplicates(&mut self, source: &str, flags: &str) -> Result<Vec<Duplicate>, String> {
+        self.validate_flags(flags)?;
+        self.class_member_lists = Some(Vec::new());
+        let result = self.validate_pattern(source, flags.contains('u'));
+        let classes = self.class_member_lists.take().unwrap_or_default();
+        result?;
+        let tree = self.parse_tree(source, flags)?;
+        let units: Vec<u16> = source.encode_utf16().collect();
+        let ignore_case = flags.contains('i');
+        let unicode = flags.contains('u');
+
+        let mut found = Vec::new();
+        for class in classes.iter().filter(|class| class.analysable) {
+            find_duplicate_members(class, &units, ignore_case, unicode, &mut found);
+        }
+        find_duplicate_alternatives(&tree, &units, &mut found);
+        found.sort_by_key(|d| (d.span.start, d.span.end));
+        return Ok(found);
+    }
+
+    /// Records a member of the character class being read, if `find_duplicates` asked for
+    /// it. `min` is -2 for a range that the analysis does not understand.
+    pub(crate) fn record_class_member(&mut self, min: i64, max: i64, span: Range<usize>) {
+        if self.class_member_lists.is_some() {
+            self.class_members.push(ClassMember { min, max, span });
+        }
+    }
+
+    /// Files the recorded members under the character class that ends at `class`.
+    pub(crate) fn finish_class_members(&mut self, class: Range<usize>) {
+        let members = core::mem::take(&mut self.class_members);
+        let analysable = members.iter().all(|m| m.min != -2);
+        if let Some(lists) = &mut self.class_member_lists {
+            lists.push(ClassMembers {
+                span: class,
+                members,
+                analysable,
+            });
+        }
+    }
+}
+
+/// Returns the set a class member matches. Property escapes, which the analysis does not
+/// look into, get a made-up code point above U+10FFFF for each way of writing them.
+fn member_set(member: &ClassMember, units: &[u16], escapes: &mut Vec<Vec<u16>>) -> CharSet {
+    if member.min >= 0 {
+        return CharSet::from_ranges(&[(member.min as u32, member.max as u32)]);
+    }
+    let text = &units[member.span.clone()];
+    let letter = text.get(1).and_then(|&u| core::char::from_u32(u as u32));
+    if let Some(set) = letter.and_then(class_escape_set) {
+        return set;
+    }
+    let index = match escapes.iter().position(|e| e == text) {
+        Some(index) => index,
+        None => {
+            escapes.push(text.to_vec());
+            escapes.len() - 1
+        }
+    };
+    CharSet::single(0x110000 + index as u32)
+}
+
+fn find_duplicate_members(
+    class: &ClassMembers,
+    units: &[u16],
+    ignore_case: bool,
+    unicode: bool,
+    found: &mut Vec<Duplicate>,
+) {
+    let mut escapes = Vec::new();
+    let sets: Vec<CharSet> = class
+        .members
+        .iter()
+        .map(|member| {
+            let set = member_set(member, units, &mut escapes);
+            if ignore_case {
+                set.fold_case(unicode)
+            } else {
+                set
+            }
+        })
+        .collect();
+
+    let mut kept: Vec<usize> = Vec::new();
+    let mut duplicates: Vec<(usize, Vec<usize>)> = Vec::new();
+    for (j, set) in sets.iter().enumerate() {
+        let covering: Vec<usize> = kept
+            .iter()
+            .copied()
+            .filter(|&i| !sets[i].intersect(set).is_empty())
+            .collect();
+        let mut union = CharSet::default();
+        covering.iter().for_each(|&i| union = union.union(&sets[i]));
+        if is_subset(set, &union) {
+            duplicates.push((j, covering));
+            continue;
+        }
+        kept.retain(|&i| {
+            if is_subset(&sets[i], set) {
+                duplicates.push((i, vec![j]));
+                return false;
+            }
+            true
+        });
+        kept.push(j);
+    }
+    if duplicates.is_empty() {
+        return;
+    }
+
+    let fix = Suggestion {
+        span: class.span.clone(),
+        replacement: minimal_class(class, &kept, units),
+    };
+    for (i, covering) in duplicates {
+        found.push(Duplicate {
+            kind: DuplicateKind::ClassMember,
+            span: class.members[i].span.clone(),
+            covered_by: covering
+                .iter()
+                .map(|&c| class.members[c].span.clone())
+                .collect(),
+            fix: fix.clone(),
+        });
+    }
+}
+
+/// Writes the class with only the `kept` members.
+fn minimal_class(class: &ClassMembers, kept: &[usize], units: &[u16]) -> String {
+    let mut text = vec!['[' as u16];
+    let negated = units[class.span.start + 1] == '^' as u16;
+    if negated {
+        text.push('^' as u16);
+    }
+    for (n, &i) in kept.iter().enumerate() {
+        let member = &units[class.members[i].span.clone()];
+        let at_edge = n == 0 || n == kept.len() - 1;
+        // A literal `-` between members or a `^` at the start would change its meaning.
+        if (member == ['-' as u16] && !at_edge) || (member == ['^' as u16] && n == 0 && !negated) {
+            text.push('\\' as u16);
+        }
+        push_escaping_lone_surrogates(&mut text, member);
+    }
+    text.push(']' as u16);
+    String::from_utf16_lossy(&text)
+}
+
+/// Appends `units`, writing surrogates that are not part of a pair in `units` as `\uXXXX`,
+/// so they neither get lost in the string nor pair up with their new neighbours.
+fn push_escaping_lone_surrogates(text: &mut Vec<u16>, units: &[u16]) {
+    let mut i = 0;
+    while i < units.len() {
+        let unit = units[i];
+        let paired = (0xd800..=0xdbff).contains(&unit)
+            && matches!(units.get(i + 1), Some(trail) if (0xdc00..=0xdfff).contains(trail));
+        if paired {
+            text.extend_from_slice(&units[i..i + 2]);
+            i += 2;
+            continue;
+        }
+        if (0xd800..=0xdfff).contains(&unit) {
+            text.extend(format!("\\u{:04X}", unit).encode_utf16());
+        } else {
+            text.push(unit);
+        }
+        i += 1;
+    }
+}
+
+/// Returns the position of a node, if it is not empty.
+fn node_span(node: &Node) -> Option<Range<usize>> {
+    match node {
+        Node::Chars(_, span) | Node::Assertion(span) => Some(span.clone()),
+        Node::Lookaround { span, .. }
+        | Node::Backreference { span, .. }
+        | Node::Group { span, .. }
+        | Node::Quantified { span, .. } => Some(span.clone()),
+        Node::Sequence(nodes) => {
+            Some(node_span(nodes.first()?)?.start..node_span(nodes.last()?)?.end)
+        }
+        Node::Alternation(_) | Node::Empty => None,
+    }
+}
+
+fn has_capturing_group(node: &Node) -> bool {
+    match node {
+        Node::Group { index: Some(_), .. } => true,
+        Node::Group { body, .. }
+        | Node::Lookaround { body, .. }
+        | Node::Quantified { body, .. } => has_capturing_group(body),
+        Node::Alternation(nodes) | Node::Sequence(nodes) => nodes.iter().any(has_capturing_group),
+        _ => false,
+    }
+}
+
+/// Finds alternatives that are written like an earlier one, or that match a single
+/// character that earlier single character alternatives match.
+fn find_duplicate_alternatives(node: &Node, units: &[u16], found: &mut Vec<Duplicate>) {
+    match node {
+        Node::Alternation(nodes) => {
+            let mut earlier: Vec<(Range<usize>, Option<CharSet>)> = Vec::new();
+            for node in nodes {
+                let span = match node_span(node) {
+                    Some(span) => span,
+                    None => continue,
+                };
+                let set = node.single_char_set();
+                let same_text: Vec<Range<usize>> = earlier
+                    .iter()
+                    .filter(|(other, _)| units[other.clone()] == units[span.clone()])
+                    .map(|(other, _)| other.clone())
+                    .collect();
+                let mut covering = same_text;
+                if covering.is_empty() {
+                    if let Some(set) = &set {
+                        let mut union = CharSet::default();
+                        for (other, other_set) in &earlier {
+                            if let Some(other_set) = other_set {
+                                if !other_set.intersect(set).is_empty() {
+                                    union = union.union(other_set);
+                                    covering.push(other.clone());
+                                }
+                            }
+                        }
+                        if !is_subset(set, &union) {
+                            covering.clear();
+                        }
+                    }
+                }
+                if !covering.is_empty() && !has_capturing_group(node) {
+                    found.push(Duplicate {
+                        kind: DuplicateKind::Alternative,
+                        span: span.clone(),
+                        covered_by: covering,
+                        fix: Suggestion {
+                            span: span.start - 1..span.end,
+                            replacement: String::new(),
+                        },
+                    });
+                }
+                earlier.push((span, set));
+            }
+            nodes
+                .iter()
+                .for_each(|node| find_duplicate_alternatives(node, units, found));
+        }
+        Node::Group { body, .. }
+        | Node::Lookaround { body, .. }
+        | Node::Quantified { body, .. } => find_duplicate_alternatives(body, units, found),
+        Node::Sequence(nodes) => nodes
+            .iter()
+            .for_each(|node| find_duplicate_alternatives(node, units, found)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EcmaVersion;
+    use alloc::string::ToString;
+
+    fn find(source: &str, flags: &str) -> Vec<(DuplicateKind, Range<usize>, String)> {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let found = validator.find_duplicates(source, flags).unwrap();
+        found
+            .into_iter()
+            .map(|d| (d.kind, d.span, d.fix.replacement))
+            .collect()
+    }
+
+    #[test]
+    fn class_members() {
+        use DuplicateKind::*;
+        assert_eq!(
+            find("[aa]", ""),
+            vec![(ClassMember, 2..3, "[a]".to_string())]
+        );
+        assert_eq!(
+            find("[a-za-f]", ""),
+            vec![(ClassMember, 4..7, "[a-z]".to_string())]
+        );
+        assert_eq!(
+            find("[a-fa-z]", ""),
+            vec![(ClassMember, 1..4, "[a-z]".to_string())]
+        );
+        assert_eq!(
+            find("[\\w_]", ""),
+            vec![(ClassMember, 3..4, "[\\w]".to_string())]
+        );
+        assert_eq!(
+            find("[0-9\\d]", ""),
+            vec![(ClassMember, 4..6, "[0-9]".to_string())]
+        );
+        assert_eq!(
+            find("[aA]", "i"),
+            vec![(ClassMember, 2..3, "[a]".to_string())]
+        );
+        assert_eq!(find("[aA]", ""), vec![]);
+        assert_eq!(find("[a-cb-d]", ""), vec![]);
+    }
+
+    #[test]
+    fn class_forms() {
+        assert_eq!(find("[^a-cb]", "")[0].2, "[^a-c]");
+        assert_eq!(find("[a-c-b-]", "")[0].2, "[a-c-]");
+        assert_eq!(find("[-a\\-]", "")[0].2, "[-a]");
+        assert_eq!(find("[b\\^a^]", "")[0].2, "[b\\^a]");
+        assert_eq!(find("[ba-c]", "")[0].1, 1..2);
+        assert_eq!(find("[^- ]", ""), vec![]);
+        assert_eq!(
+            find("[^-a-]", ""),
+            vec![(DuplicateKind::ClassMember, 4..5, "[^-a]".to_string())]
+        );
+        // Without the `u` flag the halves of a surrogate pair are members of their own.
+        assert_eq!(
+            find("[😀😁]", ""),
+            vec![(
+                DuplicateKind::ClassMember,
+                3..4,
+                "[\\uD83D\\uDE00\\uDE01]".to_string()
+            )]
+        );
+        assert_eq!(find("[😀😀]", "u")[0].2, "[😀]");
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-properties")]
+    fn property_escapes() {
+        assert_eq!(find("[\\p{L}\\p{L}]", "u")[0].1, 6..11);
+        assert_eq!(find("[\\p{L}\\p{Lu}]", "u"), vec![]);
+        assert_eq!(find("\\p{L}|\\p{N}", "u"), vec![]);
+        assert_eq!(find("\\P{L}|\\p{N}", "u"), vec![]);
+        assert_eq!(find("\\p{L}|[\\p{L}]", "u")[0].1, 6..13);
+    }
+
+    #[test]
+    fn alternatives() {
+        use DuplicateKind::*;
+        assert_eq!(find("a|a", ""), vec![(Alternative, 2..3, "".to_string())]);
+        assert_eq!(
+            find("x(\\d|1)", ""),
+            vec![(Alternative, 5..6, "".to_string())]
+        );
+        assert_eq!(
+            find("ab|c|ab", ""),
+            vec![(Alternative, 5..7, "".to_string())]
+        );
+        assert_eq!(find("a|b|[ab]", "")[0].1, 4..8);
+        assert_eq!(find("1|\\d|(a)|(a)", ""), vec![]);
+    }
+
+    #[test]
+    fn covered_by() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let found = validator.find_duplicates("[a-cd-fb-e]", "").unwrap();
+        assert_eq!(found[0].covered_by, vec![1..4, 4..7]);
+        assert!(validator.find_duplicates("[b-a]", "").is_err());
+    }
+}
@@ -0,0 +1,448 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::redundant::continues_escape;
+use crate::tree::{GroupRef, Node};
+use crate::validator::EcmaRegexValidator;
+
+/// A construct that is legal but matches nothing, everything or always the empty string.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum EmptyKind {
+    /// `[]`, which never matches.
+    EmptyClass,
+    /// `[^]`, which matches any character.
+    AnyClass,
+    /// `()` or `(?:)`, which always matches the empty string.
+    EmptyGroup,
+    /// `(?=)` or `(?<=)`, which always succeeds.
+    EmptyLookaround,
+    /// `(?!)` or `(?<!)`, which always fails.
+    EmptyNegativeLookaround,
+    /// An empty alternative like in `a||b` or `(|a)`, which makes the disjunction optional.
+    EmptyAlternative,
+}
+
+impl fmt::Display for EmptyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            EmptyKind::EmptyClass => "empty character class never matches",
+            EmptyKind::AnyClass => "negated empty character class matches any character",
+            EmptyKind::EmptyGroup => "empty group always matches the empty string",
+            EmptyKind::EmptyLookaround => "empty lookaround always succeeds",
+            EmptyKind::EmptyNegativeLookaround => "empty negative lookaround always fails",
+            EmptyKind::EmptyAlternative => "empty alternative makes the disjunction optional",
+        })
+    }
+}
+
+/// A replacement for part of a pattern.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Suggestion {
+    /// The part to replace, counted in UTF-16 code units.
+    pub span: Range<usize>,
+    pub replacement: String,
+}
+
+/// An empty construct in a pattern.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct EmptyConstruct {
+    pub kind: EmptyKind,
+    /// The position of the construct, counted in UTF-16 code units. For an empty alternative
+    /// it is the `|` that separates it from its neighbour.
+    pub span: Range<usize>,
+    /// A replacement that means the same but says so, or `None` for a capturing group that a
+    /// backreference refers to, by its name or by the number of it or a later group, and for
+    /// a construct whose removal would join its neighbours into an escape or quantifier.
+    pub fix: Option<Suggestion>,
+}
+
+/// Returns the number and name of the capturing group that starts at `start`.
+fn find_group(node: &Node, start: usize) -> Option<(u32, Option<&str>)> {
+    match node {
+        Node::Group {
+            index: Some(index),
+            name,
+            span,
+            ..
+        } if span.start == start => Some((*index, name.as_deref())),
+        Node::Group { body, .. }
+        | Node::Lookaround { body, .. }
+        | Node::Quantified { body, .. } => find_group(body, start),
+        Node::Alternation(nodes) | Node::Sequence(nodes) => {
+            nodes.iter().find_map(|node| find_group(node, start))
+        }
+        _ => None,
+    }
+}
+
+/// Returns whether a backreference refers to the group with the given number or name, or to
+/// a later group, whose number removing this one would change.
+fn is_referenced(node: &Node, index: u32, name: Option<&str>) -> bool {
+    match node {
+        Node::Backreference { group, .. } => match group {
+            GroupRef::Index(n) => *n >= index,
+            GroupRef::Name(n) => name == Some(n.as_str()),
+        },
+        Node::Group { body, .. }
+        | Node::Lookaround { body, .. }
+        | Node::Quantified { body, .. } => is_referenced(body, index, name),
+        Node::Alternation(nodes) | Node::Sequence(nodes) => {
+            nodes.iter().any(|node| is_referenced(node, index, name))
+        }
+        _ => false,
+    }
+}
+
+/// Joins the alternatives that are not empty with `|`.
+fn join_alternatives(source: &[u16], alternatives: &[Range<usize>]) -> String {
+    let mut joined = String::new();
+    for alternative in alternatives.iter().filter(|a| !a.is_empty()) {
+        if !joined.is_empty() {
+            joined.push('|');
+        }
+        joined.push_str(&String::from_utf16_lossy(&source[alternative.clone()]));
+    }
+    joined
+}
+
+/// Returns the end of the quantifier starting at `start`, or `start` if there is none.
+fn quantifier_end(source: &[u16], start: usize) -> usize {
+    let at = |i: usize| source.get(i).and_then(|&u| core::char::from_u32(u as u32));
+    let mut end = start;
+    match at(end) {
+        Some('*') | Some('+') | Some('?') => end += 1,
+        Some('{') => {
+            let mut i = end + 1;
+            while matches!(at(i), Some('0'..='9') | Some(',')) {
+                i += 1;
+            }
+            if i > end + 1 && at(end + 1) != Some(',') && at(i) == Some('}') {
+                end = i + 1;
+            }
+        }
+        _ => {}
+    }
+    if end > start && at(end) == Some('?') {
+        end += 1;
+    }
+    end
+}
+
+/// Returns whether removing `span` would join the text on either side into another token,
+/// like an escape in `\x(?:)41` or a quantifier in `a{1(?:)}`.
+fn joins_neighbours(source: &[u16], span: &Range<usize>) -> bool {
+    let before = &source[span.start.saturating_sub(5)..span.start];
+    let after = source.get(span.end);
+    if before.contains(&('\\' as u16)) && continues_escape(after) {
+        return true;
+    }
+    let is = |unit: &u16, c: char| *unit == c as u16;
+    let in_quantifier = |unit: &u16| ('0' as u16..='9' as u16).contains(unit) || is(unit, ',');
+    let braced = source[..span.start]
+        .iter()
+        .rev()
+        .find(|u| !in_quantifier(u))
+        .is_some_and(|u| is(u, '{'));
+    return braced && after.is_some_and(|u| in_quantifier(u) || is(u, '}'));
+}
+
+impl EcmaRegexValidator {
+    /// Validates the regular expression literal `/source/flags` and finds its empty
+    /// constructs: `[]`, `[^]`, empty groups and lookarounds, and empty alternatives. Each
+    /// fix is meant to be applied on its own.
+    pub fn find_empty_constructs(
+        &mut self,
+        source: &str,
+        flags: &str,
+    ) -> Result<Vec<EmptyConstruct>, String> {
+        self.validate_flags(flags)?;
+        self.empty_constructs = Some(Vec::new());
+        let result = self.validate_pattern(source, flags.contains('u'));
+        let mut found = self.empty_constructs.take().unwrap_or_default();
+        result?;
+        if flags.contains('s') {
+            for construct in &mut found {
+                if let (EmptyKind::AnyClass, Some(fix)) = (construct.kind, &mut construct.fix) {
+                    fix.replacement = String::from(".");
+                }
+            }
+        }
+        // Empty capturing groups can be removed unless that breaks a backreference.
+        if found
+            .iter()
+            .any(|c| c.kind == EmptyKind::EmptyGroup && c.fix.is_none())
+        {
+            let tree = self.parse_tree(source, flags)?;
+            let units: Vec<u16> = source.encode_utf16().collect();
+            for construct in &mut found {
+                if construct.kind != EmptyKind::EmptyGroup || construct.fix.is_some() {
+                    continue;
+                }
+                if let Some((index, name)) = find_group(&tree, construct.span.start) {
+                    let span = construct.span.start..quantifier_end(&units, construct.span.end);
+                    if !is_referenced(&tree, index, name) && !joins_neighbours(&units, &span) {
+                        construct.fix = Some(Suggestion {
+                            span,
+                            replacement: String::new(),
+                        });
+                    }
+                }
+            }
+        }
+        found.sort_by_key(|c| (c.span.start, c.span.end));
+        found.dedup();
+        return Ok(found);
+    }
+
+    /// Records an empty class, group or lookaround that ends at the current index, if
+    /// `find_empty_constructs` asked for it. An empty `replacement` removes the construct
+    /// together with its quantifier.
+    pub(crate) fn record_empty(
+        &mut self,
+        kind: EmptyKind,
+        start: usize,
+        replacement: Option<&str>,
+    ) {
+        let end = self.index();
+        let replacement = match replacement {
+            Some("") => {
+                let fix_end = quantifier_end(self.source(), end);
+                Some(start..fix_end)
+                    .filter(|span| !joins_neighbours(self.source(), span))
+                    .map(|span| (span, ""))
+            }
+            Some(replacement) => Some((start..end, replacement)),
+            None => None,
+        };
+        if let Some(found) = &mut self.empty_constructs {
+            let fix = replacement.map(|(span, replacement)| Suggestion {
+                span,
+                replacement: String::from(replacement),
+            });
+            let span = start..end;
+            found.push(EmptyConstruct { kind, span, fix });
+        }
+    }
+
+    /// Records the empty alternatives of a disjunction, given the spans of all of them.
+    ///
+    /// Only the first empty alternative changes what the disjunction matches. If it comes
+    /// last the disjunction becomes greedily optional, if it comes first lazily optional, and
+    /// in between the alternatives after it become lazily optional. Any later one is never
+    /// reached and can simply be removed.
+    pub(crate) fn record_empty_alternatives(&mut self, alternatives: &[Range<usize>]) {
+        if self.empty_constructs.is_none() || alternatives.len() < 2 {
+            return;
+        }
+        let disjunction = alternatives[0].start..alternatives[alternatives.len() - 1].end;
+        let source = self.source();
+
+        let mut constructs = Vec::new();
+        let mut first = true;
+        for (i, alternative) in alternatives.iter().enumerate() {
+            if !alternative.is_empty() {
+                continue;
+            }
+            let span = if i == 0 {
+                alternative.end..alternative.end + 1
+            } else {
+                alternative.start - 1..alternative.start
+            };
+            let fix = if !first {
+                Suggestion {
+                    span: span.clone(),
+                    replacement: String::new(),
+                }
+            } else {
+                // The alternatives before the first empty one are tried as they are, the
+                // ones after it only once matching nothing did not work out.
+                let before = join_alternatives(source, &alternatives[..i]);
+                let after = join_alternatives(source, &alternatives[i + 1..]);
+                let replacement = match (before.is_empty(), after.is_empty()) {
+                    (true, true) => String::new(),
+                    (false, true) => ["(?:", &before, ")?"].concat(),
+                    (true, false) => ["(?:", &after, ")??"].concat(),
+                    (false, false) => [&before, "|(?:", &after, ")??"].concat(),
+                };
+                Suggestion {
+                    span: disjunction.clone(),
+                    replacement,
+                }
+            };
+            first = false;
+            constructs.push(EmptyConstruct {
+                kind: EmptyKind::EmptyAlternative,
+                span,
+                fix: Some(fix),
+            });
+        }
+        if let Some(found) = &mut self.empty_constructs {
+            found.extend(constructs);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EcmaVersion;
+    use alloc::string::ToString;
+
+    fn find(source: &str, flags: &str) -> Vec<(EmptyKind, Range<usize>, Option<String>)> {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let found = validator.find_empty_constructs(source, flags).unwrap();
+        found
+            .into_iter()
+            .map(|c| (c.kind, c.span, c.fix.map(|fix| fix.replacement)))
+            .collect()
+    }
+
+    #[test]
+    fn classes() {
+        use EmptyKind::*;
+        assert_eq!(
+            find("a[]", ""),
+            vec![(EmptyClass, 1..3, Some("[^\\s\\S]".into()))]
+        );
+        // The fix can be quantified and is not flagged itself.
+        assert_eq!(
+            find("[]+", "u"),
+            vec![(EmptyClass, 0..2, Some("[^\\s\\S]".into()))]
+        );
+        assert_eq!(find("[^\\s\\S]+", "u"), vec![]);
+        assert_eq!(
+            find("[^]", ""),
+            vec![(AnyClass, 0..3, Some("[\\s\\S]".into()))]
+        );
+        assert_eq!(find("[^]", "su"), vec![(AnyClass, 0..3, Some(".".into()))]);
+        assert_eq!(find("[^a][ ]", ""), vec![]);
+    }
+
+    #[test]
+    fn groups_and_lookarounds() {
+        use EmptyKind::*;
+        assert_eq!(find("a(?:)", ""), vec![(EmptyGroup, 1..5, Some("".into()))]);
+        assert_eq!(
+            find("()(?<x>)", ""),
+            vec![
+                (EmptyGroup, 0..2, Some("".into())),
+                (EmptyGroup, 2..8, Some("".into())),
+            ]
+        );
+        // Removing a group would break a backreference to it or to a later group.
+        assert_eq!(
+            find("()(a)\\2(?<x>)\\k<x>", ""),
+            vec![(EmptyGroup, 0..2, None), (EmptyGroup, 7..13, None)]
+        );
+        assert_eq!(
+            find("(a)\\1()+", ""),
+            vec![(EmptyGroup, 5..7, Some("".into()))]
+        );
+        assert_eq!(
+            find("(?=)(?<=)", ""),
+            vec![
+                (EmptyLookaround, 0..4, Some("".into())),
+                (EmptyLookaround, 4..9, Some("".into())),
+            ]
+        );
+        assert_eq!(
+            find("(?!)(?<!)", "u"),
+            vec![
+                (EmptyNegativeLookaround, 0..4, Some("[^\\s\\S]".into())),
+                (EmptyNegativeLookaround, 4..9, Some("[^\\s\\S]".into())),
+            ]
+        );
+        assert_eq!(find("(a)(?:b)(?=c)(?!d)", ""), vec![]);
+    }
+
+    #[test]
+    fn joined_neighbours() {
+        use EmptyKind::*;
+        assert_eq!(find("\\x(?:)41", ""), vec![(EmptyGroup, 2..6, None)]);
+        assert_eq!(find("a{1(?:)}", ""), vec![(EmptyGroup, 3..7, None)]);
+        assert_eq!(find("(a)\\1(?:)0", ""), vec![(EmptyGroup, 5..9, None)]);
+        assert_eq!(find("\\c(?=)A", ""), vec![(EmptyLookaround, 2..6, None)]);
+        assert_eq!(find("a{()1}", ""), vec![(EmptyGroup, 2..4, None)]);
+        assert_eq!(find("\\x(?:)g", ""), vec![(EmptyGroup, 2..6, None)]);
+        assert_eq!(
+            find("a{(?:)x", ""),
+            vec![(EmptyGroup, 2..6, Some("".into()))]
+        );
+    }
+
+    #[test]
+    fn alternatives() {
+        use EmptyKind::*;
+        assert_eq!(
+            find("(|a)", ""),
+            vec![(EmptyAlternative, 1..2, Some("(?:a)??".into()))]
+        );
+        assert_eq!(
+            find("a|b|", ""),
+            vec![(EmptyAlternative, 3..4, Some("(?:a|b)?".into()))]
+        );
+        assert_eq!(
+            find("a||b", ""),
+            vec![(EmptyAlternative, 1..2, Some("a|(?:b)??".into()))]
+        );
+        assert_eq!(
+            find("a|||b|c", ""),
+            vec![
+                (EmptyAlternative, 1..2, Some("a|(?:b|c)??".into())),
+                (EmptyAlternative, 2..3, Some("".into())),
+            ]
+        );
+        assert_eq!(
+            find("a||", ""),
+            vec![
+                (EmptyAlternative, 1..2, Some("(?:a)?".into())),
+                (EmptyAlternative, 2..3, Some("".into())),
+            ]
+        );
+        assert_eq!(
+            find("x(?:|)", ""),
+            vec![(EmptyAlternative, 4..5, Some("".into()))]
+        );
+        assert_eq!(find("a|b", ""), vec![]);
+    }
+
+    #[test]
+    fn fix_spans() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let found = validator.find_empty_constructs("x(a||b)", "").unwrap();
+        assert_eq!(
+            found[0].fix,
+            Some(Suggestion {
+                span: 2..6,
+                replacement: "a|(?:b)??".to_string(),
+            })
+        );
+        let found = validator.find_empty_constructs("x(a|b|)", "").unwrap();
+        assert_eq!(
+            found[0].fix,
+            Some(Suggestion {
+                span: 2..6,
+                replacement: "(?:a|b)?".to_string(),
+            })
+        );
+        let found = validator
+            .find_empty_constructs("a(?:)*?b(?=){2,}", "")
+            .unwrap();
+        assert_eq!(found[0].fix.as_ref().unwrap().span, 1..7);
+        assert_eq!(found[1].fix.as_ref().unwrap().span, 8..16);
+        assert!(validator.find_empty_constructs("(", "").is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            EmptyKind::EmptyLookaround.to_string(),
+            "empty lookaround always succeeds"
+        );
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::unicode::UnicodeVersion;
+use crate::validator::{EcmaRegexValidator, EcmaVersion};
+
+/// A regular expression feature that was added after ES5.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Feature {
+    /// The `u` flag.
+    UnicodeFlag,
+    /// The `y` flag.
+    StickyFlag,
+    /// The `s` flag.
+    DotAllFlag,
+    /// The `d` flag.
+    HasIndicesFlag,
+    /// Named capture groups `(?<name>...)` and their backreferences `\k<name>`.
+    NamedCaptureGroups,
+    /// Lookbehind assertions `(?<=...)` and `(?<!...)`.
+    Lookbehind,
+    /// Unicode property escapes `\p{...}` and `\P{...}`.
+    UnicodePropertyEscapes,
+    /// Property values or group name characters of the given Unicode version.
+    UnicodeData(UnicodeVersion),
+}
+
+impl Feature {
+    /// Returns the first edition that supports this feature, or `None` if no edition the
+    /// validator knows does.
+    pub fn ecma_version(self) -> Option<EcmaVersion> {
+        match self {
+            Feature::UnicodeFlag | Feature::StickyFlag => Some(EcmaVersion::ES2015),
+            Feature::DotAllFlag
+            | Feature::NamedCaptureGroups
+            | Feature::Lookbehind
+            | Feature::UnicodePropertyEscapes => Some(EcmaVersion::ES2018),
+            Feature::HasIndicesFlag => Some(EcmaVersion::ES2022),
+            Feature::UnicodeData(version) => EcmaVersion::ALL
+                .iter()
+                .copied()
+                .find(|&v| UnicodeVersion::of_ecma_version(v) >= version),
+        }
+    }
+
+    fn of_flag(flag: char) -> Option<Feature> {
+        match flag {
+            'u' => Some(Feature::UnicodeFlag),
+            'y' => Some(Feature::StickyFlag),
+            's' => Some(Feature::DotAllFlag),
+            'd' => Some(Feature::HasIndicesFlag),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Feature {
+    /// Formats the feature as a noun phrase, like "lookbehind assertions".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Feature::UnicodeFlag => write!(f, "the u flag"),
+            Feature::StickyFlag => write!(f, "the y flag"),
+            Feature::DotAllFlag => write!(f, "the s flag"),
+            Feature::HasIndicesFlag => write!(f, "the d flag"),
+            Feature::NamedCaptureGroups => write!(f, "named capture groups"),
+            Feature::Lookbehind => write!(f, "lookbehind assertions"),
+            Feature::UnicodePropertyEscapes => write!(f, "Unicode property escapes"),
+            Feature::UnicodeData(version) => write!(f, "Unicode {} data", version),
+        }
+    }
+}
+
+/// The oldest edition that accepts a regular expression, and why.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequiredVersion {
+    /// The oldest edition that accepts the regular expression.
+    pub version: EcmaVersion,
+    /// The features of the regular expression that are newer than ES5, ordered like
+    /// `Feature`. The newest of them determine `version`.
+    pub features: Vec<Feature>,
+}
+
+/// Returns the oldest edition that accepts the regular expression literal `/pattern/flags`,
+/// with the features that require it. Property values and group names are looked up in the
+/// Unicode data of the newest edition, and report the Unicode version that introduced them.
+///
+/// Returns an error if the newest edition does not accept it.
+/// The `v` flag is not supported yet and is always rejected.
+pub fn required_version(pattern: &str, flags: &str) -> Result<RequiredVersion, String> {
+    let unicode_version = UnicodeVersion::of_ecma_version(EcmaVersion::LATEST);
+    let mut features = literal_features(pattern, flags, unicode_version)?;
+    let minimum = features
+        .iter()
+        .filter(|feature| !matches!(feature, Feature::UnicodeData(_)))
+        .filter_map(|feature| feature.ecma_version())
+        .max()
+        .unwrap_or(EcmaVersion::ES5);
+    // Unicode data only matters when it is newer than the data of the syntax's edition.
+    features.retain(|feature| match feature {
+        Feature::UnicodeData(_) => feature.ecma_version() > Some(minimum),
+        _ => true,
+    });
+    let newest = features
+        .iter()
+        .filter_map(|feature| feature.ecma_version())
+        .max()
+        .unwrap_or(EcmaVersion::ES5);
+
+    // Some syntax has no feature of its own, like `\u{...}` in group names without the
+    // `u` flag, so newer editions are tried until one accepts it. The newest one did above.
+    let version = EcmaVersion::ALL
+        .iter()
+        .copied()
+        .filter(|&v| v >= newest)
+        .find(|&v| {
+            let mut validator = EcmaRegexValidator::new(v);
+            validator
+                .validate_pattern(pattern, flags.contains('u'))
+                .is_ok()
+        })
+        .unwrap_or(EcmaVersion::LATEST);
+    return Ok(RequiredVersion { version, features });
+}
+
+/// Returns the features of the regular expression literal `/pattern/flags`, ordered like
+/// `Feature`, validating it with the newest edition and the Unicode data of
+/// `unicode_version`.
+///
+/// Unicode 9.0 data is left out, since it came with property escapes and named groups.
+pub(crate) fn literal_features(
+    pattern: &str,
+    flags: &str,
+    unicode_version: UnicodeVersion,
+) -> Result<Vec<Feature>, String> {
+    let mut validator = EcmaRegexValidator::new(EcmaVersion::LATEST);
+    validator.set_unicode_version(unicode_version);
+    validator.validate_flags(flags)?;
+    validator.validate_pattern(pattern, flags.contains('u'))?;
+
+    let mut features: Vec<Feature> = flags.chars().filter_map(Feature::of_flag).collect();
+    features.extend(validator.used_features().iter().copied());
+    features.retain(|&feature| feature != Feature::UnicodeData(UnicodeVersion::V9_0));
+    features.sort();
+    features.dedup();
+    return Ok(features);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn required(pattern: &str, flags: &str) -> (EcmaVersion, Vec<Feature>) {
+        let required = required_version(pattern, flags).unwrap();
+        (required.version, required.features)
+    }
+
+    #[test]
+    fn syntax_features() {
+        assert_eq!(required("a+b", "gim"), (EcmaVersion::ES5, vec![]));
+        assert_eq!(
+            required("a", "uy"),
+            (
+                EcmaVersion::ES2015,
+                vec![Feature::UnicodeFlag, Feature::StickyFlag]
+            )
+        );
+        assert_eq!(
+            required("(?<=a)(?<x>b)\\k<x>", "s"),
+            (
+                EcmaVersion::ES2018,
+                vec![
+                    Feature::DotAllFlag,
+                    Feature::NamedCaptureGroups,
+                    Feature::Lookbehind
+                ]
+            )
+        );
+        assert_eq!(
+            required("a", "d"),
+            (EcmaVersion::ES2022, vec![Feature::HasIndicesFlag])
+        );
+        assert_eq!(required("\\p{L}", ""), (EcmaVersion::ES5, vec![]));
+    }
+
+    #[cfg(feature = "unicode-properties")]
+    #[test]
+    fn property_escapes() {
+        assert_eq!(
+            required("\\p{L}", "u"),
+            (
+                EcmaVersion::ES2018,
+                vec![Feature::UnicodeFlag, Feature::UnicodePropertyEscapes]
+            )
+        );
+        assert_eq!(
+            required("\\p{Script=Elymaic}", "u"),
+            (
+                EcmaVersion::ES2020,
+                vec![
+                    Feature::UnicodeFlag,
+                    Feature::UnicodePropertyEscapes,
+                    Feature::UnicodeData(UnicodeVersion::V12_0)
+                ]
+            )
+        );
+        assert_eq!(
+            required("\\p{Script=Elymaic}\\p{Script=Adlam}", "u"),
+            (
+                EcmaVersion::ES2020,
+                vec![
+                    Feature::UnicodeFlag,
+                    Feature::UnicodePropertyEscapes,
+                    Feature::UnicodeData(UnicodeVersion::V12_0)
+                ]
+            )
+        );
+        assert_eq!(
+            required("\\p{Script=Adlam}", "u"),
+            (
+                EcmaVersion::ES2018,
+                vec![Feature::UnicodeFlag, Feature::UnicodePropertyEscapes]
+            )
+        );
+        assert_eq!(
+            Feature::UnicodeData(UnicodeVersion::V15_0).ecma_version(),
+            None
+        );
+    }
+
+    #[cfg(feature = "unicode-identifiers")]
+    #[test]
+    fn group_name_characters() {
+        assert_eq!(
+            required("(?<\\u{10FE0}>)", "u"),
+            (
+                EcmaVersion::ES2020,
+                vec![
+                    Feature::UnicodeFlag,
+                    Feature::NamedCaptureGroups,
+                    Feature::UnicodeData(UnicodeVersion::V12_0)
+                ]
+            )
+        );
+        assert_eq!(
+            required("(?<a\u{e9}>)", ""),
+            (EcmaVersion::ES2018, vec![Feature::NamedCaptureGroups])
+        );
+    }
+
+    #[test]
+    fn invalid_everywhere() {
+        assert_eq!(
+            required_version("a", "gg"),
+            Err("Duplicated flag g".to_string())
+        );
+        assert_eq!(
+            required_version("(", ""),
+            Err("Unterminated group".to_string())
+        );
+        assert!(required_version("a", "v").is_err());
+    }
+}
@@ -0,0 +1,519 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::tree::{CharSet, Node};
+use crate::validator::EcmaRegexValidator;
+
+/// How a regular expression is used, as far as the caller knows.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Usage {
+    Unknown,
+    /// Only passed to `RegExp.prototype.test`.
+    Test,
+    /// Only passed to `String.prototype.search`.
+    Search,
+}
+
+/// Why a flag has no effect on a pattern.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum UselessFlagReason {
+    /// `i` on a pattern that matches the same characters in any case, like `\d+`.
+    NoCasedCharacters,
+    /// `m` on a pattern without `^` or `$`.
+    NoAnchors,
+    /// `s` on a pattern without `.`.
+    NoDot,
+    /// `y` together with `g` on a pattern that starts with `^` and without `m`. Both flags
+    /// then only let it match at the start of the input.
+    AnchoredToStart,
+    /// `g` on a regular expression that is only tested or searched, which looks for a single
+    /// match. For `test` it only makes the regular expression remember where to continue.
+    NotIterated,
+}
+
+impl fmt::Display for UselessFlagReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            UselessFlagReason::NoCasedCharacters => "the pattern matches the same in any case",
+            UselessFlagReason::NoAnchors => "the pattern has no ^ or $",
+            UselessFlagReason::NoDot => "the pattern has no .",
+            UselessFlagReason::AnchoredToStart => "the pattern only matches at the start",
+            UselessFlagReason::NotIterated => "only a single match is looked for",
+        })
+    }
+}
+
+/// A flag that has no effect on a regular expression.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct UselessFlag {
+    pub flag: char,
+    pub reason: UselessFlagReason,
+}
+
+impl fmt::Display for UselessFlag {
+    /// Formats the flag like "the i flag has no effect: the pattern has no ^ or $".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the {} flag has no effect: {}", self.flag, self.reason)
+    }
+}
+
+/// Characters whose simple case folding differs from their lowercase form, with what they
+/// fold to. They are in the same case as a character that is not their upper- or lowercase.
+const FOLDS: &[(u32, u32)] = &[
+    (0x17f, 0x73),
+    (0x345, 0x3b9),
+    (0x3c2, 0x3c3),
+    (0x3d0, 0x3b2),
+    (0x3d1, 0x3b8),
+    (0x3d5, 0x3c6),
+    (0x3d6, 0x3c0),
+    (0x3f0, 0x3ba),
+    (0x3f1, 0x3c1),
+    (0x3f5, 0x3b5),
+    (0x1e9b, 0x1e61),
+    (0x1fbe, 0x3b9),
+];
+
+/// Characters that are not the uppercase form of their lowercase form, like the Kelvin sign
+/// and titlecase letters.
+const OTHER_UPPERCASE: &[(u32, u32)] = &[
+    (0x1c5, 0x1c5),
+    (0x1c8, 0x1c8),
+    (0x1cb, 0x1cb),
+    (0x1f2, 0x1f2),
+    (0x3f4, 0x3f4),
+    (0x1e9e, 0x1e9e),
+    (0x1f88, 0x1f8f),
+    (0x1f98, 0x1f9f),
+    (0x1fa8, 0x1faf),
+    (0x1fbc, 0x1fbc),
+    (0x1fcc, 0x1fcc),
+    (0x1ffc, 0x1ffc),
+    (0x2126, 0x2126),
+    (0x212a, 0x212b),
+];
+
+/// The ranges of characters that have a case mapping or a special case folding. Only these
+/// are the same as another character when ignoring case, so sets are intersected with them
+/// instead of checking every character. The test `cased_table` keeps them in sync with the
+/// Unicode data of `core`.
+const CASED: &[(u32, u32)] = &[
+    (0x41, 0x5a),
+    (0x61, 0x7a),
+    (0xb5, 0xb5),
+    (0xc0, 0xd6),
+    (0xd8, 0xf6),
+    (0xf8, 0x137),
+    (0x139, 0x18c),
+    (0x18e, 0x1a9),
+    (0x1ac, 0x1b9),
+    (0x1bc, 0x1bd),
+    (0x1bf, 0x1bf),
+    (0x1c4, 0x220),
+    (0x222, 0x233),
+    (0x23a, 0x254),
+    (0x256, 0x257),
+    (0x259, 0x259),
+    (0x25b, 0x25c),
+    (0x260, 0x261),
+    (0x263, 0x266),
+    (0x268, 0x26c),
+    (0x26f, 0x26f),
+    (0x271, 0x272),
+    (0x275, 0x275),
+    (0x27d, 0x27d),
+    (0x280, 0x280),
+    (0x282, 0x283),
+    (0x287, 0x28c),
+    (0x292, 0x292),
+    (0x29d, 0x29e),
+    (0x345, 0x345),
+    (0x370, 0x373),
+    (0x376, 0x377),
+    (0x37b, 0x37d),
+    (0x37f, 0x37f),
+    (0x386, 0x386),
+    (0x388, 0x38a),
+    (0x38c, 0x38c),
+    (0x38e, 0x3a1),
+    (0x3a3, 0x3d1),
+    (0x3d5, 0x3f5),
+    (0x3f7, 0x3fb),
+    (0x3fd, 0x481),
+    (0x48a, 0x52f),
+    (0x531, 0x556),
+    (0x561, 0x587),
+    (0x10a0, 0x10c5),
+    (0x10c7, 0x10c7),
+    (0x10cd, 0x10cd),
+    (0x10d0, 0x10fa),
+    (0x10fd, 0x10ff),
+    (0x13a0, 0x13f5),
+    (0x13f8, 0x13fd),
+    (0x1c80, 0x1c8a),
+    (0x1c90, 0x1cba),
+    (0x1cbd, 0x1cbf),
+    (0x1d79, 0x1d79),
+    (0x1d7d, 0x1d7d),
+    (0x1d8e, 0x1d8e),
+    (0x1e00, 0x1e9b),
+    (0x1e9e, 0x1e9e),
+    (0x1ea0, 0x1f15),
+    (0x1f18, 0x1f1d),
+    (0x1f20, 0x1f45),
+    (0x1f48, 0x1f4d),
+    (0x1f50, 0x1f57),
+    (0x1f59, 0x1f59),
+    (0x1f5b, 0x1f5b),
+    (0x1f5d, 0x1f5d),
+    (0x1f5f, 0x1f7d),
+    (0x1f80, 0x1fb4),
+    (0x1fb6, 0x1fbc),
+    (0x1fbe, 0x1fbe),
+    (0x1fc2, 0x1fc4),
+    (0x1fc6, 0x1fcc),
+    (0x1fd0, 0x1fd3),
+    (0x1fd6, 0x1fdb),
+    (0x1fe0, 0x1fec),
+    (0x1ff2, 0x1ff4),
+    (0x1ff6, 0x1ffc),
+    (0x2126, 0x2126),
+    (0x212a, 0x212b),
+    (0x2132, 0x2132),
+    (0x214e, 0x214e),
+    (0x2160, 0x217f),
+    (0x2183, 0x2184),
+    (0x24b6, 0x24e9),
+    (0x2c00, 0x2c70),
+    (0x2c72, 0x2c73),
+    (0x2c75, 0x2c76),
+    (0x2c7e, 0x2ce3),
+    (0x2ceb, 0x2cee),
+    (0x2cf2, 0x2cf3),
+    (0x2d00, 0x2d25),
+    (0x2d27, 0x2d27),
+    (0x2d2d, 0x2d2d),
+    (0xa640, 0xa66d),
+    (0xa680, 0xa69b),
+    (0xa722, 0xa72f),
+    (0xa732, 0xa76f),
+    (0xa779, 0xa787),
+    (0xa78b, 0xa78d),
+    (0xa790, 0xa794),
+    (0xa796, 0xa7ae),
+    (0xa7b0, 0xa7dc),
+    (0xa7f5, 0xa7f6),
+    (0xab53, 0xab53),
+    (0xab70, 0xabbf),
+    (0xfb00, 0xfb06),
+    (0xfb13, 0xfb17),
+    (0xff21, 0xff3a),
+    (0xff41, 0xff5a),
+    (0x10400, 0x1044f),
+    (0x104b0, 0x104d3),
+    (0x104d8, 0x104fb),
+    (0x10570, 0x1057a),
+    (0x1057c, 0x1058a),
+    (0x1058c, 0x10592),
+    (0x10594, 0x10595),
+    (0x10597, 0x105a1),
+    (0x105a3, 0x105b1),
+    (0x105b3, 0x105b9),
+    (0x105bb, 0x105bc),
+    (0x10c80, 0x10cb2),
+    (0x10cc0, 0x10cf2),
+    (0x10d50, 0x10d65),
+    (0x10d70, 0x10d85),
+    (0x118a0, 0x118df),
+    (0x16e40, 0x16e7f),
+    (0x16ea0, 0x16eb8),
+    (0x16ebb, 0x16ed3),
+    (0x1e900, 0x1e943),
+];
+
+fn single(mut chars: impl Iterator<Item = char>) -> Option<u32> {
+    let c = chars.next()?;
+    if chars.next().is_some() {
+        return None;
+    }
+    Some(c as u32)
+}
+
+fn lowercase(cp: u32) -> u32 {
+    core::char::from_u32(cp)
+        .and_then(|c| single(c.to_lowercase()))
+        .unwrap_or(cp)
+}
+
+fn uppercase(cp: u32) -> u32 {
+    core::char::from_u32(cp)
+        .and_then(|c| single(c.to_uppercase()))
+        .unwrap_or(cp)
+}
+
+/// Returns the character `cp` is compared as when ignoring case, following `Canonicalize`
+/// of the specification: simple case folding with the `u` flag, and otherwise uppercase
+/// unless that turns a non-ASCII character into an ASCII one.
+fn canonicalize(cp: u32, unicode: bool) -> u32 {
+    if unicode {
+        return FOLDS
+            .iter()
+            .find(|&&(from, _)| from == cp)
+            .map_or_else(|| lowercase(cp), |&(_, to)| to);
+    }
+    let upper = uppercase(cp);
+    if cp >= 0x80 && upper < 0x80 {
+        return cp;
+    }
+    upper
+}
+
+/// Returns whether `f` holds for any other character that is the same as `cp` when ignoring
+/// case.
+pub(crate) fn any_other_case<F: FnMut(u32) -> bool>(cp: u32, unicode: bool, f: F) -> bool {
+    let canonical = canonicalize(cp, unicode);
+    let (lower, upper) = (lowercase(cp), uppercase(cp));
+    let candidates = [lower, upper, uppercase(lower), lowercase(upper), canonical];
+    candidates
+        .iter()
+        .copied()
+        .chain(
+            FOLDS
+                .iter()
+                .flat_map(|&(from, to)| [from, to, uppercase(to)]),
+        )
+        .chain(OTHER_UPPERCASE.iter().flat_map(|&(min, max)| min..=max))
+        .filter(|&other| other != cp && canonicalize(other, unicode) == canonical)
+        .any(f)
+}
+
+/// Returns the characters of `set` that may have another case.
+pub(crate) fn cased_part(set: &CharSet) -> CharSet {
+    set.intersect(&CharSet::from_ranges(CASED))
+}
+
+/// Returns whether ignoring case makes `set` match more characters.
+fn is_case_sensitive(set: &CharSet, unicode: bool) -> bool {
+    cased_part(set).ranges().iter().any(|&(min, max)| {
+        (min..=max).any(|cp| any_other_case(cp, unicode, |other| !set.contains(other)))
+    })
+}
+
+/// Returns whether `set` has a character with another case.
+fn has_cased_character(set: &CharSet, unicode: bool) -> bool {
+    cased_part(set)
+        .ranges()
+        .iter()
+        .any(|&(min, max)| (min..=max).any(|cp| any_other_case(cp, unicode, |_| true)))
+}
+
+/// Returns whether the escape sequences in `units` include a property escape.
+fn has_property_escape(units: &[u16]) -> bool {
+    let mut i = 0;
+    while i + 1 < units.len() {
+        if units[i] == '\\' as u16 {
+            if units[i + 1] == 'p' as u16 || units[i + 1] == 'P' as u16 {
+                return true;
+            }
+            i += 1;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// What a pattern contains that flags can have an effect on.
+#[derive(Default)]
+struct Scan {
+    anchors: bool,
+    dot: bool,
+    case_sensitive: bool,
+    cased: bool,
+    backreference: bool,
+}
+
+impl Scan {
+    fn visit(&mut self, node: &Node, units: &[u16], unicode: bool) {
+        match node {
+            Node::Chars(set, span) => {
+                let text = &units[span.clone()];
+                self.dot |= text == ['.' as u16];
+                self.case_sensitive = self.case_sensitive
+                    || (unicode && has_property_escape(text))
+                    || is_case_sensitive(set, unicode);
+                self.cased = self.cased || has_cased_character(set, unicode);
+            }
+            Node::Assertion(span) => {
+                let text = &units[span.clone()];
+                if text == ['^' as u16] || text == ['$' as u16] {
+                    self.anchors = true;
+                } else if unicode {
+                    // With `u` and `i`, `\b` counts `ſ` and `K` as word characters.
+                    self.case_sensitive = true;
+                }
+            }
+            Node::Backreference { .. } => self.backreference = true,
+            Node::Group { body, .. }
+            | Node::Lookaround { body, .. }
+            | Node::Quantified { body, .. } => self.visit(body, units, unicode),
+            Node::Alternation(nodes) | Node::Sequence(nodes) => nodes
+                .iter()
+                .for_each(|node| self.visit(node, units, unicode)),
+            Node::Empty => {}
+        }
+    }
+}
+
+/// Returns whether every match of `node` has to start with `^`.
+fn starts_with_caret(node: &Node, units: &[u16]) -> bool {
+    match node {
+        Node::Assertion(span) => units[span.clone()] == ['^' as u16],
+        Node::Group { body, .. } => starts_with_caret(body, units),
+        Node::Sequence(nodes) => matches!(nodes.first(), Some(n) if starts_with_caret(n, units)),
+        Node::Alternation(nodes) => nodes.iter().all(|n| starts_with_caret(n, units)),
+        _ => false,
+    }
+}
+
+impl EcmaRegexValidator {
+    /// Validates the regular expression literal `/source/flags` and finds the flags that
+    /// have no effect on it, in the order they are written. `usage` tells how the regular
+    /// expression is used, which decides whether `g` has an effect.
+    ///
+    /// Case-insensitive matching is checked with the case mappings of the Rust standard
+    /// library, and property escapes are assumed to depend on case.
+    pub fn find_useless_flags(
+        &mut self,
+        source: &str,
+        flags: &str,
+        usage: Usage,
+    ) -> Result<Vec<UselessFlag>, String> {
+        self.validate_flags(flags)?;
+        let unicode = flags.contains('u');
+        let tree = self.parse_tree(source, &flags.replace('i', ""))?;
+        let units: Vec<u16> = source.encode_utf16().collect();
+        let mut scan = Scan::default();
+        scan.visit(&tree, &units, unicode);
+
+        let mut useless = Vec::new();
+        for flag in flags.chars() {
+            let reason = match flag {
+                'i' if !(scan.case_sensitive || scan.backreference && scan.cased) => {
+                    UselessFlagReason::NoCasedCharacters
+                }
+                'm' if !scan.anchors => UselessFlagReason::NoAnchors,
+                's' if !scan.dot => UselessFlagReason::NoDot,
+                'y' if flags.contains('g')
+                    && !flags.contains('m')
+                    && starts_with_caret(&tree, &units) =>
+                {
+                    UselessFlagReason::AnchoredToStart
+                }
+                'g' if usage != Usage::Unknown => UselessFlagReason::NotIterated,
+                _ => continue,
+            };
+            useless.push(UselessFlag { flag, reason });
+        }
+        return Ok(useless);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EcmaVersion;
+    use alloc::string::ToString;
+
+    fn find(source: &str, flags: &str) -> String {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let useless = validator
+            .find_useless_flags(source, flags, Usage::Unknown)
+            .unwrap();
+        useless.iter().map(|u| u.flag).collect()
+    }
+
+    #[test]
+    fn ignore_case() {
+        assert_eq!(find("\\d+", "gi"), "i");
+        assert_eq!(find("[^\\s_-]\\w", "i"), "i");
+        assert_eq!(find("\u{3b1}", "i"), "");
+        assert_eq!(find("[^a]", "i"), "");
+        assert_eq!(find("a[0-9]", "i"), "");
+        // `\w` matches `ſ` and `K` when ignoring case with the `u` flag.
+        assert_eq!(find("\\w", "iu"), "");
+        assert_eq!(find("\\b1", "iu"), "");
+        assert_eq!(find("(.)\\1", "i"), "");
+        assert_eq!(find("(\\d)\\1", "i"), "i");
+        assert_eq!(find("[\\u{0}-\\u{87FFF}]", "iu"), "i");
+        assert_eq!(find("[\\u{0}-\\u{10FFFF}]", "iu"), "i");
+        assert_eq!(find("[\\0-\\u{4B}\\u{4D}-\\u{10FFFF}]", "iu"), "");
+    }
+
+    /// Returns whether `cp` has a case mapping or a special case folding.
+    fn has_other_case(cp: u32) -> bool {
+        let c = match core::char::from_u32(cp) {
+            Some(c) => c,
+            None => return false,
+        };
+        !c.to_lowercase().eq(Some(c))
+            || !c.to_uppercase().eq(Some(c))
+            || FOLDS.iter().any(|&(from, to)| from == cp || to == cp)
+    }
+
+    #[test]
+    fn cased_table() {
+        let cased = CharSet::from_ranges(CASED);
+        assert_eq!(cased.ranges(), CASED);
+        for cp in 0..=0x10ffff {
+            assert_eq!(cased.contains(cp), has_other_case(cp), "U+{:04X}", cp);
+        }
+    }
+
+    #[cfg(feature = "unicode-properties")]
+    #[test]
+    fn ignore_case_property_escapes() {
+        assert_eq!(find("\\p{Lu}", "iu"), "");
+        assert_eq!(find("\\P{Nd}", "iu"), "");
+    }
+
+    #[test]
+    fn other_flags() {
+        assert_eq!(find("a", "m"), "m");
+        assert_eq!(find("(?=a$)", "m"), "");
+        assert_eq!(find("[.]", "s"), "s");
+        assert_eq!(find("a.", "s"), "");
+        assert_eq!(find("^a|^b", "gy"), "y");
+        assert_eq!(find("^a|b", "gy"), "");
+        assert_eq!(find("^a", "y"), "");
+        assert_eq!(find("^a", "gmy"), "");
+    }
+
+    #[test]
+    fn usage() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let useless = validator.find_useless_flags("a", "g", Usage::Test).unwrap();
+        assert_eq!(
+            useless,
+            vec![UselessFlag {
+                flag: 'g',
+                reason: UselessFlagReason::NotIterated
+            }]
+        );
+        assert_eq!(
+            useless[0].to_string(),
+            "the g flag has no effect: only a single match is looked for"
+        );
+        let useless = validator
+            .find_useless_flags("\\d+", "gi", Usage::Test)
+            .unwrap();
+        let flags: String = useless.iter().map(|u| u.flag).collect();
+        assert_eq!(flags, "gi");
+        assert!(validator
+            .find_useless_flags("a", "gg", Usage::Search)
+            .is_err());
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::empty::Suggestion;
+use crate::validator::EcmaRegexValidator;
+
+/// What makes a character in a pattern hard to see for what it is.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum HiddenKind {
+    /// A character that renders as nothing, like a zero width space.
+    Invisible,
+    /// A control that changes the direction in which the text around it is displayed.
+    Bidi,
+    /// A letter of another script that looks like an ASCII letter, in an otherwise ASCII
+    /// pattern, like the Cyrillic `а` in `[а-z]`.
+    Homoglyph,
+}
+
+impl fmt::Display for HiddenKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            HiddenKind::Invisible => "invisible character",
+            HiddenKind::Bidi => "bidirectional control character",
+            HiddenKind::Homoglyph => "character that looks like an ASCII letter",
+        })
+    }
+}
+
+/// A character written literally in a pattern that does not look like what it is.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct HiddenCharacter {
+    pub kind: HiddenKind,
+    pub value: u32,
+    /// The position of the character, counted in UTF-16 code units.
+    pub span: Range<usize>,
+    /// For homoglyphs, the ASCII letter the character looks like.
+    pub looks_like: Option<char>,
+    /// The replacement with a `\u` escape.
+    pub fix: Suggestion,
+}
+
+impl fmt::Display for HiddenCharacter {
+    /// Formats the character like "invisible character U+200B".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} U+{:04X}", self.kind, self.value)?;
+        if let Some(c) = self.looks_like {
+            write!(f, " (looks like {})", c)?;
+        }
+        Ok(())
+    }
+}
+
+const INVISIBLE: &[u32] = &[0xad, 0x200b, 0x200c, 0x200d, 0x2060, 0xfeff];
+
+const BIDI: &[(u32, u32)] = &[
+    (0x61c, 0x61c),
+    (0x200e, 0x200f),
+    (0x202a, 0x202e),
+    (0x2066, 0x2069),
+];
+
+/// Cyrillic and Greek letters that look like ASCII letters.
+const HOMOGLYPHS: &[(u32, char)] = &[
+    (0x391, 'A'),
+    (0x392, 'B'),
+    (0x395, 'E'),
+    (0x396, 'Z'),
+    (0x397, 'H'),
+    (0x399, 'I'),
+    (0x39a, 'K'),
+    (0x39c, 'M'),
+    (0x39d, 'N'),
+    (0x39f, 'O'),
+    (0x3a1, 'P'),
+    (0x3a4, 'T'),
+    (0x3a5, 'Y'),
+    (0x3a7, 'X'),
+    (0x3bd, 'v'),
+    (0x3bf, 'o'),
+    (0x405, 'S'),
+    (0x406, 'I'),
+    (0x408, 'J'),
+    (0x410, 'A'),
+    (0x412, 'B'),
+    (0x415, 'E'),
+    (0x41a, 'K'),
+    (0x41c, 'M'),
+    (0x41d, 'H'),
+    (0x41e, 'O'),
+    (0x420, 'P'),
+    (0x421, 'C'),
+    (0x422, 'T'),
+    (0x423, 'Y'),
+    (0x425, 'X'),
+    (0x430, 'a'),
+    (0x435, 'e'),
+    (0x43e, 'o'),
+    (0x440, 'p'),
+    (0x441, 'c'),
+    (0x443, 'y'),
+    (0x445, 'x'),
+    (0x455, 's'),
+    (0x456, 'i'),
+    (0x458, 'j'),
+    (0x4bb, 'h'),
+    (0x501, 'd'),
+    (0x51b, 'q'),
+    (0x51d, 'w'),
+];
+
+/// Whether `cp` is a pictograph or emoji modifier that a zero width joiner can join.
+fn is_emoji(cp: u32) -> bool {
+    matches!(cp, 0x2300..=0x23ff | 0x2600..=0x27bf | 0x2b00..=0x2bff | 0xfe0f | 0x1f000..=0x1faff)
+}
+
+fn homoglyph(cp: u32) -> Option<char> {
+    HOMOGLYPHS
+        .iter()
+        .find(|&&(homoglyph, _)| homoglyph == cp)
+        .map(|&(_, ascii)| ascii)
+}
+
+impl EcmaRegexValidator {
+    /// Finds the characters in the regular expression literal `/source/flags` that are
+    /// written literally but do not look like what they are: invisible characters,
+    /// bidirectional controls, and Cyrillic or Greek letters that look like ASCII letters in
+    /// a pattern that is otherwise ASCII. Zero width joiners between emoji are not reported.
+    ///
+    /// Only the flags have to be valid. Hidden characters are a common reason for a pattern
+    /// to be invalid, like the Cyrillic `а` in `[а-z]` that puts the range out of order.
+    pub fn find_hidden_characters(
+        &mut self,
+        source: &str,
+        flags: &str,
+    ) -> Result<Vec<HiddenCharacter>, String> {
+        self.validate_flags(flags)?;
+        let chars: Vec<char> = source.chars().collect();
+        let otherwise_ascii = chars
+            .iter()
+            .all(|&c| c.is_ascii() || homoglyph(c as u32).is_some());
+
+        let mut found = Vec::new();
+        let mut index = 0;
+        for (i, &c) in chars.iter().enumerate() {
+            let (start, cp) = (index, c as u32);
+            index += c.len_utf16();
+            let joins_emoji = || {
+                (cp == 0x200c || cp == 0x200d)
+                    && i > 0
+                    && is_emoji(chars[i - 1] as u32)
+                    && matches!(chars.get(i + 1), Some(&next) if is_emoji(next as u32))
+            };
+            let (kind, looks_like) = if INVISIBLE.contains(&cp) && !joins_emoji() {
+                (HiddenKind::Invisible, None)
+            } else if BIDI.iter().any(|&(min, max)| min <= cp && cp <= max) {
+                (HiddenKind::Bidi, None)
+            } else if let Some(ascii) = homoglyph(cp).filter(|_| otherwise_ascii) {
+                (HiddenKind::Homoglyph, Some(ascii))
+            } else {
+                continue;
+            };
+
+            // An identity escape like `\` followed by the character becomes a `\u` escape.
+            let backslashes = chars[..i].iter().rev().take_while(|&&c| c == '\\').count();
+            let fix_start = if backslashes % 2 == 1 {
+                start - 1
+            } else {
+                start
+            };
+            found.push(HiddenCharacter {
+                kind,
+                value: cp,
+                span: start..index,
+                looks_like,
+                fix: Suggestion {
+                    span: fix_start..index,
+                    replacement: format!("\\u{:04X}", cp),
+                },
+            });
+        }
+        return Ok(found);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EcmaVersion;
+    use alloc::string::ToString;
+
+    fn find(source: &str, flags: &str) -> Vec<(HiddenKind, Range<usize>, String)> {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let found = validator.find_hidden_characters(source, flags).unwrap();
+        found
+            .into_iter()
+            .map(|h| (h.kind, h.span, h.fix.replacement))
+            .collect()
+    }
+
+    #[test]
+    fn invisible() {
+        use HiddenKind::*;
+        assert_eq!(
+            find("a\u{200b}b[\u{ad}]", ""),
+            vec![
+                (Invisible, 1..2, "\\u200B".to_string()),
+                (Invisible, 4..5, "\\u00AD".to_string()),
+            ]
+        );
+        assert_eq!(find("\u{feff}", "u")[0].0, Invisible);
+        assert_eq!(find("x\u{200d}y", "")[0].1, 1..2);
+        assert_eq!(find("\u{1f468}\u{200d}\u{1f469}", "u"), vec![]);
+    }
+
+    #[test]
+    fn bidi() {
+        let found = find("a\u{202e}b\u{2066}", "");
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0], (HiddenKind::Bidi, 1..2, "\\u202E".to_string()));
+    }
+
+    #[test]
+    fn homoglyphs() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let found = validator.find_hidden_characters("[\u{430}-z]", "").unwrap();
+        assert_eq!(found[0].kind, HiddenKind::Homoglyph);
+        assert_eq!(found[0].looks_like, Some('a'));
+        assert_eq!(found[0].fix.replacement, "\\u0430");
+        assert_eq!(
+            found[0].to_string(),
+            "character that looks like an ASCII letter U+0430 (looks like a)"
+        );
+        // In a Cyrillic pattern the letters are meant as they are.
+        assert_eq!(find("[\u{430}-\u{44f}]", ""), vec![]);
+    }
+
+    #[test]
+    fn escaped() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let found = validator.find_hidden_characters("a\\\u{200b}", "").unwrap();
+        assert_eq!(found[0].span, 2..3);
+        assert_eq!(found[0].fix.span, 1..3);
+        let found = validator
+            .find_hidden_characters("\\\\\u{200b}", "")
+            .unwrap();
+        assert_eq!(found[0].fix.span, 2..3);
+        assert!(validator.find_hidden_characters("a", "gg").is_err());
+    }
+}
@@ -1,13 +1,56 @@
 // Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
 // Distributed under terms of the MIT license.
 
-#[macro_use]
-extern crate lazy_static;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![allow(clippy::needless_return)]
 
+extern crate alloc;
+
+mod annex_b;
+mod backreference;
+mod batch;
+#[cfg(feature = "std")]
+mod cache;
+mod compat;
+mod control;
+mod duplicates;
+mod empty;
+mod features;
+mod flags;
+mod hidden;
+mod misleading;
+#[cfg(feature = "unicode-properties")]
+mod property;
 mod reader;
+mod redos;
+mod redundant;
+mod suspicious;
+mod tree;
+mod u_flag;
 mod unicode;
+mod v_flag;
 mod validator;
 
+pub use annex_b::{AnnexB, AnnexBUsage};
+pub use backreference::{BackreferenceProblem, UselessBackreference};
+#[cfg(feature = "std")]
+pub use cache::{CacheStats, ValidationCache};
+pub use compat::{check_compatibility, Engine, Incompatibility, Target};
+pub use control::ControlCharacter;
+pub use duplicates::{Duplicate, DuplicateKind};
+pub use empty::{EmptyConstruct, EmptyKind, Suggestion};
+pub use features::{required_version, Feature, RequiredVersion};
+pub use flags::{Usage, UselessFlag, UselessFlagReason};
+pub use hidden::{HiddenCharacter, HiddenKind};
+pub use misleading::{MisleadingClass, MisleadingFix, MisleadingKind};
+#[cfg(feature = "unicode-properties")]
+pub use property::{PropertyAlias, PropertyKind, UnicodeProperty};
+pub use redos::{Complexity, RedosFinding};
+pub use redundant::{Redundancy, RedundancyKind};
+pub use suspicious::{RangeProblem, SuspiciousRange};
+pub use u_flag::{UFlagAnalysis, UFlagChange, UFlagFinding};
+pub use unicode::UnicodeVersion;
+pub use v_flag::{VFlagAnalysis, VFlagChange, VFlagFinding};
 pub use validator::{EcmaRegexValidator, EcmaVersion};
 
 #[cfg(test)]
@@ -56,6 +99,21 @@ mod tests {
         assert_eq!(validator.validate_flags("1"), Err("Invalid flag 1".to_string()));
     }
 
+    #[test]
+    fn flags_of_newer_versions() {
+        let validator = EcmaRegexValidator::new(EcmaVersion::ES2015);
+        assert_eq!(
+            validator.validate_flags("gs"),
+            Err("The s flag requires ES2018 (configured: ES2015)".to_string())
+        );
+        let validator = EcmaRegexValidator::new(EcmaVersion::ES5);
+        assert_eq!(
+            validator.validate_flags("y"),
+            Err("The y flag requires ES2015 (configured: ES5)".to_string())
+        );
+        assert_eq!(validator.validate_flags("f"), Err("Invalid flag f".to_string()));
+    }
+
     #[test]
     fn validate_pattern_test() {
         let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
@@ -73,9 +131,6 @@ mod tests {
         assert_eq!(validator.validate_pattern("(?:\\d{3}|\\(\\d{3}\\))([-\\/\\.])\\d{3}\\1\\d{4}", false), Ok(()));
         assert_eq!(validator.validate_pattern("https?:\\/\\/(www\\.)?[-a-zA-Z0-9@:%._\\+~#=]{1,256}\\.[a-zA-Z0-9()]{1,6}\\b([-a-zA-Z0-9()@:%_\\+.~#?&//=]*)", false), Ok(()));
 
-        assert_eq!(validator.validate_pattern("\\p{Script=Greek}", true), Ok(()));
-        assert_eq!(validator.validate_pattern("\\p{Alphabetic}", true), Ok(()));
-
         assert_ne!(validator.validate_pattern("\\", false), Ok(()));
         assert_ne!(validator.validate_pattern("a**", false), Ok(()));
         assert_ne!(validator.validate_pattern("++a", false), Ok(()));
@@ -100,6 +155,25 @@ mod tests {
         assert_ne!(validator.validate_pattern("\\1(\\P{P\0[}()/", true), Ok(()));
     }
 
+    #[cfg(feature = "unicode-properties")]
+    #[test]
+    fn validate_property_escapes_test() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        assert_eq!(validator.validate_pattern("\\p{Script=Greek}", true), Ok(()));
+        assert_eq!(validator.validate_pattern("\\p{Alphabetic}", true), Ok(()));
+    }
+
+    #[test]
+    fn negated_class_with_dash() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        assert_eq!(validator.validate_pattern("[^- ]", false), Ok(()));
+        assert_eq!(validator.validate_pattern("[^-a]", false), Ok(()));
+        assert_eq!(validator.validate_pattern("[^-!]", true), Ok(()));
+        assert_eq!(validator.validate_pattern("[^-]", true), Ok(()));
+        assert_eq!(validator.validate_pattern("[^^-a]", false), Ok(()));
+        assert_ne!(validator.validate_pattern("[^z-a]", false), Ok(()));
+    }
+
     #[test]
     fn character_range_order() {
         let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
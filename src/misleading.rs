@@ -0,0 +1,361 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::unicode::{is_large_id_continue, UnicodeVersion};
+use crate::validator::EcmaRegexValidator;
+
+/// A sequence in a character class that looks like one character but is several, so the
+/// class matches each of them on its own.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum MisleadingKind {
+    /// A surrogate pair without the `u` flag, like `[👍]`.
+    SurrogatePair,
+    /// A character followed by combining marks, like `[é]` spelled with U+0301.
+    CombiningMark,
+    /// Two regional indicators that form a flag, like `[🇯🇵]`.
+    RegionalIndicator,
+    /// An emoji followed by a skin tone modifier, like `[👶🏻]`.
+    EmojiModifier,
+    /// Characters joined by U+200D ZERO WIDTH JOINER, like `[👨‍👩‍👦]`.
+    ZeroWidthJoiner,
+}
+
+impl fmt::Display for MisleadingKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MisleadingKind::SurrogatePair => "surrogate pair without the u flag",
+            MisleadingKind::CombiningMark => "character with combining marks",
+            MisleadingKind::RegionalIndicator => "regional indicator pair",
+            MisleadingKind::EmojiModifier => "emoji with a modifier",
+            MisleadingKind::ZeroWidthJoiner => "zero width joiner sequence",
+        })
+    }
+}
+
+/// How to make a misleading character class match what it looks like.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum MisleadingFix {
+    /// Add the `u` flag, which reads the surrogate pairs as one character.
+    AddUFlag,
+    /// Replace the class with this alternation, which matches the sequences as a whole.
+    Alternation(String),
+}
+
+/// A misleading sequence in a character class.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct MisleadingClass {
+    pub kind: MisleadingKind,
+    /// The position of the sequence, counted in UTF-16 code units.
+    pub span: Range<usize>,
+    /// The position of the whole character class.
+    pub class: Range<usize>,
+    /// The fix, or `None` for negated classes, which no alternation can replace.
+    pub fix: Option<MisleadingFix>,
+}
+
+const ZERO_WIDTH_JOINER: u32 = 0x200d;
+
+fn is_lead_surrogate(cp: u32) -> bool {
+    (0xd800..=0xdbff).contains(&cp)
+}
+
+fn is_trail_surrogate(cp: u32) -> bool {
+    (0xdc00..=0xdfff).contains(&cp)
+}
+
+fn is_regional_indicator(cp: u32) -> bool {
+    (0x1f1e6..=0x1f1ff).contains(&cp)
+}
+
+fn is_emoji_modifier(cp: u32) -> bool {
+    (0x1f3fb..=0x1f3ff).contains(&cp)
+}
+
+/// Approximates `General_Category=Mark` without a table of its own: the code points in
+/// `ID_Continue` but not `ID_Start` are marks, digits and connector punctuation, and the
+/// enclosing marks are missing from them.
+fn is_combining_mark(cp: u32) -> bool {
+    const ENCLOSING_MARKS: &[(u32, u32)] = &[
+        (0x488, 0x489),
+        (0x1abe, 0x1abe),
+        (0x20dd, 0x20e0),
+        (0x20e2, 0x20e4),
+        (0xa670, 0xa672),
+    ];
+    const NOT_MARKS: &[(u32, u32)] = &[
+        (0xb7, 0xb7),
+        (0x387, 0x387),
+        (0x200c, 0x200d),
+        (0x203f, 0x2040),
+        (0x2054, 0x2054),
+        (0x30fb, 0x30fb),
+        (0xfe33, 0xfe34),
+        (0xfe4d, 0xfe4f),
+        (0xff3f, 0xff3f),
+        (0xff65, 0xff65),
+    ];
+    let within = |ranges: &[(u32, u32)]| ranges.iter().any(|&(min, max)| min <= cp && cp <= max);
+    if within(ENCLOSING_MARKS) {
+        return true;
+    }
+    cp >= 0x300
+        && is_large_id_continue(cp, UnicodeVersion::LATEST)
+        && !within(NOT_MARKS)
+        && !core::char::from_u32(cp).is_some_and(char::is_numeric)
+}
+
+/// Builds an alternation that matches the misleading `sequences` of a class as a whole and
+/// the rest of the class as before, like `(?:👍|[ab])` for `[a👍b]`.
+fn alternation(units: &[u16], class: &Range<usize>, sequences: &[Range<usize>]) -> String {
+    let text = |range: Range<usize>| String::from_utf16_lossy(&units[range]);
+    let mut alternatives: Vec<String> = Vec::new();
+    let mut rest = String::new();
+    let mut last = class.start + 1;
+    for span in sequences {
+        rest.push_str(&text(last..span.start));
+        let sequence = text(span.clone());
+        if !alternatives.contains(&sequence) {
+            alternatives.push(sequence);
+        }
+        last = span.end;
+    }
+    rest.push_str(&text(last..class.end - 1));
+    if !rest.is_empty() {
+        alternatives.push(format!("[{}]", rest));
+    }
+    format!("(?:{})", alternatives.join("|"))
+}
+
+impl EcmaRegexValidator {
+    /// Validates the regular expression literal `/source/flags` and finds the sequences in
+    /// its character classes that look like one character but match as several, like
+    /// ESLint's `no-misleading-character-class`.
+    ///
+    /// Combining marks are recognized from the identifier tables, so without the
+    /// `unicode-identifiers` feature only the enclosing marks are.
+    pub fn find_misleading_classes(
+        &mut self,
+        source: &str,
+        flags: &str,
+    ) -> Result<Vec<MisleadingClass>, String> {
+        self.validate_flags(flags)?;
+        let u_flag = flags.contains('u');
+        let mut findings = self.collect_misleading(source, u_flag)?;
+
+        // The u flag fixes a class if it has no misleading sequence left with it.
+        let with_u = match !u_flag && self.validate_pattern(source, true).is_ok() {
+            true => Some(self.collect_misleading(source, true)?),
+            false => None,
+        };
+        // Where sequences are left with it, they say what the class looks like, so they
+        // replace the surrogate pairs they consist of.
+        if let Some(with_u) = &with_u {
+            let covered = |finding: &MisleadingClass| {
+                with_u.iter().any(|other| {
+                    other.span.start <= finding.span.start && finding.span.end <= other.span.end
+                })
+            };
+            findings.retain(|finding| !covered(finding));
+            findings.extend(with_u.iter().cloned());
+            findings.sort_by_key(|finding| (finding.span.start, finding.span.end));
+        }
+        let units: Vec<u16> = source.encode_utf16().collect();
+        let sequences: Vec<(Range<usize>, Range<usize>)> = findings
+            .iter()
+            .map(|finding| (finding.class.clone(), finding.span.clone()))
+            .collect();
+        for finding in &mut findings {
+            let fixed_by_u = with_u.as_ref().is_some_and(|with_u| {
+                with_u.iter().all(|other| other.class != finding.class)
+            });
+            finding.fix = if fixed_by_u {
+                Some(MisleadingFix::AddUFlag)
+            } else if units.get(finding.class.start + 1) == Some(&('^' as u16)) {
+                None
+            } else {
+                let in_class: Vec<Range<usize>> = sequences
+                    .iter()
+                    .filter(|(class, _)| *class == finding.class)
+                    .map(|(_, span)| span.clone())
+                    .collect();
+                Some(MisleadingFix::Alternation(alternation(
+                    &units,
+                    &finding.class,
+                    &in_class,
+                )))
+            };
+        }
+        return Ok(findings);
+    }
+
+    fn collect_misleading(
+        &mut self,
+        source: &str,
+        u_flag: bool,
+    ) -> Result<Vec<MisleadingClass>, String> {
+        self.misleading = Some(Vec::new());
+        let result = self.validate_pattern(source, u_flag);
+        let findings = self.misleading.take().unwrap_or_default();
+        result?;
+        Ok(findings)
+    }
+
+    /// Notes a character of the current class, or a range or class escape if `value` is
+    /// `None`, for `find_misleading_classes`.
+    pub(crate) fn record_class_char(&mut self, value: Option<i64>, span: Range<usize>) {
+        if self.misleading.is_some() {
+            let value = value.filter(|&v| v >= 0).map(|v| v as u32);
+            self.class_chars.push(value.map(|v| (v, span)));
+        }
+    }
+
+    /// Looks for misleading sequences in the characters of the class that ends at `class`.
+    pub(crate) fn check_misleading_class(&mut self, class: Range<usize>) {
+        let chars = core::mem::take(&mut self.class_chars);
+        let findings = match &mut self.misleading {
+            Some(findings) => findings,
+            None => return,
+        };
+        let value = |i: usize| chars.get(i).and_then(|c| c.as_ref()).map(|c| c.0);
+        let mut i = 0;
+        while i < chars.len() {
+            let first = match &chars[i] {
+                Some((first, _)) => *first,
+                None => {
+                    i += 1;
+                    continue;
+                }
+            };
+            let mut kind = None;
+            let mut end = i + 1;
+            if is_lead_surrogate(first) && value(i + 1).is_some_and(is_trail_surrogate) {
+                kind = Some(MisleadingKind::SurrogatePair);
+                end = i + 2;
+            } else if is_regional_indicator(first) && value(i + 1).is_some_and(is_regional_indicator)
+            {
+                kind = Some(MisleadingKind::RegionalIndicator);
+                end = i + 2;
+            } else if !is_combining_mark(first) {
+                while let Some(next) = value(end) {
+                    let (next_kind, len) = if next == ZERO_WIDTH_JOINER && value(end + 1).is_some() {
+                        (MisleadingKind::ZeroWidthJoiner, 2)
+                    } else if is_emoji_modifier(next) {
+                        (MisleadingKind::EmojiModifier, 1)
+                    } else if is_combining_mark(next) {
+                        (MisleadingKind::CombiningMark, 1)
+                    } else {
+                        break;
+                    };
+                    // A joiner makes the whole sequence one, the first other reason names it.
+                    if kind.is_none() || next_kind == MisleadingKind::ZeroWidthJoiner {
+                        kind = Some(next_kind);
+                    }
+                    end += len;
+                }
+            }
+            if let Some(kind) = kind {
+                let start = chars[i].as_ref().map_or(0, |c| c.1.start);
+                let stop = chars[end - 1].as_ref().map_or(start, |c| c.1.end);
+                findings.push(MisleadingClass {
+                    kind,
+                    span: start..stop,
+                    class: class.clone(),
+                    fix: None,
+                });
+            }
+            i = end;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EcmaVersion;
+
+    fn find(source: &str, flags: &str) -> Vec<MisleadingClass> {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        validator.find_misleading_classes(source, flags).unwrap()
+    }
+
+    fn kinds(source: &str, flags: &str) -> Vec<(MisleadingKind, Range<usize>)> {
+        let findings = find(source, flags);
+        findings.into_iter().map(|f| (f.kind, f.span)).collect()
+    }
+
+    #[test]
+    fn surrogate_pairs() {
+        let findings = find("[a👍]", "");
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].kind, MisleadingKind::SurrogatePair);
+        assert_eq!(findings[0].span, 2..4);
+        assert_eq!(findings[0].class, 0..5);
+        assert_eq!(findings[0].fix, Some(MisleadingFix::AddUFlag));
+        assert_eq!(
+            kinds("[\\uD83D\\uDC4D]", ""),
+            vec![(MisleadingKind::SurrogatePair, 1..13)]
+        );
+        assert_eq!(kinds("[a👍]", "u"), vec![]);
+        assert_eq!(kinds("👍", ""), vec![]);
+    }
+
+    #[test]
+    fn emoji_sequences() {
+        assert_eq!(
+            kinds("[🇯🇵]", "u"),
+            vec![(MisleadingKind::RegionalIndicator, 1..5)]
+        );
+        assert_eq!(
+            kinds("[👶🏻]", "u"),
+            vec![(MisleadingKind::EmojiModifier, 1..5)]
+        );
+        assert_eq!(
+            kinds("[👨\\u{200d}👩]", "u"),
+            vec![(MisleadingKind::ZeroWidthJoiner, 1..13)]
+        );
+        // With the u flag the pairs are gone, but the modifier is still misleading.
+        let findings = find("[👶🏻]", "");
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].kind, MisleadingKind::EmojiModifier);
+        assert_eq!(
+            findings[0].fix,
+            Some(MisleadingFix::Alternation("(?:👶🏻)".into()))
+        );
+        assert_eq!(
+            kinds("[a👶🏻😀]", ""),
+            vec![
+                (MisleadingKind::EmojiModifier, 2..6),
+                (MisleadingKind::SurrogatePair, 6..8)
+            ]
+        );
+        assert_eq!(
+            find("[a👶🏻😀]", "")[1].fix,
+            Some(MisleadingFix::Alternation("(?:👶🏻|😀|[a])".into()))
+        );
+    }
+
+    #[cfg(feature = "unicode-identifiers")]
+    #[test]
+    fn combining_marks() {
+        let findings = find("[xe\\u0301y]", "");
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].kind, MisleadingKind::CombiningMark);
+        assert_eq!(findings[0].span, 2..9);
+        assert_eq!(
+            findings[0].fix,
+            Some(MisleadingFix::Alternation("(?:e\\u0301|[xy])".into()))
+        );
+        assert_eq!(find("[^e\\u0301]", "")[0].fix, None);
+        assert_eq!(kinds("[\\u0301e]", ""), vec![]);
+        assert_eq!(kinds("[a-e\\u0301]", ""), vec![]);
+        assert_eq!(kinds("[e\\u0966]", ""), vec![]);
+        assert!(is_combining_mark(0x20dd));
+        assert!(!is_combining_mark('_' as u32));
+    }
+}
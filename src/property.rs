@@ -0,0 +1,208 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::unicode::{self, UnicodeVersion, ValueTable};
+use crate::validator::{EcmaRegexValidator, EcmaVersion};
+
+/// The kinds of properties whose names or values can be used in `\p{...}`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum PropertyKind {
+    /// Values of `General_Category`, like `Lu` in `\p{gc=Lu}` or `\p{Lu}`.
+    GeneralCategory,
+    /// Values of `Script`, like `Grek` in `\p{sc=Grek}`.
+    Script,
+    /// Values of `Script_Extensions`, like `Grek` in `\p{scx=Grek}`.
+    ScriptExtensions,
+    /// Binary properties, like `Alpha` in `\p{Alpha}`.
+    Binary,
+}
+
+impl PropertyKind {
+    fn table(self) -> ValueTable {
+        match self {
+            PropertyKind::GeneralCategory => unicode::GC_VALUE_PATTERNS,
+            PropertyKind::Script | PropertyKind::ScriptExtensions => unicode::SC_VALUE_PATTERNS,
+            PropertyKind::Binary => unicode::BIN_PROPERTY_PATTERNS,
+        }
+    }
+}
+
+/// A name that can be used in `\p{...}`, either a canonical name or one of its aliases.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct PropertyAlias {
+    /// The name as it can be written in a pattern, e.g. `Lu`.
+    pub name: &'static str,
+    /// The canonical name it stands for, e.g. `Uppercase_Letter`.
+    pub canonical: &'static str,
+    /// The Unicode version that introduced it.
+    pub unicode_version: UnicodeVersion,
+}
+
+impl PropertyAlias {
+    /// Returns the oldest `EcmaVersion` that accepts this name with its default Unicode
+    /// version, or `None` if it is newer than the Unicode version of every edition.
+    pub fn ecma_version(&self) -> Option<EcmaVersion> {
+        EcmaVersion::ALL.iter().copied().find(|&v| {
+            v >= EcmaVersion::ES2018 && UnicodeVersion::of_ecma_version(v) >= self.unicode_version
+        })
+    }
+}
+
+/// A property expression of `\p{...}` with all aliases resolved to canonical names.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct UnicodeProperty {
+    /// The canonical property name, e.g. `General_Category` or `Alphabetic`.
+    pub name: &'static str,
+    /// The canonical value, e.g. `Uppercase_Letter`, or `None` for binary properties.
+    pub value: Option<&'static str>,
+}
+
+impl fmt::Display for UnicodeProperty {
+    /// Formats the property like it is written in a pattern,
+    /// e.g. `General_Category=Uppercase_Letter` or `Alphabetic`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value {
+            Some(value) => write!(f, "{}={}", self.name, value),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+impl EcmaRegexValidator {
+    /// Resolves the contents of a `\p{...}` escape to canonical names, e.g. `gc=Lu` to
+    /// `General_Category=Uppercase_Letter`, `sc=Grek` to `Script=Greek` and `Alpha` to
+    /// `Alphabetic`. A lone general category value like `Lu` resolves like `gc=Lu`.
+    ///
+    /// Returns `None` if the expression is not valid with the `EcmaVersion` and
+    /// `UnicodeVersion` of this validator. Properties of strings are not resolved.
+    pub fn resolve_unicode_property(&self, expression: &str) -> Option<UnicodeProperty> {
+        if self.ecma_version() < EcmaVersion::ES2018 {
+            return None;
+        }
+        let version = self.unicode_version();
+        if let Some(i) = expression.find('=') {
+            let name = unicode::canonical_property_name(&expression[..i])?;
+            let table = unicode::value_table(name)?;
+            let value = unicode::lookup(table, version, &expression[i + 1..])?;
+            return Some(UnicodeProperty {
+                name,
+                value: Some(value.1),
+            });
+        }
+        if let Some(value) = unicode::lookup(unicode::GC_VALUE_PATTERNS, version, expression) {
+            return Some(UnicodeProperty {
+                name: "General_Category",
+                value: Some(value.1),
+            });
+        }
+        let property = unicode::lookup(unicode::BIN_PROPERTY_PATTERNS, version, expression)?;
+        return Some(UnicodeProperty {
+            name: property.1,
+            value: None,
+        });
+    }
+
+    /// Returns the names that can be used before the `=` of `\p{name=value}`, sorted by name.
+    /// Returns nothing for editions without property escapes.
+    pub fn unicode_property_names(&self) -> Vec<PropertyAlias> {
+        if self.ecma_version() < EcmaVersion::ES2018 {
+            return Vec::new();
+        }
+        let mut names: Vec<PropertyAlias> = unicode::property_names()
+            .map(|&(name, canonical)| PropertyAlias {
+                name,
+                canonical,
+                unicode_version: UnicodeVersion::V9_0,
+            })
+            .collect();
+        names.sort_by_key(|alias| alias.name);
+        return names;
+    }
+
+    /// Returns all values of the given kind, with their aliases, that are valid with the
+    /// `EcmaVersion` and `UnicodeVersion` of this validator, sorted by name.
+    /// Returns nothing for editions without property escapes.
+    pub fn unicode_property_values(&self, kind: PropertyKind) -> Vec<PropertyAlias> {
+        if self.ecma_version() < EcmaVersion::ES2018 {
+            return Vec::new();
+        }
+        let version = self.unicode_version();
+        kind.table()
+            .iter()
+            .filter(|entry| entry.2 <= version)
+            .map(|&(name, canonical, unicode_version)| PropertyAlias {
+                name,
+                canonical,
+                unicode_version,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn resolve_aliases() {
+        let validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let resolve = |expression| {
+            validator
+                .resolve_unicode_property(expression)
+                .map(|property| property.to_string())
+        };
+        assert_eq!(resolve("gc=Lu").as_deref(), Some("General_Category=Uppercase_Letter"));
+        assert_eq!(resolve("Lu").as_deref(), Some("General_Category=Uppercase_Letter"));
+        assert_eq!(resolve("sc=Grek").as_deref(), Some("Script=Greek"));
+        assert_eq!(resolve("scx=Greek").as_deref(), Some("Script_Extensions=Greek"));
+        assert_eq!(resolve("Alpha").as_deref(), Some("Alphabetic"));
+        assert_eq!(resolve("sc=Lu"), None);
+        assert_eq!(resolve("Alpha=Yes"), None);
+        assert_eq!(resolve("Grek"), None);
+        assert_eq!(resolve("sc=Elym"), None);
+
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        validator.set_unicode_version(UnicodeVersion::V12_0);
+        assert_eq!(
+            validator.resolve_unicode_property("sc=Elym").unwrap().value,
+            Some("Elymaic")
+        );
+
+        let validator = EcmaRegexValidator::new(EcmaVersion::ES2017);
+        assert_eq!(validator.resolve_unicode_property("gc=Lu"), None);
+    }
+
+    #[test]
+    fn list_values() {
+        let validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let scripts = validator.unicode_property_values(PropertyKind::Script);
+        assert!(scripts.windows(2).all(|w| w[0].name < w[1].name));
+        assert!(scripts.iter().any(|alias| alias.name == "Grek" && alias.canonical == "Greek"));
+        assert!(!scripts.iter().any(|alias| alias.name == "Elym"));
+
+        let validator = EcmaRegexValidator::new(EcmaVersion::ES2020);
+        let elymaic = validator
+            .unicode_property_values(PropertyKind::ScriptExtensions)
+            .into_iter()
+            .find(|alias| alias.name == "Elym")
+            .unwrap();
+        assert_eq!(elymaic.unicode_version, UnicodeVersion::V12_0);
+        assert_eq!(elymaic.ecma_version(), Some(EcmaVersion::ES2020));
+
+        let binary = validator.unicode_property_values(PropertyKind::Binary);
+        let alpha = binary.iter().find(|alias| alias.name == "Alpha").unwrap();
+        assert_eq!(alpha.ecma_version(), Some(EcmaVersion::ES2018));
+
+        let names = validator.unicode_property_names();
+        assert_eq!(names.len(), 6);
+        assert_eq!(names[0].name, "General_Category");
+
+        let validator = EcmaRegexValidator::new(EcmaVersion::ES2015);
+        assert!(validator.unicode_property_values(PropertyKind::GeneralCategory).is_empty());
+        assert!(validator.unicode_property_names().is_empty());
+    }
+}
@@ -1,15 +1,21 @@
 // Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
 // Distributed under terms of the MIT license.
 
-use std::collections::VecDeque;
-
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// Reads a pattern as UTF-16 code units, or as code points when the `u` flag is set.
+/// Indices always count UTF-16 code units, like `String.prototype.length` in JS.
+///
+/// The decoded source buffer is kept between calls to `reset`, so that validating
+/// many patterns with one reader does not allocate once it is large enough.
 #[derive(Debug)]
 pub struct Reader {
     unicode: bool,
-    src: String,
+    src: Vec<u16>,
     index: usize,
     end: usize,
-    cps: VecDeque<char>,
+    cps: VecDeque<u32>,
     widths: VecDeque<usize>,
 }
 
@@ -17,15 +23,15 @@ impl Reader {
     pub fn new() -> Self {
         Self {
             unicode: false,
-            src: "".to_owned(),
+            src: Vec::new(),
             index: 0,
             end: 0,
             cps: VecDeque::with_capacity(4),
-            widths: VecDeque::with_capacity(3),
+            widths: VecDeque::with_capacity(4),
         }
     }
 
-    pub fn source(&self) -> &str {
+    pub fn source(&self) -> &[u16] {
         &self.src
     }
 
@@ -33,14 +39,15 @@ impl Reader {
         self.index
     }
 
-    pub fn code_point_with_offset(&self, offset: usize) -> Option<char> {
+    pub fn code_point_with_offset(&self, offset: usize) -> Option<u32> {
         self.cps.get(offset).cloned()
     }
 
     pub fn reset(&mut self, source: &str, start: usize, end: usize, u_flag: bool) {
         self.unicode = u_flag;
-        self.src = source.to_owned();
-        self.end = end;
+        self.src.clear();
+        self.src.extend(source.encode_utf16());
+        self.end = end.min(self.src.len());
         self.rewind(start);
     }
 
@@ -48,11 +55,13 @@ impl Reader {
         self.index = index;
         self.cps.clear();
         self.widths.clear();
-        for i in 0..4 {
-            let w_sum: usize = self.widths.iter().take(i).sum();
-            if let Some(c) = self.at(index + w_sum) {
+        let mut i = index;
+        for _ in 0..4 {
+            if let Some(c) = self.at(i) {
+                let w = self.width(c);
                 self.cps.push_back(c);
-                self.widths.push_back(self.width(c));
+                self.widths.push_back(w);
+                i += w;
             } else {
                 break;
             }
@@ -60,21 +69,20 @@ impl Reader {
     }
 
     pub fn advance(&mut self) {
-        if self.cps.get(0).is_some() {
+        if !self.cps.is_empty() {
             self.index += self.widths[0];
             self.cps.pop_front();
             self.widths.pop_front();
             let w_sum: usize = self.widths.iter().sum();
             if let Some(c) = self.at(self.index + w_sum) {
-                self.widths.push_back(self.width(*self.cps.back().unwrap()));
+                self.widths.push_back(self.width(c));
                 self.cps.push_back(c);
             }
         }
     }
 
     pub fn eat(&mut self, cp: char) -> bool {
-        let opt = self.cps.get(0);
-        if opt.is_some() && *opt.unwrap() == cp {
+        if self.cps.front() == Some(&(cp as u32)) {
             self.advance();
             return true;
         }
@@ -82,8 +90,7 @@ impl Reader {
     }
 
     pub fn eat2(&mut self, cp1: char, cp2: char) -> bool {
-        let (opt1, opt2) = (self.cps.get(0), self.cps.get(1));
-        if opt1.is_some() && opt2.is_some() && *opt1.unwrap() == cp1 && *opt2.unwrap() == cp2 {
+        if self.cps.front() == Some(&(cp1 as u32)) && self.cps.get(1) == Some(&(cp2 as u32)) {
             self.advance();
             self.advance();
             return true;
@@ -92,13 +99,9 @@ impl Reader {
     }
 
     pub fn eat3(&mut self, cp1: char, cp2: char, cp3: char) -> bool {
-        let (opt1, opt2, opt3) = (self.cps.get(0), self.cps.get(1), self.cps.get(2));
-        if opt1.is_some()
-            && opt2.is_some()
-            && opt3.is_some()
-            && *opt1.unwrap() == cp1
-            && *opt2.unwrap() == cp2
-            && *opt3.unwrap() == cp3
+        if self.cps.front() == Some(&(cp1 as u32))
+            && self.cps.get(1) == Some(&(cp2 as u32))
+            && self.cps.get(2) == Some(&(cp3 as u32))
         {
             self.advance();
             self.advance();
@@ -108,28 +111,23 @@ impl Reader {
         return false;
     }
 
-    fn at(&self, i: usize) -> Option<char> {
-        //println!("{:?}", self.src.as_bytes());
-        //println!("{:?}", self.src.encode_utf16().collect::<Vec<u16>>());
+    fn at(&self, i: usize) -> Option<u32> {
         if i >= self.end {
-            None
-        } else if self.unicode {
-            // TODO: read non ASCII as UTF-8
-            //let c: char = self.src.as_bytes()[i].into();
-            let c = self.src.chars().skip(i).next().unwrap();
-            Some(c)
-        } else {
-            // TODO: move the conversion out of this method and make it safe
-            unsafe {
-                let c: char = std::char::from_u32_unchecked(self.src.encode_utf16().skip(i).next().unwrap() as u32);
-                Some(c)
+            return None;
+        }
+        let c = u32::from(self.src[i]);
+        if self.unicode && (0xd800..=0xdbff).contains(&c) && i + 1 < self.end {
+            let d = u32::from(self.src[i + 1]);
+            if (0xdc00..=0xdfff).contains(&d) {
+                return Some((c - 0xd800) * 0x400 + (d - 0xdc00) + 0x10000);
             }
         }
+        Some(c)
     }
 
-    fn width(&self, c: char) -> usize {
-        if self.unicode && c > '\u{FFFF}' {
-            1
+    fn width(&self, c: u32) -> usize {
+        if self.unicode && c > 0xffff {
+            2
         } else {
             1
         }
@@ -144,27 +142,27 @@ mod tests {
     fn eat_test() {
         let mut reader = Reader::new();
         reader.reset("abcdefghijk", 0, 11, true);
-        assert_eq!(reader.eat('a'), true);
-        assert_eq!(reader.eat3('b', 'd', 'd'), false);
-        assert_eq!(reader.eat3('b', 'c', 'd'), true);
-        assert_eq!(reader.eat2('e', 'f'), true);
-        assert_eq!(reader.eat('h'), false);
-        assert_eq!(reader.eat('g'), true);
-        assert_eq!(reader.eat2('h', 'i'), true);
-        assert_eq!(reader.eat3('j', 'k', 'a'), false);
+        assert!(reader.eat('a'));
+        assert!(!reader.eat3('b', 'd', 'd'));
+        assert!(reader.eat3('b', 'c', 'd'));
+        assert!(reader.eat2('e', 'f'));
+        assert!(!reader.eat('h'));
+        assert!(reader.eat('g'));
+        assert!(reader.eat2('h', 'i'));
+        assert!(!reader.eat3('j', 'k', 'a'));
     }
 
     #[test]
     fn rewind_test() {
         let mut reader = Reader::new();
         reader.reset("abcd", 0, 4, true);
-        assert_eq!(reader.eat('a'), true);
-        assert_eq!(reader.eat3('b', 'd', 'd'), false);
-        assert_eq!(reader.eat3('b', 'c', 'd'), true);
+        assert!(reader.eat('a'));
+        assert!(!reader.eat3('b', 'd', 'd'));
+        assert!(reader.eat3('b', 'c', 'd'));
         reader.rewind(0);
-        assert_eq!(reader.eat('a'), true);
-        assert_eq!(reader.eat3('b', 'd', 'd'), false);
-        assert_eq!(reader.eat3('b', 'c', 'd'), true);
+        assert!(reader.eat('a'));
+        assert!(!reader.eat3('b', 'd', 'd'));
+        assert!(reader.eat3('b', 'c', 'd'));
     }
 
     #[test]
@@ -172,19 +170,19 @@ mod tests {
         let mut reader = Reader::new();
         // without unicode flag
         reader.reset("Hello", 0, 5, false);
-        assert_eq!(reader.at(1).unwrap() as u32, 101);
+        assert_eq!(reader.at(1).unwrap(), 101);
         reader.reset("􀃃a🩢☃★♲", 0, 6, false);
-        assert_eq!(reader.at(0).unwrap() as u32, 56256);
+        assert_eq!(reader.at(0).unwrap(), 56256);
         reader.reset("􀃃ello", 0, 6, false);
-        assert_eq!(reader.at(0).unwrap() as u32, 56256);
+        assert_eq!(reader.at(0).unwrap(), 56256);
         reader.reset("􀃃ello", 0, 6, false);
-        assert_eq!(reader.at(1).unwrap() as u32, 56515);
+        assert_eq!(reader.at(1).unwrap(), 56515);
         // with unicode flag
         reader.reset("Hello", 0, 5, true);
-        assert_eq!(reader.at(1).unwrap() as u32, 101);
+        assert_eq!(reader.at(1).unwrap(), 101);
         reader.reset("􀃃a🩢☃★♲", 0, 6, true);
-        assert_eq!(reader.at(0).unwrap() as u32, 1048771);
+        assert_eq!(reader.at(0).unwrap(), 1048771);
         reader.reset("􀃃ello", 0, 6, true);
-        assert_eq!(reader.at(0).unwrap() as u32, 1048771);
+        assert_eq!(reader.at(0).unwrap(), 1048771);
     }
 }
@@ -0,0 +1,377 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::tree::{CharSet, Node};
+use crate::validator::EcmaRegexValidator;
+
+/// How often attack strings repeat the ambiguous part for exponential backtracking.
+const EXPONENTIAL_PUMPS: usize = 30;
+/// How often attack strings repeat the ambiguous part for polynomial backtracking.
+const POLYNOMIAL_PUMPS: usize = 10_000;
+
+/// How the matching time of a backtracking engine grows with the length of the input.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Complexity {
+    /// Polynomial time of the given degree, like O(n²) for adjacent `\s*\s*`.
+    Polynomial(u32),
+    /// Exponential time, like for nested quantifiers `(a+)+`.
+    Exponential,
+}
+
+impl fmt::Display for Complexity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Complexity::Polynomial(degree) => write!(f, "polynomial (O(n^{}))", degree),
+            Complexity::Exponential => write!(f, "exponential"),
+        }
+    }
+}
+
+/// A part of a pattern that can backtrack catastrophically.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RedosFinding {
+    pub complexity: Complexity,
+    /// The quantified terms that cause it, counted in UTF-16 code units.
+    /// For exponential backtracking the outer quantifier comes first.
+    pub quantifiers: Vec<Range<usize>>,
+    /// An input the pattern takes this long to reject.
+    pub attack: String,
+}
+
+impl EcmaRegexValidator {
+    /// Validates the regular expression literal `/source/flags` and finds the places where a
+    /// backtracking engine can take exponential or polynomial time to reject an input:
+    /// nested quantifiers over an ambiguous body like `(a+)+` or `(\w|\d)*`, and adjacent
+    /// quantified terms that match the same characters like `\s*\s*$`.
+    ///
+    /// The analysis is static and approximate. Property escapes only overlap with the same
+    /// escape, and no attack string is built from them, so they are not reported.
+    pub fn find_redos(&mut self, source: &str, flags: &str) -> Result<Vec<RedosFinding>, String> {
+        let tree = self.parse_tree(source, flags)?;
+        let mut used = CharSet::default();
+        tree.for_each_set(&mut |set| used = used.union(set));
+        let mut analyzer = Analyzer {
+            used,
+            findings: Vec::new(),
+        };
+        analyzer.analyze(&tree, "", false);
+        analyzer
+            .findings
+            .sort_by_key(|finding| (finding.quantifiers[0].start, finding.quantifiers[0].end));
+        analyzer
+            .findings
+            .dedup_by(|a, b| a.quantifiers == b.quantifiers);
+        // Adjacent quantifiers inside an exponential finding, like in `(a+a+)+`, are part of it.
+        let exponential: Vec<Range<usize>> = analyzer
+            .findings
+            .iter()
+            .filter(|finding| finding.complexity == Complexity::Exponential)
+            .flat_map(|finding| finding.quantifiers.iter().cloned())
+            .collect();
+        analyzer.findings.retain(|finding| {
+            finding.complexity == Complexity::Exponential
+                || !finding.quantifiers.iter().all(|q| exponential.contains(q))
+        });
+        return Ok(analyzer.findings);
+    }
+}
+
+struct Analyzer {
+    /// Every character the pattern mentions, to find one that makes it fail.
+    used: CharSet,
+    findings: Vec<RedosFinding>,
+}
+
+impl Analyzer {
+    /// Analyses `node`, which is reached by matching `prefix`. `tail_can_fail` tells whether
+    /// what follows the node can fail, which forces the engine to backtrack into it.
+    fn analyze(&mut self, node: &Node, prefix: &str, tail_can_fail: bool) {
+        match node {
+            Node::Group { body, .. } => self.analyze(body, prefix, tail_can_fail),
+            Node::Lookaround { body, .. } => self.analyze(body, prefix, false),
+            Node::Alternation(nodes) => {
+                for node in nodes {
+                    self.analyze(node, prefix, tail_can_fail);
+                }
+            }
+            Node::Sequence(nodes) => {
+                self.find_overlapping_terms(nodes, prefix, tail_can_fail);
+                let mut prefix = String::from(prefix);
+                for (i, node) in nodes.iter().enumerate() {
+                    let can_fail =
+                        tail_can_fail || nodes[i + 1..].iter().any(|n| !n.always_matches());
+                    self.analyze(node, &prefix, can_fail);
+                    prefix.push_str(&node.example());
+                }
+            }
+            Node::Quantified {
+                body, max, span, ..
+            } => {
+                if max.is_none() && tail_can_fail {
+                    if let Some((pump, inner)) = ambiguity(body) {
+                        let mut quantifiers = vec![span.clone()];
+                        quantifiers.extend(inner);
+                        let attack = self.attack(prefix, &pump, EXPONENTIAL_PUMPS);
+                        self.findings.push(RedosFinding {
+                            complexity: Complexity::Exponential,
+                            quantifiers,
+                            attack,
+                        });
+                    }
+                }
+                self.analyze(body, prefix, tail_can_fail);
+            }
+            _ => {}
+        }
+    }
+
+    /// Finds runs of unbounded quantifiers in a sequence that can match the same character,
+    /// separated only by terms that can match nothing, like `\d+\.?\d+`.
+    fn find_overlapping_terms(&mut self, nodes: &[Node], prefix: &str, tail_can_fail: bool) {
+        let mut i = 0;
+        while i < nodes.len() {
+            let (common, quantifiers, last) = match overlapping_run(&nodes[i..]) {
+                Some((common, quantifiers, last)) => (common, quantifiers, i + last),
+                None => {
+                    i += 1;
+                    continue;
+                }
+            };
+            let can_fail = tail_can_fail || nodes[last + 1..].iter().any(|n| !n.always_matches());
+            if quantifiers.len() > 1 && can_fail {
+                if let Some(pump) = common.representative() {
+                    let mut prefix = String::from(prefix);
+                    nodes[..i]
+                        .iter()
+                        .for_each(|n| prefix.push_str(&n.example()));
+                    let attack = self.attack(&prefix, &pump.to_string(), POLYNOMIAL_PUMPS);
+                    self.findings.push(RedosFinding {
+                        complexity: Complexity::Polynomial(quantifiers.len() as u32),
+                        quantifiers,
+                        attack,
+                    });
+                }
+            }
+            i = last + 1;
+        }
+    }
+
+    /// Builds an attack string: the prefix, the pumped string, and a character that the
+    /// pattern does not use, so the match fails only after trying every way to split it.
+    fn attack(&self, prefix: &str, pump: &str, pumps: usize) -> String {
+        let candidates = ['!', '#', '~', '\u{0}', '\u{ffff}'];
+        let suffix = candidates
+            .iter()
+            .find(|&&c| !self.used.contains(c as u32))
+            .or_else(|| candidates.iter().find(|&&c| !pump.contains(c)))
+            .copied()
+            .unwrap_or('!');
+        let mut attack = String::with_capacity(prefix.len() + pump.len() * pumps + 4);
+        attack.push_str(prefix);
+        for _ in 0..pumps {
+            attack.push_str(pump);
+        }
+        attack.push(suffix);
+        attack
+    }
+}
+
+/// Returns the set and span of an unbounded quantifier over a single character.
+fn repeated_char_set(node: &Node) -> Option<(CharSet, Range<usize>)> {
+    match node.unwrap_groups() {
+        Node::Quantified {
+            body,
+            max: None,
+            span,
+            ..
+        } => Some((body.single_char_set()?, span.clone())),
+        _ => None,
+    }
+}
+
+/// Returns the characters that the unbounded quantifier `nodes[0]` and the ones right after it
+/// all match, with their spans and the index of the last one. Only terms that can match
+/// nothing may be between them.
+fn overlapping_run(nodes: &[Node]) -> Option<(CharSet, Vec<Range<usize>>, usize)> {
+    let (mut common, span) = repeated_char_set(nodes.first()?)?;
+    let mut quantifiers = vec![span];
+    let mut last = 0;
+    for (j, node) in nodes.iter().enumerate().skip(1) {
+        if let Some((set, span)) = repeated_char_set(node) {
+            let overlap = common.intersect(&set);
+            if overlap.is_empty() {
+                break;
+            }
+            common = overlap;
+            quantifiers.push(span);
+            last = j;
+        } else if !node.always_matches() {
+            break;
+        }
+    }
+    Some((common, quantifiers, last))
+}
+
+/// Returns a string the body of an unbounded quantifier can match in more than one way,
+/// with the spans of the inner quantifiers that cause it.
+fn ambiguity(body: &Node) -> Option<(String, Vec<Range<usize>>)> {
+    match body.unwrap_groups() {
+        Node::Quantified {
+            body,
+            max: None,
+            span,
+            ..
+        } => Some((non_empty_example(body)?, vec![span.clone()])),
+        Node::Sequence(nodes) => {
+            // Adjacent unbounded quantifiers over the same characters, like `(a+a+)+`.
+            for i in 0..nodes.len() {
+                let (common, quantifiers, last) = match overlapping_run(&nodes[i..]) {
+                    Some(run) if run.1.len() > 1 => run,
+                    _ => continue,
+                };
+                let others_nullable = nodes[..i]
+                    .iter()
+                    .chain(&nodes[i + last + 1..])
+                    .all(Node::is_nullable);
+                if let Some(c) = common.representative().filter(|_| others_nullable) {
+                    return Some((c.to_string().repeat(quantifiers.len()), quantifiers));
+                }
+            }
+            // An unbounded quantifier next to terms that can match nothing, like `(a+b?)+`.
+            let (k, inner) = nodes.iter().enumerate().find(|(_, node)| {
+                matches!(node.unwrap_groups(), Node::Quantified { max: None, .. })
+            })?;
+            let others_nullable = nodes
+                .iter()
+                .enumerate()
+                .all(|(j, node)| j == k || node.is_nullable());
+            if !others_nullable {
+                return None;
+            }
+            ambiguity(inner)
+        }
+        Node::Alternation(nodes) => {
+            if let Some(found) = nodes.iter().find_map(ambiguity) {
+                return Some(found);
+            }
+            // Alternatives that match the same character, like `(\w|\d)*`.
+            let sets: Vec<Option<CharSet>> = nodes.iter().map(Node::single_char_set).collect();
+            for (i, a) in sets.iter().enumerate() {
+                for b in &sets[i + 1..] {
+                    if let (Some(a), Some(b)) = (a, b) {
+                        if let Some(c) = a.intersect(b).representative() {
+                            return Some((c.to_string(), vec![]));
+                        }
+                    }
+                }
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+fn non_empty_example(node: &Node) -> Option<String> {
+    let example = node.example();
+    if !example.is_empty() {
+        return Some(example);
+    }
+    let mut first = None;
+    node.for_each_set(&mut |set| {
+        if first.is_none() {
+            first = set.representative();
+        }
+    });
+    first.map(|c| c.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EcmaVersion;
+
+    fn find(source: &str, flags: &str) -> Vec<RedosFinding> {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        validator.find_redos(source, flags).unwrap()
+    }
+
+    #[test]
+    fn exponential() {
+        let findings = find("^(a+)+$", "");
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].complexity, Complexity::Exponential);
+        assert_eq!(findings[0].quantifiers, vec![1..6, 2..4]);
+        assert_eq!(findings[0].attack, "a".repeat(30) + "!");
+
+        let findings = find("(\\w|\\d)*x", "");
+        assert_eq!(findings[0].quantifiers, vec![0..8]);
+        assert_eq!(findings[0].attack, "0".repeat(30) + "!");
+
+        assert_eq!(
+            find("(\\d+\\.?)+$", "")[0].complexity,
+            Complexity::Exponential
+        );
+        assert_eq!(
+            find("x(?:[a-z]*)*y", "i")[0].attack,
+            "x".to_string() + &"a".repeat(30) + "!"
+        );
+
+        // The adjacent quantifiers are part of the exponential finding.
+        let findings = find("(x+x+)+y", "");
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].complexity, Complexity::Exponential);
+        assert_eq!(findings[0].quantifiers, vec![0..7, 1..3, 3..5]);
+        assert_eq!(findings[0].attack, "xx".repeat(30) + "!");
+    }
+
+    #[test]
+    fn polynomial() {
+        let findings = find("\\s*\\s*$", "");
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].complexity, Complexity::Polynomial(2));
+        assert_eq!(findings[0].quantifiers, vec![0..3, 3..6]);
+        assert_eq!(findings[0].attack, " ".repeat(10_000) + "!");
+
+        let findings = find("a\\d+\\.?\\d+\\w*=", "");
+        assert_eq!(findings[0].complexity, Complexity::Polynomial(3));
+        assert!(findings[0].attack.starts_with("a0"));
+        assert_eq!(find(".*.*=.*", "")[0].quantifiers, vec![0..2, 2..4]);
+    }
+
+    #[test]
+    fn safe() {
+        assert_eq!(find("^[a-z]+\\d+$", ""), vec![]);
+        assert_eq!(find("(a|b)*c", ""), vec![]);
+        assert_eq!(find("(ab+)+c", ""), vec![]);
+        assert_eq!(find("\\s*\\d*$", ""), vec![]);
+        // Nothing after the quantifiers can fail, so the first attempt matches.
+        assert_eq!(find("(a+)+", ""), vec![]);
+        assert_eq!(find("x\\s*\\s*", ""), vec![]);
+    }
+
+    #[cfg(feature = "unicode-properties")]
+    #[test]
+    fn property_escapes() {
+        assert_eq!(find("\\p{L}+\\p{N}+$", "u"), vec![]);
+        assert_eq!(find("\\p{L}+\\d+$", "u"), vec![]);
+    }
+
+    #[test]
+    fn invalid() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        assert!(validator.find_redos("(", "").is_err());
+        assert!(validator.find_redos("a", "gg").is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Complexity::Exponential.to_string(), "exponential");
+        assert_eq!(Complexity::Polynomial(2).to_string(), "polynomial (O(n^2))");
+    }
+}
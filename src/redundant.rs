@@ -0,0 +1,446 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::empty::Suggestion;
+use crate::flags::Usage;
+use crate::tree::Node;
+use crate::validator::{is_syntax_character, EcmaRegexValidator};
+
+/// Syntax that can be simplified without changing what the pattern matches.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum RedundancyKind {
+    /// An escaped character that means the same unescaped, like `\:`.
+    UselessEscape,
+    /// A quantifier with a shorter form, like `{0,}`, or none at all, like `{1}`.
+    RedundantQuantifier,
+    /// A lazy modifier on a quantifier that repeats a fixed number of times, like `a{3}?`.
+    LazyFixedQuantifier,
+    /// A non-capturing group around a single atom, like `(?:a)`.
+    UselessGroup,
+    /// A leading or trailing `.*` in a pattern that is only tested.
+    UselessWildcard,
+}
+
+impl fmt::Display for RedundancyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RedundancyKind::UselessEscape => "unnecessary escape",
+            RedundancyKind::RedundantQuantifier => "quantifier can be simplified",
+            RedundancyKind::LazyFixedQuantifier => "lazy modifier on a fixed quantifier",
+            RedundancyKind::UselessGroup => "group around a single atom",
+            RedundancyKind::UselessWildcard => "wildcard at the edge of a tested pattern",
+        })
+    }
+}
+
+/// Syntax in a pattern that can be simplified.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Redundancy {
+    pub kind: RedundancyKind,
+    /// The position of the syntax, counted in UTF-16 code units.
+    pub span: Range<usize>,
+    /// The edit that simplifies it.
+    pub fix: Suggestion,
+}
+
+/// Returns whether `unit` could continue an escape sequence before it.
+pub(crate) fn continues_escape(unit: Option<&u16>) -> bool {
+    matches!(unit, Some(&u) if (u < 0x80 && (u as u8).is_ascii_alphanumeric()) || u == '{' as u16)
+}
+
+/// Returns whether `units` has a braced quantifier like `{1,2}` that starts before one of
+/// `boundaries` and ends at or after it.
+fn has_quantifier_across(units: &[u16], boundaries: [usize; 2]) -> bool {
+    let is_digit = |i: usize| {
+        units
+            .get(i)
+            .is_some_and(|u| ('0' as u16..='9' as u16).contains(u))
+    };
+    let is = |i: usize, c: char| units.get(i) == Some(&(c as u16));
+    (0..units.len()).filter(|&p| is(p, '{')).any(|p| {
+        let mut q = p + 1;
+        while is_digit(q) {
+            q += 1;
+        }
+        if q == p + 1 {
+            return false;
+        }
+        if is(q, ',') {
+            q += 1;
+            while is_digit(q) {
+                q += 1;
+            }
+        }
+        is(q, '}') && boundaries.iter().any(|&b| p < b && b <= q)
+    })
+}
+
+/// Returns where the atom of a quantified term ends.
+fn atom_end(node: &Node) -> Option<usize> {
+    match node {
+        Node::Chars(_, span) | Node::Assertion(span) => Some(span.end),
+        Node::Lookaround { span, .. }
+        | Node::Backreference { span, .. }
+        | Node::Group { span, .. } => Some(span.end),
+        _ => None,
+    }
+}
+
+/// Returns whether `node` is `.*` or `.*?`.
+fn is_wildcard(node: &Node, units: &[u16]) -> bool {
+    match node {
+        Node::Quantified {
+            body,
+            min: 0,
+            max: None,
+            ..
+        } => matches!(&**body, Node::Chars(_, span) if units[span.clone()] == ['.' as u16]),
+        _ => false,
+    }
+}
+
+struct Finder<'a> {
+    units: &'a [u16],
+    classes: Vec<Range<usize>>,
+    found: Vec<Redundancy>,
+}
+
+impl<'a> Finder<'a> {
+    fn text(&self, span: Range<usize>) -> String {
+        String::from_utf16_lossy(&self.units[span])
+    }
+
+    fn push(&mut self, kind: RedundancyKind, span: Range<usize>, fix: Range<usize>, text: &str) {
+        self.found.push(Redundancy {
+            kind,
+            span,
+            fix: Suggestion {
+                span: fix,
+                replacement: String::from(text),
+            },
+        });
+    }
+
+    /// Visits `node`, which is the atom of a quantified term if `quantified`.
+    fn visit(&mut self, node: &Node, quantified: bool) {
+        match node {
+            Node::Chars(_, span) if self.units[span.start] == '[' as u16 => {
+                self.classes.push(span.clone());
+            }
+            Node::Quantified {
+                body,
+                min,
+                max,
+                span,
+            } => {
+                self.check_quantifier(body, *min, *max, span.clone());
+                self.visit(body, true);
+            }
+            Node::Group {
+                body,
+                index: None,
+                span,
+                ..
+            } => {
+                self.check_group(body, quantified, span.clone());
+                self.visit(body, false);
+            }
+            Node::Group { body, .. } | Node::Lookaround { body, .. } => self.visit(body, false),
+            Node::Alternation(nodes) | Node::Sequence(nodes) => {
+                nodes.iter().for_each(|node| self.visit(node, false))
+            }
+            _ => {}
+        }
+    }
+
+    fn check_quantifier(&mut self, body: &Node, min: u32, max: Option<u32>, span: Range<usize>) {
+        let start = match atom_end(body) {
+            Some(start) => start,
+            None => return,
+        };
+        if self.units[start] != '{' as u16 {
+            return;
+        }
+        let lazy = self.units[span.end - 1] == '?' as u16;
+        let prefix = start..if lazy { span.end - 1 } else { span.end };
+        let shorter = match (min, max) {
+            (1, Some(1)) => {
+                let quantifier = start..span.end;
+                let kind = RedundancyKind::RedundantQuantifier;
+                self.push(kind, quantifier.clone(), quantifier, "");
+                return;
+            }
+            (0, None) => Some(String::from("*")),
+            (1, None) => Some(String::from("+")),
+            (0, Some(1)) => Some(String::from("?")),
+            (min, Some(max))
+                if min == max && self.units[prefix.clone()].contains(&(',' as u16)) =>
+            {
+                Some(format!("{{{}}}", min))
+            }
+            _ => None,
+        };
+        if let Some(shorter) = shorter {
+            let kind = RedundancyKind::RedundantQuantifier;
+            self.push(kind, prefix.clone(), prefix, &shorter);
+        }
+        if lazy && Some(min) == max {
+            let kind = RedundancyKind::LazyFixedQuantifier;
+            self.push(kind, start..span.end, span.end - 1..span.end, "");
+        }
+    }
+
+    fn check_group(&mut self, body: &Node, quantified: bool, span: Range<usize>) {
+        let atom = match body {
+            Node::Sequence(nodes) if nodes.len() == 1 => &nodes[0],
+            _ => return,
+        };
+        let single = match atom {
+            Node::Chars(..) | Node::Group { .. } | Node::Backreference { .. } => true,
+            Node::Assertion(..) | Node::Lookaround { .. } | Node::Quantified { .. } => !quantified,
+            _ => false,
+        };
+        if !single {
+            return;
+        }
+        // Keep escapes from running into their neighbours, like `\1(?:0)` or `(?:\x4)1`.
+        let inner = span.start + 3..span.end - 1;
+        let before = &self.units[span.start.saturating_sub(5)..span.start];
+        if before.contains(&('\\' as u16)) && continues_escape(self.units.get(inner.start)) {
+            return;
+        }
+        let escaped = self.units[inner.clone()].contains(&('\\' as u16));
+        if escaped && continues_escape(self.units.get(span.end)) {
+            return;
+        }
+        // Keep braces from turning into a quantifier, like `a(?:{)1}`.
+        let mut unwrapped = self.units[..span.start].to_vec();
+        unwrapped.extend_from_slice(&self.units[inner.clone()]);
+        unwrapped.extend_from_slice(&self.units[span.end..]);
+        if has_quantifier_across(&unwrapped, [span.start, span.start + inner.len()]) {
+            return;
+        }
+        let text = self.text(inner);
+        self.push(RedundancyKind::UselessGroup, span.clone(), span, &text);
+    }
+
+    /// Finds `.*` at the start or end of the alternatives of a pattern that is only tested.
+    fn check_wildcards(&mut self, tree: &Node) {
+        let alternatives = match tree {
+            Node::Alternation(nodes) => &nodes[..],
+            node => core::slice::from_ref(node),
+        };
+        for alternative in alternatives {
+            let nodes = match alternative {
+                Node::Sequence(nodes) if nodes.len() > 1 => nodes,
+                _ => continue,
+            };
+            for node in [&nodes[0], &nodes[nodes.len() - 1]].iter() {
+                if let Node::Quantified { span, .. } = node {
+                    if is_wildcard(node, self.units) {
+                        let kind = RedundancyKind::UselessWildcard;
+                        self.push(kind, span.clone(), span.clone(), "");
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl EcmaRegexValidator {
+    /// Validates the regular expression literal `/source/flags` and finds syntax that can be
+    /// simplified without changing what it matches: unnecessary escapes, quantifiers like
+    /// `{1}` and `{0,}`, lazy fixed quantifiers, non-capturing groups around a single atom,
+    /// and, if `usage` is `Usage::Test`, leading and trailing `.*`. `literal` tells whether
+    /// the pattern is written as a literal, where `/` has to be escaped, or passed to
+    /// `new RegExp`. The findings are in order of their position.
+    pub fn find_redundant_syntax(
+        &mut self,
+        source: &str,
+        flags: &str,
+        usage: Usage,
+        literal: bool,
+    ) -> Result<Vec<Redundancy>, String> {
+        self.validate_flags(flags)?;
+        self.identity_escapes = Some(Vec::new());
+        let result = self.validate_pattern(source, flags.contains('u'));
+        let escapes = self.identity_escapes.take().unwrap_or_default();
+        result?;
+        let tree = self.parse_tree(source, flags)?;
+        let units: Vec<u16> = source.encode_utf16().collect();
+        let mut finder = Finder {
+            units: &units,
+            classes: Vec::new(),
+            found: Vec::new(),
+        };
+        finder.visit(&tree, false);
+        if usage == Usage::Test && !flags.contains('y') {
+            finder.check_wildcards(&tree);
+        }
+
+        for (cp, span) in escapes {
+            let in_class = finder
+                .classes
+                .iter()
+                .any(|class| class.start < span.start && span.end < class.end);
+            let needed = if in_class {
+                [']', '\\', '^', '-'].iter().any(|&c| c as u32 == cp)
+            } else {
+                is_syntax_character(cp) || (literal && cp == '/' as u32)
+            };
+            // Removing the backslash could complete an escape before it, like in `\x4\1`.
+            let digit_or_letter = cp < 0x80 && (cp as u8).is_ascii_alphanumeric();
+            if needed || digit_or_letter && continues_escape(units.get(span.start.wrapping_sub(1)))
+            {
+                continue;
+            }
+            let text = finder.text(span.start + 1..span.end);
+            finder.push(RedundancyKind::UselessEscape, span.clone(), span, &text);
+        }
+        let mut found = finder.found;
+        found.sort_by_key(|r| (r.span.start, r.span.end));
+        return Ok(found);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EcmaVersion;
+    use alloc::string::ToString;
+
+    fn find(source: &str, flags: &str, usage: Usage) -> Vec<(RedundancyKind, String)> {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let found = validator
+            .find_redundant_syntax(source, flags, usage, true)
+            .unwrap();
+        found
+            .into_iter()
+            .map(|r| {
+                let mut fixed: Vec<u16> = source.encode_utf16().collect();
+                let replacement: Vec<u16> = r.fix.replacement.encode_utf16().collect();
+                fixed.splice(r.fix.span, replacement);
+                (r.kind, String::from_utf16(&fixed).unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn escapes() {
+        use RedundancyKind::*;
+        assert_eq!(
+            find("a\\:", "", Usage::Unknown),
+            vec![(UselessEscape, "a:".to_string())]
+        );
+        assert_eq!(
+            find("\\-[\\-\\.]", "", Usage::Unknown),
+            vec![
+                (UselessEscape, "-[\\-\\.]".to_string()),
+                (UselessEscape, "\\-[\\-.]".to_string()),
+            ]
+        );
+        assert_eq!(find("\\/\\.\\$[\\]\\^]", "u", Usage::Unknown), vec![]);
+        assert_eq!(
+            find(" \\q", "", Usage::Unknown),
+            vec![(UselessEscape, " q".to_string())]
+        );
+        assert_eq!(find("\\c\\q", "", Usage::Unknown), vec![]);
+
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let found = validator
+            .find_redundant_syntax("a\\/b", "u", Usage::Unknown, false)
+            .unwrap();
+        assert_eq!(found[0].span, 1..3);
+        assert_eq!(found[0].fix.replacement, "/");
+    }
+
+    #[test]
+    fn quantifiers() {
+        use RedundancyKind::*;
+        assert_eq!(
+            find("a{1}b{1}?", "", Usage::Unknown),
+            vec![
+                (RedundantQuantifier, "ab{1}?".to_string()),
+                (RedundantQuantifier, "a{1}b".to_string()),
+            ]
+        );
+        assert_eq!(
+            find("a{0,}?b{1,}c{0,1}", "", Usage::Unknown),
+            vec![
+                (RedundantQuantifier, "a*?b{1,}c{0,1}".to_string()),
+                (RedundantQuantifier, "a{0,}?b+c{0,1}".to_string()),
+                (RedundantQuantifier, "a{0,}?b{1,}c?".to_string()),
+            ]
+        );
+        assert_eq!(
+            find("a{3}?", "", Usage::Unknown),
+            vec![(LazyFixedQuantifier, "a{3}".to_string())]
+        );
+        assert_eq!(
+            find("a{2,2}", "", Usage::Unknown),
+            vec![(RedundantQuantifier, "a{2}".to_string())]
+        );
+        assert_eq!(find("a{2,3}?b*?c{", "", Usage::Unknown), vec![]);
+    }
+
+    #[test]
+    fn groups() {
+        use RedundancyKind::*;
+        assert_eq!(
+            find("(?:a)+", "", Usage::Unknown),
+            vec![(UselessGroup, "a+".to_string())]
+        );
+        assert_eq!(
+            find("(?:[ab])(?:\\d)", "", Usage::Unknown),
+            vec![
+                (UselessGroup, "[ab](?:\\d)".to_string()),
+                (UselessGroup, "(?:[ab])\\d".to_string()),
+            ]
+        );
+        assert_eq!(
+            find("(?:a+)b", "", Usage::Unknown),
+            vec![(UselessGroup, "a+b".to_string())]
+        );
+        assert_eq!(find("(?:a+)?(?:ab)(?:a|b)(a)", "", Usage::Unknown), vec![]);
+        assert_eq!(find("(a)\\1(?:0)(?:\\1)0", "", Usage::Unknown), vec![]);
+        assert_eq!(find("a(?:{)1}", "", Usage::Unknown), vec![]);
+        assert_eq!(find("a{(?:1)}", "", Usage::Unknown), vec![]);
+        assert_eq!(find("a{1(?:,)2}", "", Usage::Unknown), vec![]);
+        assert_eq!(
+            find("a(?:1)2{", "", Usage::Unknown),
+            vec![(UselessGroup, "a12{".to_string())]
+        );
+    }
+
+    #[test]
+    fn wildcards() {
+        use RedundancyKind::*;
+        assert_eq!(
+            find(".*a.*?|b", "", Usage::Test),
+            vec![
+                (UselessWildcard, "a.*?|b".to_string()),
+                (UselessWildcard, ".*a|b".to_string()),
+            ]
+        );
+        assert_eq!(find(".*a", "", Usage::Unknown), vec![]);
+        assert_eq!(find(".*a", "y", Usage::Test), vec![]);
+        assert_eq!(
+            find(".*|^.*", "", Usage::Test),
+            vec![(UselessWildcard, ".*|^".to_string())]
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            RedundancyKind::UselessEscape.to_string(),
+            "unnecessary escape"
+        );
+    }
+}
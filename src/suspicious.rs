@@ -0,0 +1,251 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::validator::EcmaRegexValidator;
+
+/// Why a range in a character class is probably not what was meant.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum RangeProblem {
+    /// From an uppercase to a lowercase ASCII letter, like `A-z`, which includes ``[\]^_` ``.
+    MixedCase,
+    /// Between a digit and a letter, like `0-z`.
+    LetterAndDigit,
+    /// Between letters of different scripts, like `a-я`.
+    DifferentScripts,
+    /// Between ASCII punctuation characters, like `+--`, which is easily meant as a list.
+    Punctuation,
+    /// An endpoint is an escape for a printable ASCII character, like `\x41-\x5a`, which
+    /// hides what the range includes.
+    EscapedEndpoint,
+}
+
+impl fmt::Display for RangeProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RangeProblem::MixedCase => "range goes from upper to lower case",
+            RangeProblem::LetterAndDigit => "range goes from a digit to a letter",
+            RangeProblem::DifferentScripts => "range goes from one script to another",
+            RangeProblem::Punctuation => "range between punctuation characters",
+            RangeProblem::EscapedEndpoint => "range endpoint is an escaped printable character",
+        })
+    }
+}
+
+/// A legal range in a character class that is probably wrong.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SuspiciousRange {
+    pub problem: RangeProblem,
+    /// The position of the range, counted in UTF-16 code units.
+    pub span: Range<usize>,
+    pub min: u32,
+    pub max: u32,
+}
+
+impl SuspiciousRange {
+    /// Returns all the characters the range includes, without lone surrogates.
+    pub fn characters(&self) -> String {
+        (self.min..=self.max)
+            .filter_map(core::char::from_u32)
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Thai,
+    Georgian,
+    Hiragana,
+    Katakana,
+    Han,
+    Hangul,
+}
+
+/// The blocks of the scripts that ranges of letters are commonly written in.
+const SCRIPT_BLOCKS: &[(u32, u32, Script)] = &[
+    (0x41, 0x5a, Script::Latin),
+    (0x61, 0x7a, Script::Latin),
+    (0xc0, 0x24f, Script::Latin),
+    (0x370, 0x3ff, Script::Greek),
+    (0x400, 0x52f, Script::Cyrillic),
+    (0x531, 0x58f, Script::Armenian),
+    (0x5d0, 0x5ff, Script::Hebrew),
+    (0x620, 0x6ff, Script::Arabic),
+    (0x900, 0x97f, Script::Devanagari),
+    (0xe00, 0xe7f, Script::Thai),
+    (0x10a0, 0x10ff, Script::Georgian),
+    (0x1e00, 0x1eff, Script::Latin),
+    (0x1f00, 0x1fff, Script::Greek),
+    (0x3041, 0x309f, Script::Hiragana),
+    (0x30a0, 0x30ff, Script::Katakana),
+    (0x4e00, 0x9fff, Script::Han),
+    (0xac00, 0xd7af, Script::Hangul),
+];
+
+fn script(cp: u32) -> Option<Script> {
+    SCRIPT_BLOCKS
+        .iter()
+        .find(|&&(min, max, _)| min <= cp && cp <= max)
+        .map(|&(_, _, script)| script)
+}
+
+fn ascii(cp: u32) -> Option<char> {
+    Some(cp as u8 as char).filter(|_| cp < 0x80)
+}
+
+fn is_ascii(cp: u32, predicate: fn(&char) -> bool) -> bool {
+    matches!(ascii(cp), Some(c) if predicate(&c))
+}
+
+/// Returns what is wrong with the range from `min` to `max`, whose endpoints are written as
+/// escapes if `escaped` says so.
+fn problem(min: u32, max: u32, escaped: (bool, bool)) -> Option<RangeProblem> {
+    if is_ascii(min, char::is_ascii_uppercase) && is_ascii(max, char::is_ascii_lowercase) {
+        return Some(RangeProblem::MixedCase);
+    }
+    let (digit, letter) = (char::is_ascii_digit, char::is_ascii_alphabetic);
+    if (is_ascii(min, digit) && is_ascii(max, letter))
+        || (is_ascii(min, letter) && is_ascii(max, digit))
+    {
+        return Some(RangeProblem::LetterAndDigit);
+    }
+    if let (Some(a), Some(b)) = (script(min), script(max)) {
+        if a != b {
+            return Some(RangeProblem::DifferentScripts);
+        }
+    }
+    let punctuation = char::is_ascii_punctuation;
+    if min != max && is_ascii(min, punctuation) && is_ascii(max, punctuation) {
+        return Some(RangeProblem::Punctuation);
+    }
+    // `\`, `]`, `-` and `^` may have to be escaped in a class.
+    let printable = |cp: u32| (0x21..=0x7e).contains(&cp) && !"\\]-^".contains(cp as u8 as char);
+    if (escaped.0 && printable(min)) || (escaped.1 && printable(max)) {
+        return Some(RangeProblem::EscapedEndpoint);
+    }
+    None
+}
+
+/// Returns the error for a range from `min` to `max` that is out of order, suggesting the
+/// two ranges that were probably meant for `a-Z`.
+pub(crate) fn range_out_of_order(min: i64, max: i64) -> String {
+    let message = "Range out of order in character class";
+    let letters = (ascii(min as u32), ascii(max as u32));
+    if let (Some(min), Some(max)) = letters {
+        if min.is_ascii_lowercase() && max.is_ascii_uppercase() {
+            return format!("{}: did you mean {}-zA-{}?", message, min, max);
+        }
+    }
+    message.to_string()
+}
+
+impl EcmaRegexValidator {
+    /// Validates the regular expression literal `/source/flags` and finds the ranges in its
+    /// character classes that are legal but probably wrong, like `[A-z]`, in the order they
+    /// appear.
+    pub fn find_suspicious_ranges(
+        &mut self,
+        source: &str,
+        flags: &str,
+    ) -> Result<Vec<SuspiciousRange>, String> {
+        self.validate_flags(flags)?;
+        self.suspicious_ranges = Some(Vec::new());
+        let result = self.validate_pattern(source, flags.contains('u'));
+        let found = self.suspicious_ranges.take().unwrap_or_default();
+        result?;
+        return Ok(found);
+    }
+
+    /// Records the range from `min` to `max` that ends at the current index if it is
+    /// suspicious and `find_suspicious_ranges` asked for it. `max_start` is where the second
+    /// endpoint starts.
+    pub(crate) fn record_range(&mut self, min: i64, max: i64, start: usize, max_start: usize) {
+        if self.suspicious_ranges.is_none() || min < 0 || max < min {
+            return;
+        }
+        let escaped = (
+            self.source()[start] == '\\' as u16,
+            self.source()[max_start] == '\\' as u16,
+        );
+        let span = start..self.index();
+        if let Some(problem) = problem(min as u32, max as u32, escaped) {
+            if let Some(found) = &mut self.suspicious_ranges {
+                found.push(SuspiciousRange {
+                    problem,
+                    span,
+                    min: min as u32,
+                    max: max as u32,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EcmaVersion;
+
+    fn problems(source: &str) -> Vec<RangeProblem> {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let found = validator.find_suspicious_ranges(source, "u").unwrap();
+        found.into_iter().map(|r| r.problem).collect()
+    }
+
+    #[test]
+    fn suspicious() {
+        use RangeProblem::*;
+        assert_eq!(problems("[A-z]"), vec![MixedCase]);
+        assert_eq!(problems("[0-z]"), vec![LetterAndDigit]);
+        assert_eq!(problems("[a-я]"), vec![DifferentScripts]);
+        assert_eq!(problems("[+--]"), vec![Punctuation]);
+        assert_eq!(problems("[\\x41-\\x5a]"), vec![EscapedEndpoint]);
+        assert_eq!(problems("[\\u0000-\\x5d]"), vec![]);
+    }
+
+    #[test]
+    fn intended() {
+        assert_eq!(problems("[a-zA-Z0-9_.+-]"), vec![]);
+        assert_eq!(problems("[а-яё\\u0370-\\u03ff]"), vec![]);
+    }
+
+    #[test]
+    fn characters() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let found = validator.find_suspicious_ranges("x[A-z]", "").unwrap();
+        assert_eq!(found[0].span, 2..5);
+        assert_eq!(
+            validator.find_suspicious_ranges("[\\d-z]", "").unwrap(),
+            vec![]
+        );
+        assert_eq!(
+            found[0].characters(),
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz"
+        );
+    }
+
+    #[test]
+    fn out_of_order() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        assert_eq!(
+            validator.validate_pattern("[a-Z]", false),
+            Err("Range out of order in character class: did you mean a-zA-Z?".to_string())
+        );
+        assert_eq!(
+            validator.validate_pattern("[z-a]", false),
+            Err("Range out of order in character class".to_string())
+        );
+    }
+}
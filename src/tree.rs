@@ -0,0 +1,1055 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::flags::{any_other_case, cased_part};
+use crate::validator::EcmaRegexValidator;
+
+const MAX_CODE_POINT: u32 = 0x10ffff;
+/// Sets that the analyses do not look into, like property escapes, get made-up code points
+/// above U+10FFFF. `\p{..}` gets an even one and the same escape with `\P` the next one.
+const FIRST_OPAQUE: u32 = MAX_CODE_POINT + 1;
+
+/// A set of code points, as sorted ranges that neither overlap nor touch.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct CharSet(Vec<(u32, u32)>);
+
+impl CharSet {
+    pub(crate) fn from_ranges(ranges: &[(u32, u32)]) -> Self {
+        let mut ranges = ranges.to_vec();
+        ranges.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (min, max) in ranges {
+            match merged.last_mut() {
+                Some(last) if min <= last.1.saturating_add(1) => last.1 = last.1.max(max),
+                _ => merged.push((min, max)),
+            }
+        }
+        CharSet(merged)
+    }
+
+    pub(crate) fn single(cp: u32) -> Self {
+        CharSet(vec![(cp, cp)])
+    }
+
+    pub(crate) fn full() -> Self {
+        CharSet(vec![(0, MAX_CODE_POINT)])
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the sorted ranges of the set.
+    pub(crate) fn ranges(&self) -> &[(u32, u32)] {
+        &self.0
+    }
+
+    pub(crate) fn contains(&self, cp: u32) -> bool {
+        self.0
+            .binary_search_by(|&(min, max)| {
+                if max < cp {
+                    core::cmp::Ordering::Less
+                } else if min > cp {
+                    core::cmp::Ordering::Greater
+                } else {
+                    core::cmp::Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// Returns the part of the set with real code points and the part with opaque ones.
+    fn split_opaque(&self) -> (CharSet, CharSet) {
+        let real = self.intersect(&CharSet::full());
+        let opaque = self.intersect(&CharSet(vec![(FIRST_OPAQUE, u32::MAX)]));
+        (real, opaque)
+    }
+
+    pub(crate) fn union(&self, other: &CharSet) -> CharSet {
+        let mut ranges = self.0.clone();
+        ranges.extend_from_slice(&other.0);
+        CharSet::from_ranges(&ranges)
+    }
+
+    pub(crate) fn intersect(&self, other: &CharSet) -> CharSet {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.0.len() && j < other.0.len() {
+            let (a, b) = (self.0[i], other.0[j]);
+            let (min, max) = (a.0.max(b.0), a.1.min(b.1));
+            if min <= max {
+                ranges.push((min, max));
+            }
+            if a.1 < b.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        CharSet(ranges)
+    }
+
+    /// Returns the characters that are not in the set. For a set with opaque code points this
+    /// is approximated by the opposite opaque code points, so `[^\p{L}]` is `\P{L}`.
+    pub(crate) fn complement(&self) -> CharSet {
+        let (_, opaque) = self.split_opaque();
+        if !opaque.is_empty() {
+            let mut ranges = Vec::new();
+            for &(min, max) in opaque.ranges() {
+                ranges.extend((min..=max).map(|cp| (cp ^ 1, cp ^ 1)));
+            }
+            return CharSet::from_ranges(&ranges);
+        }
+        let mut ranges = Vec::new();
+        let mut next = 0;
+        for &(min, max) in &self.0 {
+            if min > next {
+                ranges.push((next, min - 1));
+            }
+            next = max + 1;
+        }
+        if next <= MAX_CODE_POINT {
+            ranges.push((next, MAX_CODE_POINT));
+        }
+        CharSet(ranges)
+    }
+
+    /// Adds every character that is the same as one of the set when ignoring case, with
+    /// simple case folding for the `u` flag. Opaque code points are kept as they are.
+    pub(crate) fn fold_case(&self, unicode: bool) -> CharSet {
+        let (real, opaque) = self.split_opaque();
+        // Only cased characters outside the set can join it, through another case in it.
+        let mut ranges = real.0.clone();
+        for &(min, max) in cased_part(&real.complement()).ranges() {
+            for cp in min..=max {
+                if any_other_case(cp, unicode, |other| real.contains(other)) {
+                    ranges.push((cp, cp));
+                }
+            }
+        }
+        ranges.extend_from_slice(&opaque.0);
+        CharSet::from_ranges(&ranges)
+    }
+
+    /// Returns a readable character of the set, if it has one that is not a lone surrogate.
+    pub(crate) fn representative(&self) -> Option<char> {
+        ['a', '0', ' ', 'A', '_', '-', '.']
+            .iter()
+            .copied()
+            .chain('b'..='z')
+            .chain('1'..='9')
+            .chain('B'..='Z')
+            .find(|&c| self.contains(c as u32))
+            .or_else(|| {
+                self.0
+                    .iter()
+                    .find_map(|&(min, max)| (min..=max.min(min + 0x800)).find_map(char::from_u32))
+            })
+    }
+}
+
+/// The group a backreference refers to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum GroupRef {
+    Index(u32),
+    /// The name as written in the pattern.
+    Name(String),
+}
+
+/// The syntax tree of a validated pattern, with what each part can match.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Node {
+    /// An empty alternative.
+    Empty,
+    /// A single character of the set.
+    Chars(CharSet, Range<usize>),
+    /// `^`, `$`, `\b` or `\B`.
+    Assertion(Range<usize>),
+    /// A lookahead or lookbehind assertion.
+    Lookaround {
+        body: Box<Node>,
+        behind: bool,
+        negated: bool,
+        span: Range<usize>,
+    },
+    /// A numbered or named backreference.
+    Backreference {
+        group: GroupRef,
+        span: Range<usize>,
+    },
+    /// A capturing or non-capturing group.
+    Group {
+        body: Box<Node>,
+        /// The number of a capturing group, counted from 1.
+        index: Option<u32>,
+        /// The name of a named group, as written in the pattern.
+        name: Option<String>,
+        span: Range<usize>,
+    },
+    Alternation(Vec<Node>),
+    Sequence(Vec<Node>),
+    Quantified {
+        body: Box<Node>,
+        min: u32,
+        /// `None` if the quantifier is unbounded.
+        max: Option<u32>,
+        span: Range<usize>,
+    },
+}
+
+impl Node {
+    /// Returns the node inside of groups.
+    pub(crate) fn unwrap_groups(&self) -> &Node {
+        match self {
+            Node::Group { body, .. } => body.unwrap_groups(),
+            Node::Sequence(nodes) if nodes.len() == 1 => nodes[0].unwrap_groups(),
+            _ => self,
+        }
+    }
+
+    /// Whether the node can match the empty string.
+    pub(crate) fn is_nullable(&self) -> bool {
+        match self {
+            Node::Chars(..) => false,
+            Node::Group { body, .. } => body.is_nullable(),
+            Node::Alternation(nodes) => nodes.iter().any(Node::is_nullable),
+            Node::Sequence(nodes) => nodes.iter().all(Node::is_nullable),
+            Node::Quantified { body, min, .. } => *min == 0 || body.is_nullable(),
+            _ => true,
+        }
+    }
+
+    /// Whether the node matches the empty string wherever it is, so it can never fail.
+    pub(crate) fn always_matches(&self) -> bool {
+        match self {
+            Node::Empty => true,
+            Node::Group { body, .. } => body.always_matches(),
+            Node::Alternation(nodes) => nodes.iter().any(Node::always_matches),
+            Node::Sequence(nodes) => nodes.iter().all(Node::always_matches),
+            Node::Quantified { body, min, .. } => *min == 0 || body.always_matches(),
+            _ => false,
+        }
+    }
+
+    /// Returns the set of characters the node matches if it always matches exactly one.
+    pub(crate) fn single_char_set(&self) -> Option<CharSet> {
+        match self.unwrap_groups() {
+            Node::Chars(set, _) => Some(set.clone()),
+            Node::Alternation(nodes) => {
+                let mut set = CharSet::default();
+                for node in nodes {
+                    set = set.union(&node.single_char_set()?);
+                }
+                Some(set)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns a short string the node matches, ignoring assertions and backreferences.
+    pub(crate) fn example(&self) -> String {
+        let mut example = String::new();
+        self.write_example(&mut example);
+        example
+    }
+
+    fn write_example(&self, out: &mut String) {
+        match self {
+            Node::Chars(set, _) => out.extend(set.representative()),
+            Node::Group { body, .. } => body.write_example(out),
+            Node::Alternation(nodes) => {
+                if let Some(node) = nodes.first() {
+                    node.write_example(out);
+                }
+            }
+            Node::Sequence(nodes) => nodes.iter().for_each(|node| node.write_example(out)),
+            Node::Quantified { body, min, .. } => {
+                for _ in 0..*min {
+                    body.write_example(out);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Calls `f` for every set of characters in the node.
+    pub(crate) fn for_each_set<F: FnMut(&CharSet)>(&self, f: &mut F) {
+        match self {
+            Node::Chars(set, _) => f(set),
+            Node::Group { body, .. } | Node::Lookaround { body, .. } => body.for_each_set(f),
+            Node::Quantified { body, .. } => body.for_each_set(f),
+            Node::Alternation(nodes) | Node::Sequence(nodes) => {
+                nodes.iter().for_each(|node| node.for_each_set(f))
+            }
+            _ => {}
+        }
+    }
+}
+
+impl EcmaRegexValidator {
+    /// Validates the regular expression literal `/source/flags` and returns its syntax tree.
+    pub(crate) fn parse_tree(&mut self, source: &str, flags: &str) -> Result<Node, String> {
+        self.validate_flags(flags)?;
+        let u_flag = flags.contains('u');
+        self.validate_pattern(source, u_flag)?;
+        let units: Vec<u16> = source.encode_utf16().collect();
+        let mut parser = Parser {
+            units: &units,
+            pos: 0,
+            u_flag,
+            ignore_case: flags.contains('i'),
+            dot_all: flags.contains('s'),
+            group_count: self.capturing_group_count(),
+            named_references: self.has_named_references(),
+            groups: 0,
+            properties: Vec::new(),
+        };
+        Ok(parser.disjunction())
+    }
+}
+
+/// A lenient parser for patterns that were already validated.
+///
+/// The validator reads a pattern in a single pass, backtracks where the grammar is ambiguous,
+/// and keeps nothing of its structure beyond what the recording hooks ask for. Threading a
+/// tree through it would cost every validation, so the analyses that need alternatives,
+/// quantified terms and the sets of nested terms parse the pattern again here. Everything
+/// the grammar leaves open is taken from the validator: the pattern is valid, and the number
+/// of groups and whether `\k` is a backreference decide how `\1`, `\10` and `\k<a>` read.
+/// The `agrees_with_validator` test runs both parsers on the same patterns and compares the
+/// groups, classes, escapes and backreferences they read.
+struct Parser<'a> {
+    units: &'a [u16],
+    pos: usize,
+    u_flag: bool,
+    ignore_case: bool,
+    dot_all: bool,
+    group_count: u32,
+    named_references: bool,
+    /// The number of capturing groups opened so far.
+    groups: u32,
+    /// The property escapes read so far, as written after the `p` or `P`.
+    properties: Vec<&'a [u16]>,
+}
+
+fn digit_set() -> CharSet {
+    CharSet::from_ranges(&[('0' as u32, '9' as u32)])
+}
+
+fn word_set() -> CharSet {
+    CharSet::from_ranges(&[
+        ('0' as u32, '9' as u32),
+        ('A' as u32, 'Z' as u32),
+        ('_' as u32, '_' as u32),
+        ('a' as u32, 'z' as u32),
+    ])
+}
+
+fn space_set() -> CharSet {
+    CharSet::from_ranges(&[
+        (0x09, 0x0d),
+        (0x20, 0x20),
+        (0xa0, 0xa0),
+        (0x1680, 0x1680),
+        (0x2000, 0x200a),
+        (0x2028, 0x2029),
+        (0x202f, 0x202f),
+        (0x205f, 0x205f),
+        (0x3000, 0x3000),
+        (0xfeff, 0xfeff),
+    ])
+}
+
+/// Returns the set of `\d`, `\s`, `\w` or their negations, given the letter after the
+/// backslash.
+pub(crate) fn class_escape_set(letter: char) -> Option<CharSet> {
+    let set = match letter.to_ascii_lowercase() {
+        'd' => digit_set(),
+        's' => space_set(),
+        'w' => word_set(),
+        _ => return None,
+    };
+    if letter.is_ascii_uppercase() {
+        return Some(set.complement());
+    }
+    Some(set)
+}
+
+fn line_terminator_set() -> CharSet {
+    CharSet::from_ranges(&[(0x0a, 0x0a), (0x0d, 0x0d), (0x2028, 0x2029)])
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u32> {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<u32> {
+        self.units.get(self.pos + offset).map(|&u| u as u32)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c as u32) {
+            self.pos += 1;
+            return true;
+        }
+        false
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        let len = s.len();
+        if s.bytes()
+            .enumerate()
+            .all(|(i, b)| self.peek_at(i) == Some(b as u32))
+        {
+            self.pos += len;
+            return true;
+        }
+        false
+    }
+
+    /// Reads the next code point, combining surrogate pairs in unicode mode.
+    fn next_code_point(&mut self) -> u32 {
+        let lead = self.units[self.pos] as u32;
+        self.pos += 1;
+        if self.u_flag && (0xd800..=0xdbff).contains(&lead) {
+            if let Some(trail) = self.peek().filter(|t| (0xdc00..=0xdfff).contains(t)) {
+                self.pos += 1;
+                return (lead - 0xd800) * 0x400 + (trail - 0xdc00) + 0x10000;
+            }
+        }
+        lead
+    }
+
+    fn chars(&self, set: CharSet, start: usize) -> Node {
+        let set = if self.ignore_case {
+            set.fold_case(self.u_flag)
+        } else {
+            set
+        };
+        Node::Chars(set, start..self.pos)
+    }
+
+    fn disjunction(&mut self) -> Node {
+        let mut alternatives = vec![self.alternative()];
+        while self.eat('|') {
+            alternatives.push(self.alternative());
+        }
+        if alternatives.len() == 1 {
+            return alternatives.pop().unwrap();
+        }
+        Node::Alternation(alternatives)
+    }
+
+    fn alternative(&mut self) -> Node {
+        let mut nodes = Vec::new();
+        while let Some(cp) = self.peek() {
+            if cp == '|' as u32 || cp == ')' as u32 {
+                break;
+            }
+            nodes.push(self.term());
+        }
+        match nodes.len() {
+            0 => Node::Empty,
+            _ => Node::Sequence(nodes),
+        }
+    }
+
+    fn term(&mut self) -> Node {
+        let start = self.pos;
+        let atom = if self.eat('^') || self.eat('$') || self.eat_str("\\b") || self.eat_str("\\B") {
+            return Node::Assertion(start..self.pos);
+        } else if self.eat('(') {
+            self.group(start)
+        } else if self.eat('[') {
+            self.class(start)
+        } else if self.eat('.') {
+            let set = if self.dot_all {
+                CharSet::full()
+            } else {
+                line_terminator_set().complement()
+            };
+            Node::Chars(set, start..self.pos)
+        } else if self.eat('\\') {
+            self.atom_escape(start)
+        } else {
+            let cp = self.next_code_point();
+            self.chars(CharSet::single(cp), start)
+        };
+        self.quantifier(atom, start)
+    }
+
+    fn group(&mut self, start: usize) -> Node {
+        let lookaround = ["?=", "?!", "?<=", "?<!"]
+            .iter()
+            .find(|prefix| self.eat_str(prefix))
+            .copied();
+        let mut index = None;
+        let mut name = None;
+        if lookaround.is_none() && !self.eat_str("?:") {
+            self.groups += 1;
+            index = Some(self.groups);
+            if self.eat_str("?<") {
+                name = Some(self.group_name());
+            }
+        }
+        let body = Box::new(self.disjunction());
+        self.eat(')');
+        let span = start..self.pos;
+        match lookaround {
+            Some(prefix) => Node::Lookaround {
+                body,
+                behind: prefix.starts_with("?<"),
+                negated: prefix.ends_with('!'),
+                span,
+            },
+            None => Node::Group {
+                body,
+                index,
+                name,
+                span,
+            },
+        }
+    }
+
+    /// Reads a group name up to and including the closing `>`, and decodes its escapes.
+    fn group_name(&mut self) -> String {
+        let mut name = Vec::new();
+        while self.peek().is_some() && !self.eat('>') {
+            if self.eat_str("\\u") {
+                let cp = if self.eat('{') {
+                    let cp = self.hex_value();
+                    self.eat('}');
+                    cp
+                } else {
+                    self.hex_digits(4).unwrap_or('u' as u32)
+                };
+                match char::from_u32(cp) {
+                    Some(c) => name.extend_from_slice(c.encode_utf16(&mut [0; 2])),
+                    // A lead surrogate, which the next escape completes.
+                    None => name.push(cp as u16),
+                }
+                continue;
+            }
+            name.push(self.units[self.pos]);
+            self.pos += 1;
+        }
+        String::from_utf16_lossy(&name)
+    }
+
+    fn quantifier(&mut self, atom: Node, start: usize) -> Node {
+        let (min, max) = if self.eat('*') {
+            (0, None)
+        } else if self.eat('+') {
+            (1, None)
+        } else if self.eat('?') {
+            (0, Some(1))
+        } else if let Some(bounds) = self.braced_quantifier() {
+            bounds
+        } else {
+            return atom;
+        };
+        self.eat('?');
+        Node::Quantified {
+            body: Box::new(atom),
+            min,
+            max,
+            span: start..self.pos,
+        }
+    }
+
+    /// Reads `{n}`, `{n,}` or `{n,m}`, and leaves a lone `{` alone.
+    fn braced_quantifier(&mut self) -> Option<(u32, Option<u32>)> {
+        let start = self.pos;
+        if !self.eat('{') {
+            return None;
+        }
+        let bounds = match self.decimal() {
+            Some(min) if self.eat('}') => Some((min, Some(min))),
+            Some(min) if self.eat_str(",}") => Some((min, None)),
+            Some(min) if self.eat(',') => match self.decimal() {
+                Some(max) if self.eat('}') => Some((min, Some(max))),
+                _ => None,
+            },
+            _ => None,
+        };
+        if bounds.is_none() {
+            self.pos = start;
+        }
+        bounds
+    }
+
+    fn decimal(&mut self) -> Option<u32> {
+        let start = self.pos;
+        let mut value: u32 = 0;
+        while let Some(digit) = self.peek().and_then(|cp| char::from_u32(cp)?.to_digit(10)) {
+            value = value.saturating_mul(10).saturating_add(digit);
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return None;
+        }
+        Some(value)
+    }
+
+    fn atom_escape(&mut self, start: usize) -> Node {
+        if let Some(set) = self.class_escape() {
+            return self.chars(set, start);
+        }
+        let digit = self
+            .peek()
+            .filter(|&cp| ('1' as u32..='9' as u32).contains(&cp));
+        if digit.is_some() {
+            let after = self.pos;
+            let n = self.decimal().unwrap_or(0);
+            if self.u_flag || n <= self.group_count {
+                return Node::Backreference {
+                    group: GroupRef::Index(n),
+                    span: start..self.pos,
+                };
+            }
+            self.pos = after;
+        }
+        if self.named_references && self.eat_str("k<") {
+            let name = self.group_name();
+            return Node::Backreference {
+                group: GroupRef::Name(name),
+                span: start..self.pos,
+            };
+        }
+        let cp = self.character_escape(false);
+        self.chars(CharSet::single(cp), start)
+    }
+
+    /// Reads `\d`, `\s`, `\w`, their negations and property escapes after the backslash.
+    /// Property escapes get an opaque code point for each way of writing them, so they only
+    /// overlap with themselves.
+    fn class_escape(&mut self) -> Option<CharSet> {
+        let c = char::from_u32(self.peek()?)?;
+        if c.eq_ignore_ascii_case(&'p') && self.u_flag {
+            self.pos += 1;
+            let start = self.pos;
+            while self.peek().is_some() && !self.eat('}') {
+                self.pos += 1;
+            }
+            let text = &self.units[start..self.pos];
+            let index = match self.properties.iter().position(|&p| p == text) {
+                Some(index) => index,
+                None => {
+                    self.properties.push(text);
+                    self.properties.len() - 1
+                }
+            };
+            let negated = c == 'P';
+            return Some(CharSet::single(
+                FIRST_OPAQUE + 2 * index as u32 + negated as u32,
+            ));
+        }
+        let set = class_escape_set(c)?;
+        self.pos += 1;
+        Some(set)
+    }
+
+    /// Reads the escaped character after the backslash and returns its code point.
+    fn character_escape(&mut self, in_class: bool) -> u32 {
+        let c = match self.peek().and_then(char::from_u32) {
+            Some(c) => c,
+            None => return '\\' as u32,
+        };
+        let simple = match c {
+            'n' => Some(0x0a),
+            'r' => Some(0x0d),
+            't' => Some(0x09),
+            'v' => Some(0x0b),
+            'f' => Some(0x0c),
+            _ => None,
+        };
+        if let Some(cp) = simple {
+            self.pos += 1;
+            return cp;
+        }
+        match c {
+            'c' => {
+                let letter = self.peek_at(1).and_then(char::from_u32);
+                match letter {
+                    Some(l) if l.is_ascii_alphabetic() => {
+                        self.pos += 2;
+                        l as u32 % 32
+                    }
+                    Some(l) if in_class && !self.u_flag && (l.is_ascii_digit() || l == '_') => {
+                        self.pos += 2;
+                        l as u32 % 32
+                    }
+                    // A lone `\c` matches the backslash, and the `c` is read next.
+                    _ => '\\' as u32,
+                }
+            }
+            'x' => {
+                self.pos += 1;
+                self.hex_digits(2).unwrap_or('x' as u32)
+            }
+            'u' => {
+                self.pos += 1;
+                self.unicode_escape().unwrap_or('u' as u32)
+            }
+            '0'..='7' if !self.u_flag => self.legacy_octal(),
+            '0' => {
+                self.pos += 1;
+                0
+            }
+            _ => self.next_code_point(),
+        }
+    }
+
+    fn hex_digits(&mut self, count: usize) -> Option<u32> {
+        let mut value = 0;
+        for i in 0..count {
+            let digit = self.peek_at(i).and_then(char::from_u32)?.to_digit(16)?;
+            value = value * 16 + digit;
+        }
+        self.pos += count;
+        Some(value)
+    }
+
+    /// Reads hexadecimal digits of any length, like in `\u{1F600}`.
+    fn hex_value(&mut self) -> u32 {
+        let mut value: u32 = 0;
+        while let Some(digit) = self.peek().and_then(|cp| char::from_u32(cp)?.to_digit(16)) {
+            value = value.saturating_mul(16).saturating_add(digit);
+            self.pos += 1;
+        }
+        value
+    }
+
+    fn unicode_escape(&mut self) -> Option<u32> {
+        if self.u_flag && self.eat('{') {
+            let value = self.hex_value();
+            self.eat('}');
+            return Some(value);
+        }
+        let lead = self.hex_digits(4)?;
+        if self.u_flag && (0xd800..=0xdbff).contains(&lead) {
+            let after = self.pos;
+            if self.eat_str("\\u") {
+                match self.hex_digits(4) {
+                    Some(trail) if (0xdc00..=0xdfff).contains(&trail) => {
+                        return Some((lead - 0xd800) * 0x400 + (trail - 0xdc00) + 0x10000);
+                    }
+                    _ => self.pos = after,
+                }
+            }
+        }
+        Some(lead)
+    }
+
+    fn legacy_octal(&mut self) -> u32 {
+        let mut value = 0;
+        for _ in 0..3 {
+            match self.peek().and_then(|cp| char::from_u32(cp)?.to_digit(8)) {
+                Some(digit) if value * 8 + digit <= 0o377 => {
+                    value = value * 8 + digit;
+                    self.pos += 1;
+                }
+                _ => break,
+            }
+        }
+        value
+    }
+
+    fn class(&mut self, start: usize) -> Node {
+        let negated = self.eat('^');
+        let mut set = CharSet::default();
+        while self.peek().is_some() && !self.eat(']') {
+            let min = self.class_atom();
+            if self.peek() == Some('-' as u32) && self.peek_at(1) != Some(']' as u32) {
+                self.pos += 1;
+                let max = self.class_atom();
+                match (min, max) {
+                    (Ok(min), Ok(max)) => set = set.union(&CharSet::from_ranges(&[(min, max)])),
+                    (min, max) => {
+                        // A class escape as a bound makes the range three atoms.
+                        for atom in [min, Ok('-' as u32), max].iter() {
+                            set = set.union(&match atom {
+                                Ok(cp) => CharSet::single(*cp),
+                                Err(escape) => escape.clone(),
+                            });
+                        }
+                    }
+                }
+                continue;
+            }
+            set = set.union(&match min {
+                Ok(cp) => CharSet::single(cp),
+                Err(escape) => escape,
+            });
+        }
+        if negated {
+            let set = if self.ignore_case {
+                set.fold_case(self.u_flag)
+            } else {
+                set
+            };
+            return Node::Chars(set.complement(), start..self.pos);
+        }
+        self.chars(set, start)
+    }
+
+    /// Reads a class atom as a single code point, or as the set of a class escape.
+    fn class_atom(&mut self) -> Result<u32, CharSet> {
+        if !self.eat('\\') {
+            return Ok(self.next_code_point());
+        }
+        if self.eat('b') {
+            return Ok(0x08);
+        }
+        if self.eat('-') {
+            return Ok('-' as u32);
+        }
+        if let Some(set) = self.class_escape() {
+            return Err(set);
+        }
+        Ok(self.character_escape(true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EcmaVersion;
+
+    fn parse(source: &str, flags: &str) -> Node {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        validator.parse_tree(source, flags).unwrap()
+    }
+
+    #[test]
+    fn char_sets() {
+        let set = CharSet::from_ranges(&[(5, 10), (0, 3), (4, 4), (20, 30)]);
+        assert_eq!(set, CharSet(vec![(0, 10), (20, 30)]));
+        assert_eq!(
+            set.intersect(&CharSet::from_ranges(&[(8, 25)])),
+            CharSet(vec![(8, 10), (20, 25)])
+        );
+        assert_eq!(
+            set.complement(),
+            CharSet(vec![(11, 19), (31, MAX_CODE_POINT)])
+        );
+        assert_eq!(digit_set().representative(), Some('0'));
+        assert_eq!(
+            CharSet::single('a' as u32).fold_case(false),
+            CharSet(vec![(65, 65), (97, 97)])
+        );
+        assert!(CharSet::single('k' as u32).fold_case(true).contains(0x212a));
+        assert!(!CharSet::single('k' as u32)
+            .fold_case(false)
+            .contains(0x212a));
+        assert!(CharSet::single(0x3c3).fold_case(false).contains(0x3a3));
+        let not_a = CharSet::single('a' as u32).complement();
+        assert_eq!(not_a.fold_case(false), CharSet::full());
+    }
+
+    #[test]
+    fn quantifiers() {
+        let node = parse("a{2,}b{,1}", "");
+        let nodes = match node {
+            Node::Sequence(nodes) => nodes,
+            _ => panic!("not a sequence"),
+        };
+        assert_eq!(nodes.len(), 6);
+        match &nodes[0] {
+            Node::Quantified { min, max, span, .. } => {
+                assert_eq!((*min, *max, span.clone()), (2, None, 0..5))
+            }
+            _ => panic!("not quantified"),
+        }
+        assert!(parse("(a|b?)", "").is_nullable());
+        assert!(!parse("(a|b?)$", "").always_matches());
+    }
+
+    #[test]
+    fn classes() {
+        let set = |source: &str, flags: &str| parse(source, flags).single_char_set().unwrap();
+        assert_eq!(set("[\\w-]", ""), word_set().union(&CharSet::single(45)));
+        assert!(!set("[^-a]", "").contains('-' as u32));
+        assert_eq!(set("[\\d-z]", ""), set("[-\\dz]", ""));
+        assert_eq!(set("[😀]", "u"), CharSet::single(0x1f600));
+        assert_eq!(set("[😀]", "").0.len(), 2);
+        assert_eq!(set("\\u{1F600}", "u"), CharSet::single(0x1f600));
+        assert_eq!(set("[\\0\\101]", ""), CharSet(vec![(0, 0), (65, 65)]));
+        assert_eq!(set("(?:A|b)", "i"), set("[abAB]", ""));
+        assert_eq!(set("\\u00e9", "i"), set("[\\u00e9\\u00c9]", ""));
+        assert_eq!(set("[^-]", ""), CharSet::single('-' as u32).complement());
+        assert_eq!(set("[^\\-a]", ""), set("[^a\\-]", ""));
+    }
+
+    #[cfg(feature = "unicode-properties")]
+    #[test]
+    fn property_escapes() {
+        let sets: Vec<CharSet> = match parse("\\p{L}|\\p{N}|[a-z]|[^\\p{L}]|\\P{L}", "u") {
+            Node::Alternation(nodes) => {
+                nodes.iter().map(|n| n.single_char_set().unwrap()).collect()
+            }
+            _ => panic!("not an alternation"),
+        };
+        assert!(sets[0].intersect(&sets[1]).is_empty());
+        assert!(sets[0].intersect(&sets[2]).is_empty());
+        assert!(sets[0].intersect(&sets[4]).is_empty());
+        assert_eq!(sets[3], sets[4]);
+        assert_eq!(sets[0].representative(), None);
+    }
+
+    #[test]
+    fn group_names() {
+        let name = |source: &str| match parse(source, "u") {
+            Node::Sequence(nodes) => match &nodes[0] {
+                Node::Group { name, .. } => name.clone(),
+                _ => panic!("not a group"),
+            },
+            _ => panic!("not a sequence"),
+        };
+        assert_eq!(name("(?<a>x)"), Some("a".to_string()));
+        assert_eq!(name("(?<\\u0061b>x)"), Some("ab".to_string()));
+        assert_eq!(name("(?<\\ud835\\udc9c>x)"), Some("\u{1d49c}".to_string()));
+    }
+
+    /// Returns the nodes of the tree in the order they start.
+    fn nodes(node: &Node) -> Vec<&Node> {
+        let mut nodes = vec![node];
+        match node {
+            Node::Group { body, .. }
+            | Node::Lookaround { body, .. }
+            | Node::Quantified { body, .. } => nodes.extend(self::nodes(body)),
+            Node::Alternation(children) | Node::Sequence(children) => children
+                .iter()
+                .for_each(|child| nodes.extend(self::nodes(child))),
+            _ => {}
+        }
+        nodes
+    }
+
+    fn chars_at(tree: &Node, at: &Range<usize>) -> Option<CharSet> {
+        nodes(tree).into_iter().find_map(|node| match node {
+            Node::Chars(set, span) if span == at => Some(set.clone()),
+            _ => None,
+        })
+    }
+
+    fn opaque_part(tree: &Node, at: &Range<usize>) -> CharSet {
+        let opaque = CharSet(vec![(FIRST_OPAQUE, u32::MAX)]);
+        chars_at(tree, at).unwrap().intersect(&opaque)
+    }
+
+    /// Checks that the tree reads `/source/flags` like the validator does.
+    fn cross_check(source: &str, flags: &str) {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        let tree = validator.parse_tree(source, flags).unwrap();
+        let units: Vec<u16> = source.encode_utf16().collect();
+        let nodes = nodes(&tree);
+
+        let groups = nodes
+            .iter()
+            .filter_map(|node| match node {
+                Node::Group { index, .. } => *index,
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0);
+        assert_eq!(groups, validator.capturing_group_count(), "{}", source);
+        for node in &nodes {
+            if let Node::Backreference { group, span } = node {
+                match group {
+                    GroupRef::Index(n) => assert!(*n <= groups, "{} at {:?}", source, span),
+                    GroupRef::Name(name) => assert!(nodes.iter().any(|node| {
+                        matches!(node, Node::Group { name: Some(n), .. } if n == name)
+                    })),
+                }
+            }
+        }
+
+        // Escapes that Annex B reads as characters are characters in the tree as well.
+        for usage in validator.find_annex_b(source, flags).unwrap() {
+            let is_backreference = nodes.iter().any(
+                |node| matches!(node, Node::Backreference { span, .. } if span.start == usage.span.start),
+            );
+            assert!(!is_backreference, "{} at {:?}", source, usage.span);
+        }
+
+        validator.class_member_lists = Some(Vec::new());
+        validator
+            .validate_pattern(source, flags.contains('u'))
+            .unwrap();
+        let classes = validator.class_member_lists.take().unwrap();
+        for class in classes.iter().filter(|class| class.analysable) {
+            let mut set = CharSet::default();
+            for member in &class.members {
+                let letter = units.get(member.span.start + 1).map(|&u| u as u32);
+                // Property escapes are opaque, so only their code points are compared.
+                let escape = letter.and_then(char::from_u32).and_then(class_escape_set);
+                set = set.union(&match member.min {
+                    -1 => escape.unwrap_or_else(|| opaque_part(&tree, &class.span)),
+                    _ => CharSet::from_ranges(&[(member.min as u32, member.max as u32)]),
+                });
+            }
+            if units[class.span.start + 1] == '^' as u16 {
+                set = set.complement();
+            }
+            let tree_set = chars_at(&tree, &class.span).unwrap();
+            assert_eq!(set, tree_set, "{}", source);
+        }
+
+        let in_class = |at: &Range<usize>| {
+            classes
+                .iter()
+                .any(|c| c.span.start <= at.start && at.end <= c.span.end)
+        };
+        for control in validator
+            .find_control_characters(source, flags, &[])
+            .unwrap()
+        {
+            if !in_class(&control.span) {
+                let set = chars_at(&tree, &control.span);
+                assert_eq!(set, Some(CharSet::single(control.value)), "{}", source);
+            }
+        }
+    }
+
+    #[test]
+    fn agrees_with_validator() {
+        let patterns = [
+            ("[^-a][^- ][a-]", ""),
+            ("[\\-a][a\\-z]", "u"),
+            ("[\\w-z][\\d-\\s]", ""),
+            ("[\\b\\0\\01\\101\\c1\\c_]\\b", ""),
+            ("\\0\\00\\01\\7\\123\\400", ""),
+            ("\\1\\8\\9(a)\\1\\2", ""),
+            ("(a)(b)(c)(d)(e)(f)(g)(h)(i)(j)\\10\\11\\100", ""),
+            ("(a)\\1(?:b)\\1", "u"),
+            ("\\k<a>(?<a>b)\\k<a>", ""),
+            ("\\k<a>", ""),
+            ("\\c\\cA\\c1\\x4g\\x41\\u004\\u0041", ""),
+            ("\\u{41}\\u{1F600}\\uD83D\\uDE00[\\uD83D\\uDE00]", "u"),
+            ("\\u{41}", ""),
+            ("[😀-😂]\\t\\n\\v\\f\\r", "u"),
+            ("a{1,2}{]}x{,2}{1}", ""),
+            ("(?=a)*(?!b)+(?<=c)(?<!d)", ""),
+            ("(?:a|b)+?c*?d??e{2,}?", ""),
+            ("\\p{L}[\\p{L}]", ""),
+            ("[^]|[]|.", "s"),
+        ];
+        for (source, flags) in patterns.iter() {
+            cross_check(source, flags);
+        }
+    }
+
+    #[cfg(feature = "unicode-properties")]
+    #[test]
+    fn agrees_with_validator_on_properties() {
+        cross_check("\\p{L}[\\p{Lu}a\\d]\\P{L}[\\P{Lu}]", "u");
+    }
+}
@@ -0,0 +1,268 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::annex_b::AnnexB;
+use crate::validator::EcmaRegexValidator;
+
+/// Something that changes when the `u` flag is added to a regular expression.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum UFlagChange {
+    /// Annex B syntax that is a syntax error with the `u` flag.
+    SyntaxError(AnnexB),
+    /// An escaped character that is neither an identifier character nor a syntax character,
+    /// like `\-` or `\:`. It is core syntax, but an error with the `u` flag.
+    InvalidEscape,
+    /// `\u{...}` becomes a code point escape instead of a repeated `u`.
+    CodePointEscape,
+    /// `\p{...}` or `\P{...}` becomes a property escape instead of the letters themselves.
+    PropertyEscape,
+    /// `.` matches an astral code point as one character instead of a surrogate.
+    Dot,
+    /// A negated class `[^...]` matches an astral code point as one character.
+    NegatedClass,
+    /// `\D`, `\S` or `\W` matches an astral code point as one character.
+    NegatedClassEscape,
+    /// An astral character in a class or before a quantifier is one character instead of
+    /// two surrogates.
+    AstralCharacter,
+    /// Escaped surrogates like `\uD83D\uDE00` combine into one code point.
+    SurrogatePairEscape,
+    /// `\w`, `\W`, `\b` or `\B` with the `i` flag also match `ſ` (U+017F) and the Kelvin sign
+    /// (U+212A) because of Unicode case folding.
+    CaseFolding,
+}
+
+impl UFlagChange {
+    /// Whether the change makes the regular expression invalid, rather than changing what
+    /// it matches.
+    pub fn is_error(self) -> bool {
+        matches!(self, UFlagChange::SyntaxError(_) | UFlagChange::InvalidEscape)
+    }
+}
+
+impl fmt::Display for UFlagChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UFlagChange::SyntaxError(kind) => write!(f, "{} is invalid with the u flag", kind),
+            UFlagChange::InvalidEscape => write!(f, "escape is invalid with the u flag"),
+            UFlagChange::CodePointEscape => write!(f, "\\u{{...}} becomes a code point escape"),
+            UFlagChange::PropertyEscape => write!(f, "\\p{{...}} becomes a property escape"),
+            UFlagChange::Dot => write!(f, ". matches astral characters"),
+            UFlagChange::NegatedClass => write!(f, "negated class matches astral characters"),
+            UFlagChange::NegatedClassEscape => {
+                write!(f, "negated class escape matches astral characters")
+            }
+            UFlagChange::AstralCharacter => write!(f, "astral character is no longer split"),
+            UFlagChange::SurrogatePairEscape => write!(f, "escaped surrogate pair combines"),
+            UFlagChange::CaseFolding => write!(f, "word boundary or class escape case-folds"),
+        }
+    }
+}
+
+/// A place in a pattern that changes when the `u` flag is added.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct UFlagFinding {
+    pub change: UFlagChange,
+    /// The position in the pattern, counted in UTF-16 code units.
+    pub span: Range<usize>,
+}
+
+/// What happens when the `u` flag is added to a regular expression.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct UFlagAnalysis {
+    /// The error the regular expression has with the `u` flag, if any.
+    pub error: Option<String>,
+    /// Every place whose syntax or meaning changes, in the order they appear.
+    pub findings: Vec<UFlagFinding>,
+}
+
+impl UFlagAnalysis {
+    /// Whether adding the `u` flag neither breaks the regular expression nor changes what it
+    /// matches, so it can be added automatically.
+    pub fn is_safe(&self) -> bool {
+        self.error.is_none() && self.findings.is_empty()
+    }
+}
+
+impl EcmaRegexValidator {
+    /// Analyses what changes when the `u` flag is added to the regular expression literal
+    /// `/source/flags`. Returns an empty analysis if the flags already contain `u`, and an
+    /// error if the regular expression is invalid as it is.
+    pub fn analyze_u_flag(&mut self, source: &str, flags: &str) -> Result<UFlagAnalysis, String> {
+        self.validate_flags(flags)?;
+        if flags.contains('u') {
+            self.validate_pattern(source, true)?;
+            return Ok(UFlagAnalysis::default());
+        }
+
+        self.u_changes = Some(Vec::new());
+        let annex_b = self.find_annex_b(source, flags);
+        let recorded = self.u_changes.take().unwrap_or_default();
+        let units: Vec<u16> = source.encode_utf16().collect();
+        let starts_with = |span: &Range<usize>, prefix: &str| {
+            let prefix: Vec<u16> = prefix.encode_utf16().collect();
+            units[span.start..].starts_with(&prefix)
+        };
+
+        let mut findings = Vec::new();
+        for usage in annex_b? {
+            let mut span = usage.span;
+            let change = if usage.kind != AnnexB::IdentityEscape {
+                UFlagChange::SyntaxError(usage.kind)
+            } else if starts_with(&span, "\\u{") {
+                UFlagChange::CodePointEscape
+            } else if starts_with(&span, "\\p{") || starts_with(&span, "\\P{") {
+                UFlagChange::PropertyEscape
+            } else {
+                UFlagChange::SyntaxError(usage.kind)
+            };
+            if !change.is_error() {
+                // The new escape reaches up to the closing brace.
+                let close = units[span.end..].iter().position(|&u| u == '}' as u16);
+                span.end = close.map_or(units.len(), |i| span.end + i + 1);
+            }
+            findings.push(UFlagFinding { change, span });
+        }
+        for finding in recorded {
+            if finding.change != UFlagChange::CaseFolding || flags.contains('i') {
+                findings.push(finding);
+            }
+        }
+        // Braces of the new escapes are no lone brackets anymore.
+        let escapes: Vec<Range<usize>> = findings
+            .iter()
+            .filter(|finding| {
+                matches!(
+                    finding.change,
+                    UFlagChange::CodePointEscape | UFlagChange::PropertyEscape
+                )
+            })
+            .map(|finding| finding.span.clone())
+            .collect();
+        findings.retain(|finding| {
+            !escapes.iter().any(|escape| {
+                escape != &finding.span
+                    && escape.start <= finding.span.start
+                    && finding.span.end <= escape.end
+            })
+        });
+        findings.sort_by_key(|finding| (finding.span.start, finding.span.end, finding.change));
+
+        let error = self.validate_pattern(source, true).err();
+        return Ok(UFlagAnalysis { error, findings });
+    }
+
+    /// Records that the characters in `span` change with the `u` flag, if `analyze_u_flag`
+    /// asked for it.
+    pub(crate) fn record_u_change(&mut self, change: UFlagChange, span: Range<usize>) {
+        if let Some(findings) = &mut self.u_changes {
+            findings.push(UFlagFinding { change, span });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EcmaVersion;
+
+    fn analyze(source: &str, flags: &str) -> UFlagAnalysis {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        validator.analyze_u_flag(source, flags).unwrap()
+    }
+
+    fn changes(source: &str, flags: &str) -> Vec<(UFlagChange, Range<usize>)> {
+        let analysis = analyze(source, flags);
+        analysis
+            .findings
+            .into_iter()
+            .map(|finding| (finding.change, finding.span))
+            .collect()
+    }
+
+    #[test]
+    fn safe() {
+        assert!(analyze("^[a-z]+\\d*$", "g").is_safe());
+        assert!(analyze("\\w+", "").is_safe());
+        assert!(analyze("\\.\\/", "").is_safe());
+        assert!(analyze("[\\-]", "").is_safe());
+        assert!(analyze("[a\\-z]", "").is_safe());
+        assert!(analyze("a", "u").is_safe());
+    }
+
+    #[test]
+    fn syntax_errors() {
+        let analysis = analyze("\\-a]", "");
+        assert_eq!(analysis.error, Some("Invalid escape".to_string()));
+        assert_eq!(
+            changes("\\-a]", ""),
+            vec![
+                (UFlagChange::InvalidEscape, 0..2),
+                (UFlagChange::SyntaxError(AnnexB::LoneBracket), 3..4),
+            ]
+        );
+        assert_eq!(
+            changes("[\\:\\-]", ""),
+            vec![(UFlagChange::InvalidEscape, 1..3)]
+        );
+        assert_eq!(
+            changes("\\a", ""),
+            vec![(UFlagChange::SyntaxError(AnnexB::IdentityEscape), 0..2)]
+        );
+        assert!(UFlagChange::SyntaxError(AnnexB::LoneBracket).is_error());
+        assert!(!UFlagChange::Dot.is_error());
+    }
+
+    #[test]
+    fn new_escapes() {
+        assert_eq!(
+            changes("\\u{41}", ""),
+            vec![(UFlagChange::CodePointEscape, 0..6)]
+        );
+        assert_eq!(analyze("\\u{41}", "").error, None);
+        assert_eq!(
+            changes("\\p{L}", ""),
+            vec![(UFlagChange::PropertyEscape, 0..5)]
+        );
+    }
+
+    #[test]
+    fn astral_characters() {
+        assert_eq!(
+            changes(".[^a]\\S", ""),
+            vec![
+                (UFlagChange::Dot, 0..1),
+                (UFlagChange::NegatedClass, 1..5),
+                (UFlagChange::NegatedClassEscape, 5..7),
+            ]
+        );
+        assert_eq!(
+            changes("[😀]😀+😀", ""),
+            vec![
+                (UFlagChange::AstralCharacter, 1..3),
+                (UFlagChange::AstralCharacter, 4..6),
+            ]
+        );
+        assert_eq!(
+            changes("\\uD83D\\uDE00", ""),
+            vec![(UFlagChange::SurrogatePairEscape, 0..12)]
+        );
+    }
+
+    #[test]
+    fn case_folding() {
+        assert_eq!(changes("\\w\\b", ""), vec![]);
+        assert_eq!(
+            changes("\\w\\b", "i"),
+            vec![
+                (UFlagChange::CaseFolding, 0..2),
+                (UFlagChange::CaseFolding, 2..4)
+            ]
+        );
+    }
+}
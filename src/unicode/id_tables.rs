@@ -0,0 +1,352 @@
+// This file was generated by unicode-gen. Do not edit it by hand.
+//
+// Unicode Character Database versions:
+// 9.0.0 10.0.0 11.0.0 12.0.0 12.1.0 13.0.0 14.0.0 15.0.0 15.1.0 16.0.0
+
+use super::UnicodeVersion::{self, *};
+
+/// Non-ASCII `ID_Start` code points, tagged with the Unicode version they joined `ID_Start` in.
+pub static LARGE_ID_START_RANGES: &[(u32, u32, UnicodeVersion)] = &[
+    (0xaa, 0xaa, V9_0), (0xb5, 0xb5, V9_0), (0xba, 0xba, V9_0), (0xc0, 0xd6, V9_0),
+    (0xd8, 0xf6, V9_0), (0xf8, 0x2c1, V9_0), (0x2c6, 0x2d1, V9_0), (0x2e0, 0x2e4, V9_0),
+    (0x2ec, 0x2ec, V9_0), (0x2ee, 0x2ee, V9_0), (0x370, 0x374, V9_0), (0x376, 0x377, V9_0),
+    (0x37a, 0x37d, V9_0), (0x37f, 0x37f, V9_0), (0x386, 0x386, V9_0), (0x388, 0x38a, V9_0),
+    (0x38c, 0x38c, V9_0), (0x38e, 0x3a1, V9_0), (0x3a3, 0x3f5, V9_0), (0x3f7, 0x481, V9_0),
+    (0x48a, 0x52f, V9_0), (0x531, 0x556, V9_0), (0x559, 0x559, V9_0), (0x560, 0x560, V11_0),
+    (0x561, 0x587, V9_0), (0x588, 0x588, V11_0), (0x5d0, 0x5ea, V9_0), (0x5ef, 0x5ef, V11_0),
+    (0x5f0, 0x5f2, V9_0), (0x620, 0x64a, V9_0), (0x66e, 0x66f, V9_0), (0x671, 0x6d3, V9_0),
+    (0x6d5, 0x6d5, V9_0), (0x6e5, 0x6e6, V9_0), (0x6ee, 0x6ef, V9_0), (0x6fa, 0x6fc, V9_0),
+    (0x6ff, 0x6ff, V9_0), (0x710, 0x710, V9_0), (0x712, 0x72f, V9_0), (0x74d, 0x7a5, V9_0),
+    (0x7b1, 0x7b1, V9_0), (0x7ca, 0x7ea, V9_0), (0x7f4, 0x7f5, V9_0), (0x7fa, 0x7fa, V9_0),
+    (0x800, 0x815, V9_0), (0x81a, 0x81a, V9_0), (0x824, 0x824, V9_0), (0x828, 0x828, V9_0),
+    (0x840, 0x858, V9_0), (0x860, 0x86a, V10_0), (0x870, 0x887, V14_0), (0x889, 0x88e, V14_0),
+    (0x8a0, 0x8b4, V9_0), (0x8b5, 0x8b5, V14_0), (0x8b6, 0x8bd, V9_0), (0x8be, 0x8c7, V13_0),
+    (0x8c8, 0x8c9, V14_0), (0x904, 0x939, V9_0), (0x93d, 0x93d, V9_0), (0x950, 0x950, V9_0),
+    (0x958, 0x961, V9_0), (0x971, 0x980, V9_0), (0x985, 0x98c, V9_0), (0x98f, 0x990, V9_0),
+    (0x993, 0x9a8, V9_0), (0x9aa, 0x9b0, V9_0), (0x9b2, 0x9b2, V9_0), (0x9b6, 0x9b9, V9_0),
+    (0x9bd, 0x9bd, V9_0), (0x9ce, 0x9ce, V9_0), (0x9dc, 0x9dd, V9_0), (0x9df, 0x9e1, V9_0),
+    (0x9f0, 0x9f1, V9_0), (0x9fc, 0x9fc, V10_0), (0xa05, 0xa0a, V9_0), (0xa0f, 0xa10, V9_0),
+    (0xa13, 0xa28, V9_0), (0xa2a, 0xa30, V9_0), (0xa32, 0xa33, V9_0), (0xa35, 0xa36, V9_0),
+    (0xa38, 0xa39, V9_0), (0xa59, 0xa5c, V9_0), (0xa5e, 0xa5e, V9_0), (0xa72, 0xa74, V9_0),
+    (0xa85, 0xa8d, V9_0), (0xa8f, 0xa91, V9_0), (0xa93, 0xaa8, V9_0), (0xaaa, 0xab0, V9_0),
+    (0xab2, 0xab3, V9_0), (0xab5, 0xab9, V9_0), (0xabd, 0xabd, V9_0), (0xad0, 0xad0, V9_0),
+    (0xae0, 0xae1, V9_0), (0xaf9, 0xaf9, V9_0), (0xb05, 0xb0c, V9_0), (0xb0f, 0xb10, V9_0),
+    (0xb13, 0xb28, V9_0), (0xb2a, 0xb30, V9_0), (0xb32, 0xb33, V9_0), (0xb35, 0xb39, V9_0),
+    (0xb3d, 0xb3d, V9_0), (0xb5c, 0xb5d, V9_0), (0xb5f, 0xb61, V9_0), (0xb71, 0xb71, V9_0),
+    (0xb83, 0xb83, V9_0), (0xb85, 0xb8a, V9_0), (0xb8e, 0xb90, V9_0), (0xb92, 0xb95, V9_0),
+    (0xb99, 0xb9a, V9_0), (0xb9c, 0xb9c, V9_0), (0xb9e, 0xb9f, V9_0), (0xba3, 0xba4, V9_0),
+    (0xba8, 0xbaa, V9_0), (0xbae, 0xbb9, V9_0), (0xbd0, 0xbd0, V9_0), (0xc05, 0xc0c, V9_0),
+    (0xc0e, 0xc10, V9_0), (0xc12, 0xc28, V9_0), (0xc2a, 0xc39, V9_0), (0xc3d, 0xc3d, V9_0),
+    (0xc58, 0xc5a, V9_0), (0xc5d, 0xc5d, V14_0), (0xc60, 0xc61, V9_0), (0xc80, 0xc80, V9_0),
+    (0xc85, 0xc8c, V9_0), (0xc8e, 0xc90, V9_0), (0xc92, 0xca8, V9_0), (0xcaa, 0xcb3, V9_0),
+    (0xcb5, 0xcb9, V9_0), (0xcbd, 0xcbd, V9_0), (0xcdd, 0xcdd, V14_0), (0xcde, 0xcde, V9_0),
+    (0xce0, 0xce1, V9_0), (0xcf1, 0xcf2, V9_0), (0xd04, 0xd04, V13_0), (0xd05, 0xd0c, V9_0),
+    (0xd0e, 0xd10, V9_0), (0xd12, 0xd3a, V9_0), (0xd3d, 0xd3d, V9_0), (0xd4e, 0xd4e, V9_0),
+    (0xd54, 0xd56, V9_0), (0xd5f, 0xd61, V9_0), (0xd7a, 0xd7f, V9_0), (0xd85, 0xd96, V9_0),
+    (0xd9a, 0xdb1, V9_0), (0xdb3, 0xdbb, V9_0), (0xdbd, 0xdbd, V9_0), (0xdc0, 0xdc6, V9_0),
+    (0xe01, 0xe30, V9_0), (0xe32, 0xe33, V9_0), (0xe40, 0xe46, V9_0), (0xe81, 0xe82, V9_0),
+    (0xe84, 0xe84, V9_0), (0xe86, 0xe86, V12_0), (0xe87, 0xe88, V9_0), (0xe89, 0xe89, V12_0),
+    (0xe8a, 0xe8a, V9_0), (0xe8c, 0xe8c, V12_0), (0xe8d, 0xe8d, V9_0), (0xe8e, 0xe93, V12_0),
+    (0xe94, 0xe97, V9_0), (0xe98, 0xe98, V12_0), (0xe99, 0xe9f, V9_0), (0xea0, 0xea0, V12_0),
+    (0xea1, 0xea3, V9_0), (0xea5, 0xea5, V9_0), (0xea7, 0xea7, V9_0), (0xea8, 0xea9, V12_0),
+    (0xeaa, 0xeab, V9_0), (0xeac, 0xeac, V12_0), (0xead, 0xeb0, V9_0), (0xeb2, 0xeb3, V9_0),
+    (0xebd, 0xebd, V9_0), (0xec0, 0xec4, V9_0), (0xec6, 0xec6, V9_0), (0xedc, 0xedf, V9_0),
+    (0xf00, 0xf00, V9_0), (0xf40, 0xf47, V9_0), (0xf49, 0xf6c, V9_0), (0xf88, 0xf8c, V9_0),
+    (0x1000, 0x102a, V9_0), (0x103f, 0x103f, V9_0), (0x1050, 0x1055, V9_0), (0x105a, 0x105d, V9_0),
+    (0x1061, 0x1061, V9_0), (0x1065, 0x1066, V9_0), (0x106e, 0x1070, V9_0), (0x1075, 0x1081, V9_0),
+    (0x108e, 0x108e, V9_0), (0x10a0, 0x10c5, V9_0), (0x10c7, 0x10c7, V9_0), (0x10cd, 0x10cd, V9_0),
+    (0x10d0, 0x10fa, V9_0), (0x10fc, 0x1248, V9_0), (0x124a, 0x124d, V9_0), (0x1250, 0x1256, V9_0),
+    (0x1258, 0x1258, V9_0), (0x125a, 0x125d, V9_0), (0x1260, 0x1288, V9_0), (0x128a, 0x128d, V9_0),
+    (0x1290, 0x12b0, V9_0), (0x12b2, 0x12b5, V9_0), (0x12b8, 0x12be, V9_0), (0x12c0, 0x12c0, V9_0),
+    (0x12c2, 0x12c5, V9_0), (0x12c8, 0x12d6, V9_0), (0x12d8, 0x1310, V9_0), (0x1312, 0x1315, V9_0),
+    (0x1318, 0x135a, V9_0), (0x1380, 0x138f, V9_0), (0x13a0, 0x13f5, V9_0), (0x13f8, 0x13fd, V9_0),
+    (0x1401, 0x166c, V9_0), (0x166f, 0x167f, V9_0), (0x1681, 0x169a, V9_0), (0x16a0, 0x16ea, V9_0),
+    (0x16ee, 0x16f8, V9_0), (0x1700, 0x170c, V9_0), (0x170d, 0x170d, V14_0), (0x170e, 0x1711, V9_0),
+    (0x171f, 0x171f, V14_0), (0x1720, 0x1731, V9_0), (0x1740, 0x1751, V9_0), (0x1760, 0x176c, V9_0),
+    (0x176e, 0x1770, V9_0), (0x1780, 0x17b3, V9_0), (0x17d7, 0x17d7, V9_0), (0x17dc, 0x17dc, V9_0),
+    (0x1820, 0x1877, V9_0), (0x1878, 0x1878, V11_0), (0x1880, 0x18a8, V9_0), (0x18aa, 0x18aa, V9_0),
+    (0x18b0, 0x18f5, V9_0), (0x1900, 0x191e, V9_0), (0x1950, 0x196d, V9_0), (0x1970, 0x1974, V9_0),
+    (0x1980, 0x19ab, V9_0), (0x19b0, 0x19c9, V9_0), (0x1a00, 0x1a16, V9_0), (0x1a20, 0x1a54, V9_0),
+    (0x1aa7, 0x1aa7, V9_0), (0x1b05, 0x1b33, V9_0), (0x1b45, 0x1b4b, V9_0), (0x1b4c, 0x1b4c, V14_0),
+    (0x1b83, 0x1ba0, V9_0), (0x1bae, 0x1baf, V9_0), (0x1bba, 0x1be5, V9_0), (0x1c00, 0x1c23, V9_0),
+    (0x1c4d, 0x1c4f, V9_0), (0x1c5a, 0x1c7d, V9_0), (0x1c80, 0x1c88, V9_0), (0x1c89, 0x1c8a, V16_0),
+    (0x1c90, 0x1cba, V11_0), (0x1cbd, 0x1cbf, V11_0), (0x1ce9, 0x1cec, V9_0),
+    (0x1cee, 0x1cf1, V9_0), (0x1cf2, 0x1cf3, V12_0), (0x1cf5, 0x1cf6, V9_0),
+    (0x1cfa, 0x1cfa, V12_0), (0x1d00, 0x1dbf, V9_0), (0x1e00, 0x1f15, V9_0), (0x1f18, 0x1f1d, V9_0),
+    (0x1f20, 0x1f45, V9_0), (0x1f48, 0x1f4d, V9_0), (0x1f50, 0x1f57, V9_0), (0x1f59, 0x1f59, V9_0),
+    (0x1f5b, 0x1f5b, V9_0), (0x1f5d, 0x1f5d, V9_0), (0x1f5f, 0x1f7d, V9_0), (0x1f80, 0x1fb4, V9_0),
+    (0x1fb6, 0x1fbc, V9_0), (0x1fbe, 0x1fbe, V9_0), (0x1fc2, 0x1fc4, V9_0), (0x1fc6, 0x1fcc, V9_0),
+    (0x1fd0, 0x1fd3, V9_0), (0x1fd6, 0x1fdb, V9_0), (0x1fe0, 0x1fec, V9_0), (0x1ff2, 0x1ff4, V9_0),
+    (0x1ff6, 0x1ffc, V9_0), (0x2071, 0x2071, V9_0), (0x207f, 0x207f, V9_0), (0x2090, 0x209c, V9_0),
+    (0x2102, 0x2102, V9_0), (0x2107, 0x2107, V9_0), (0x210a, 0x2113, V9_0), (0x2115, 0x2115, V9_0),
+    (0x2118, 0x211d, V9_0), (0x2124, 0x2124, V9_0), (0x2126, 0x2126, V9_0), (0x2128, 0x2128, V9_0),
+    (0x212a, 0x2139, V9_0), (0x213c, 0x213f, V9_0), (0x2145, 0x2149, V9_0), (0x214e, 0x214e, V9_0),
+    (0x2160, 0x2188, V9_0), (0x2c00, 0x2c2e, V9_0), (0x2c2f, 0x2c2f, V14_0), (0x2c30, 0x2c5e, V9_0),
+    (0x2c5f, 0x2c5f, V14_0), (0x2c60, 0x2ce4, V9_0), (0x2ceb, 0x2cee, V9_0), (0x2cf2, 0x2cf3, V9_0),
+    (0x2d00, 0x2d25, V9_0), (0x2d27, 0x2d27, V9_0), (0x2d2d, 0x2d2d, V9_0), (0x2d30, 0x2d67, V9_0),
+    (0x2d6f, 0x2d6f, V9_0), (0x2d80, 0x2d96, V9_0), (0x2da0, 0x2da6, V9_0), (0x2da8, 0x2dae, V9_0),
+    (0x2db0, 0x2db6, V9_0), (0x2db8, 0x2dbe, V9_0), (0x2dc0, 0x2dc6, V9_0), (0x2dc8, 0x2dce, V9_0),
+    (0x2dd0, 0x2dd6, V9_0), (0x2dd8, 0x2dde, V9_0), (0x3005, 0x3007, V9_0), (0x3021, 0x3029, V9_0),
+    (0x3031, 0x3035, V9_0), (0x3038, 0x303c, V9_0), (0x3041, 0x3096, V9_0), (0x309b, 0x309f, V9_0),
+    (0x30a1, 0x30fa, V9_0), (0x30fc, 0x30ff, V9_0), (0x3105, 0x312d, V9_0), (0x312e, 0x312e, V10_0),
+    (0x312f, 0x312f, V11_0), (0x3131, 0x318e, V9_0), (0x31a0, 0x31ba, V9_0),
+    (0x31bb, 0x31bf, V13_0), (0x31f0, 0x31ff, V9_0), (0x3400, 0x4db5, V9_0),
+    (0x4db6, 0x4dbf, V13_0), (0x4e00, 0x9fd5, V9_0), (0x9fd6, 0x9fea, V10_0),
+    (0x9feb, 0x9fef, V11_0), (0x9ff0, 0x9ffc, V13_0), (0x9ffd, 0x9fff, V14_0),
+    (0xa000, 0xa48c, V9_0), (0xa4d0, 0xa4fd, V9_0), (0xa500, 0xa60c, V9_0), (0xa610, 0xa61f, V9_0),
+    (0xa62a, 0xa62b, V9_0), (0xa640, 0xa66e, V9_0), (0xa67f, 0xa69d, V9_0), (0xa6a0, 0xa6ef, V9_0),
+    (0xa717, 0xa71f, V9_0), (0xa722, 0xa788, V9_0), (0xa78b, 0xa7ae, V9_0), (0xa7af, 0xa7af, V11_0),
+    (0xa7b0, 0xa7b7, V9_0), (0xa7b8, 0xa7b9, V11_0), (0xa7ba, 0xa7bf, V12_0),
+    (0xa7c0, 0xa7c1, V14_0), (0xa7c2, 0xa7c6, V12_0), (0xa7c7, 0xa7ca, V13_0),
+    (0xa7cb, 0xa7cd, V16_0), (0xa7d0, 0xa7d1, V14_0), (0xa7d3, 0xa7d3, V14_0),
+    (0xa7d5, 0xa7d9, V14_0), (0xa7da, 0xa7dc, V16_0), (0xa7f2, 0xa7f4, V14_0),
+    (0xa7f5, 0xa7f6, V13_0), (0xa7f7, 0xa801, V9_0), (0xa803, 0xa805, V9_0), (0xa807, 0xa80a, V9_0),
+    (0xa80c, 0xa822, V9_0), (0xa840, 0xa873, V9_0), (0xa882, 0xa8b3, V9_0), (0xa8f2, 0xa8f7, V9_0),
+    (0xa8fb, 0xa8fb, V9_0), (0xa8fd, 0xa8fd, V9_0), (0xa8fe, 0xa8fe, V11_0), (0xa90a, 0xa925, V9_0),
+    (0xa930, 0xa946, V9_0), (0xa960, 0xa97c, V9_0), (0xa984, 0xa9b2, V9_0), (0xa9cf, 0xa9cf, V9_0),
+    (0xa9e0, 0xa9e4, V9_0), (0xa9e6, 0xa9ef, V9_0), (0xa9fa, 0xa9fe, V9_0), (0xaa00, 0xaa28, V9_0),
+    (0xaa40, 0xaa42, V9_0), (0xaa44, 0xaa4b, V9_0), (0xaa60, 0xaa76, V9_0), (0xaa7a, 0xaa7a, V9_0),
+    (0xaa7e, 0xaaaf, V9_0), (0xaab1, 0xaab1, V9_0), (0xaab5, 0xaab6, V9_0), (0xaab9, 0xaabd, V9_0),
+    (0xaac0, 0xaac0, V9_0), (0xaac2, 0xaac2, V9_0), (0xaadb, 0xaadd, V9_0), (0xaae0, 0xaaea, V9_0),
+    (0xaaf2, 0xaaf4, V9_0), (0xab01, 0xab06, V9_0), (0xab09, 0xab0e, V9_0), (0xab11, 0xab16, V9_0),
+    (0xab20, 0xab26, V9_0), (0xab28, 0xab2e, V9_0), (0xab30, 0xab5a, V9_0), (0xab5c, 0xab65, V9_0),
+    (0xab66, 0xab67, V12_0), (0xab68, 0xab69, V13_0), (0xab70, 0xabe2, V9_0),
+    (0xac00, 0xd7a3, V9_0), (0xd7b0, 0xd7c6, V9_0), (0xd7cb, 0xd7fb, V9_0), (0xf900, 0xfa6d, V9_0),
+    (0xfa70, 0xfad9, V9_0), (0xfb00, 0xfb06, V9_0), (0xfb13, 0xfb17, V9_0), (0xfb1d, 0xfb1d, V9_0),
+    (0xfb1f, 0xfb28, V9_0), (0xfb2a, 0xfb36, V9_0), (0xfb38, 0xfb3c, V9_0), (0xfb3e, 0xfb3e, V9_0),
+    (0xfb40, 0xfb41, V9_0), (0xfb43, 0xfb44, V9_0), (0xfb46, 0xfbb1, V9_0), (0xfbd3, 0xfd3d, V9_0),
+    (0xfd50, 0xfd8f, V9_0), (0xfd92, 0xfdc7, V9_0), (0xfdf0, 0xfdfb, V9_0), (0xfe70, 0xfe74, V9_0),
+    (0xfe76, 0xfefc, V9_0), (0xff21, 0xff3a, V9_0), (0xff41, 0xff5a, V9_0), (0xff66, 0xffbe, V9_0),
+    (0xffc2, 0xffc7, V9_0), (0xffca, 0xffcf, V9_0), (0xffd2, 0xffd7, V9_0), (0xffda, 0xffdc, V9_0),
+    (0x10000, 0x1000b, V9_0), (0x1000d, 0x10026, V9_0), (0x10028, 0x1003a, V9_0),
+    (0x1003c, 0x1003d, V9_0), (0x1003f, 0x1004d, V9_0), (0x10050, 0x1005d, V9_0),
+    (0x10080, 0x100fa, V9_0), (0x10140, 0x10174, V9_0), (0x10280, 0x1029c, V9_0),
+    (0x102a0, 0x102d0, V9_0), (0x10300, 0x1031f, V9_0), (0x1032d, 0x1032f, V10_0),
+    (0x10330, 0x1034a, V9_0), (0x10350, 0x10375, V9_0), (0x10380, 0x1039d, V9_0),
+    (0x103a0, 0x103c3, V9_0), (0x103c8, 0x103cf, V9_0), (0x103d1, 0x103d5, V9_0),
+    (0x10400, 0x1049d, V9_0), (0x104b0, 0x104d3, V9_0), (0x104d8, 0x104fb, V9_0),
+    (0x10500, 0x10527, V9_0), (0x10530, 0x10563, V9_0), (0x10570, 0x1057a, V14_0),
+    (0x1057c, 0x1058a, V14_0), (0x1058c, 0x10592, V14_0), (0x10594, 0x10595, V14_0),
+    (0x10597, 0x105a1, V14_0), (0x105a3, 0x105b1, V14_0), (0x105b3, 0x105b9, V14_0),
+    (0x105bb, 0x105bc, V14_0), (0x105c0, 0x105f3, V16_0), (0x10600, 0x10736, V9_0),
+    (0x10740, 0x10755, V9_0), (0x10760, 0x10767, V9_0), (0x10780, 0x10785, V14_0),
+    (0x10787, 0x107b0, V14_0), (0x107b2, 0x107ba, V14_0), (0x10800, 0x10805, V9_0),
+    (0x10808, 0x10808, V9_0), (0x1080a, 0x10835, V9_0), (0x10837, 0x10838, V9_0),
+    (0x1083c, 0x1083c, V9_0), (0x1083f, 0x10855, V9_0), (0x10860, 0x10876, V9_0),
+    (0x10880, 0x1089e, V9_0), (0x108e0, 0x108f2, V9_0), (0x108f4, 0x108f5, V9_0),
+    (0x10900, 0x10915, V9_0), (0x10920, 0x10939, V9_0), (0x10980, 0x109b7, V9_0),
+    (0x109be, 0x109bf, V9_0), (0x10a00, 0x10a00, V9_0), (0x10a10, 0x10a13, V9_0),
+    (0x10a15, 0x10a17, V9_0), (0x10a19, 0x10a33, V9_0), (0x10a34, 0x10a35, V11_0),
+    (0x10a60, 0x10a7c, V9_0), (0x10a80, 0x10a9c, V9_0), (0x10ac0, 0x10ac7, V9_0),
+    (0x10ac9, 0x10ae4, V9_0), (0x10b00, 0x10b35, V9_0), (0x10b40, 0x10b55, V9_0),
+    (0x10b60, 0x10b72, V9_0), (0x10b80, 0x10b91, V9_0), (0x10c00, 0x10c48, V9_0),
+    (0x10c80, 0x10cb2, V9_0), (0x10cc0, 0x10cf2, V9_0), (0x10d00, 0x10d23, V11_0),
+    (0x10d4a, 0x10d65, V16_0), (0x10d6f, 0x10d85, V16_0), (0x10e80, 0x10ea9, V13_0),
+    (0x10eb0, 0x10eb1, V13_0), (0x10ec2, 0x10ec4, V16_0), (0x10f00, 0x10f1c, V11_0),
+    (0x10f27, 0x10f27, V11_0), (0x10f30, 0x10f45, V11_0), (0x10f70, 0x10f81, V14_0),
+    (0x10fb0, 0x10fc4, V13_0), (0x10fe0, 0x10ff6, V12_0), (0x11003, 0x11037, V9_0),
+    (0x11071, 0x11072, V14_0), (0x11075, 0x11075, V14_0), (0x11083, 0x110af, V9_0),
+    (0x110d0, 0x110e8, V9_0), (0x11103, 0x11126, V9_0), (0x11144, 0x11144, V11_0),
+    (0x11147, 0x11147, V13_0), (0x11150, 0x11172, V9_0), (0x11176, 0x11176, V9_0),
+    (0x11183, 0x111b2, V9_0), (0x111c1, 0x111c4, V9_0), (0x111da, 0x111da, V9_0),
+    (0x111dc, 0x111dc, V9_0), (0x11200, 0x11211, V9_0), (0x11213, 0x1122b, V9_0),
+    (0x1123f, 0x11240, V15_0), (0x11280, 0x11286, V9_0), (0x11288, 0x11288, V9_0),
+    (0x1128a, 0x1128d, V9_0), (0x1128f, 0x1129d, V9_0), (0x1129f, 0x112a8, V9_0),
+    (0x112b0, 0x112de, V9_0), (0x11305, 0x1130c, V9_0), (0x1130f, 0x11310, V9_0),
+    (0x11313, 0x11328, V9_0), (0x1132a, 0x11330, V9_0), (0x11332, 0x11333, V9_0),
+    (0x11335, 0x11339, V9_0), (0x1133d, 0x1133d, V9_0), (0x11350, 0x11350, V9_0),
+    (0x1135d, 0x11361, V9_0), (0x11380, 0x11389, V16_0), (0x1138b, 0x1138b, V16_0),
+    (0x1138e, 0x1138e, V16_0), (0x11390, 0x113b5, V16_0), (0x113b7, 0x113b7, V16_0),
+    (0x113d1, 0x113d1, V16_0), (0x113d3, 0x113d3, V16_0), (0x11400, 0x11434, V9_0),
+    (0x11447, 0x1144a, V9_0), (0x1145f, 0x1145f, V12_0), (0x11460, 0x11461, V13_0),
+    (0x11480, 0x114af, V9_0), (0x114c4, 0x114c5, V9_0), (0x114c7, 0x114c7, V9_0),
+    (0x11580, 0x115ae, V9_0), (0x115d8, 0x115db, V9_0), (0x11600, 0x1162f, V9_0),
+    (0x11644, 0x11644, V9_0), (0x11680, 0x116aa, V9_0), (0x116b8, 0x116b8, V12_0),
+    (0x11700, 0x11719, V9_0), (0x1171a, 0x1171a, V11_0), (0x11740, 0x11746, V14_0),
+    (0x11800, 0x1182b, V11_0), (0x118a0, 0x118df, V9_0), (0x118ff, 0x118ff, V9_0),
+    (0x11900, 0x11906, V13_0), (0x11909, 0x11909, V13_0), (0x1190c, 0x11913, V13_0),
+    (0x11915, 0x11916, V13_0), (0x11918, 0x1192f, V13_0), (0x1193f, 0x1193f, V13_0),
+    (0x11941, 0x11941, V13_0), (0x119a0, 0x119a7, V12_0), (0x119aa, 0x119d0, V12_0),
+    (0x119e1, 0x119e1, V12_0), (0x119e3, 0x119e3, V12_0), (0x11a00, 0x11a00, V10_0),
+    (0x11a0b, 0x11a32, V10_0), (0x11a3a, 0x11a3a, V10_0), (0x11a50, 0x11a50, V10_0),
+    (0x11a5c, 0x11a83, V10_0), (0x11a84, 0x11a85, V12_0), (0x11a86, 0x11a89, V10_0),
+    (0x11a9d, 0x11a9d, V11_0), (0x11ab0, 0x11abf, V14_0), (0x11ac0, 0x11af8, V9_0),
+    (0x11bc0, 0x11be0, V16_0), (0x11c00, 0x11c08, V9_0), (0x11c0a, 0x11c2e, V9_0),
+    (0x11c40, 0x11c40, V9_0), (0x11c72, 0x11c8f, V9_0), (0x11d00, 0x11d06, V10_0),
+    (0x11d08, 0x11d09, V10_0), (0x11d0b, 0x11d30, V10_0), (0x11d46, 0x11d46, V10_0),
+    (0x11d60, 0x11d65, V11_0), (0x11d67, 0x11d68, V11_0), (0x11d6a, 0x11d89, V11_0),
+    (0x11d98, 0x11d98, V11_0), (0x11ee0, 0x11ef2, V11_0), (0x11f02, 0x11f02, V15_0),
+    (0x11f04, 0x11f10, V15_0), (0x11f12, 0x11f33, V15_0), (0x11fb0, 0x11fb0, V13_0),
+    (0x12000, 0x12399, V9_0), (0x12400, 0x1246e, V9_0), (0x12480, 0x12543, V9_0),
+    (0x12f90, 0x12ff0, V14_0), (0x13000, 0x1342e, V9_0), (0x1342f, 0x1342f, V15_0),
+    (0x13441, 0x13446, V15_0), (0x13460, 0x143fa, V16_0), (0x14400, 0x14646, V9_0),
+    (0x16100, 0x1611d, V16_0), (0x16800, 0x16a38, V9_0), (0x16a40, 0x16a5e, V9_0),
+    (0x16a70, 0x16abe, V14_0), (0x16ad0, 0x16aed, V9_0), (0x16b00, 0x16b2f, V9_0),
+    (0x16b40, 0x16b43, V9_0), (0x16b63, 0x16b77, V9_0), (0x16b7d, 0x16b8f, V9_0),
+    (0x16d40, 0x16d6c, V16_0), (0x16e40, 0x16e7f, V11_0), (0x16f00, 0x16f44, V9_0),
+    (0x16f45, 0x16f4a, V12_0), (0x16f50, 0x16f50, V9_0), (0x16f93, 0x16f9f, V9_0),
+    (0x16fe0, 0x16fe0, V9_0), (0x16fe1, 0x16fe1, V10_0), (0x16fe3, 0x16fe3, V12_0),
+    (0x17000, 0x187ec, V9_0), (0x187ed, 0x187f1, V11_0), (0x187f2, 0x187f7, V12_0),
+    (0x18800, 0x18af2, V9_0), (0x18af3, 0x18cd5, V13_0), (0x18cff, 0x18cff, V16_0),
+    (0x18d00, 0x18d08, V13_0), (0x1aff0, 0x1aff3, V14_0), (0x1aff5, 0x1affb, V14_0),
+    (0x1affd, 0x1affe, V14_0), (0x1b000, 0x1b001, V9_0), (0x1b002, 0x1b11e, V10_0),
+    (0x1b11f, 0x1b122, V14_0), (0x1b132, 0x1b132, V15_0), (0x1b150, 0x1b152, V12_0),
+    (0x1b155, 0x1b155, V15_0), (0x1b164, 0x1b167, V12_0), (0x1b170, 0x1b2fb, V10_0),
+    (0x1bc00, 0x1bc6a, V9_0), (0x1bc70, 0x1bc7c, V9_0), (0x1bc80, 0x1bc88, V9_0),
+    (0x1bc90, 0x1bc99, V9_0), (0x1d400, 0x1d454, V9_0), (0x1d456, 0x1d49c, V9_0),
+    (0x1d49e, 0x1d49f, V9_0), (0x1d4a2, 0x1d4a2, V9_0), (0x1d4a5, 0x1d4a6, V9_0),
+    (0x1d4a9, 0x1d4ac, V9_0), (0x1d4ae, 0x1d4b9, V9_0), (0x1d4bb, 0x1d4bb, V9_0),
+    (0x1d4bd, 0x1d4c3, V9_0), (0x1d4c5, 0x1d505, V9_0), (0x1d507, 0x1d50a, V9_0),
+    (0x1d50d, 0x1d514, V9_0), (0x1d516, 0x1d51c, V9_0), (0x1d51e, 0x1d539, V9_0),
+    (0x1d53b, 0x1d53e, V9_0), (0x1d540, 0x1d544, V9_0), (0x1d546, 0x1d546, V9_0),
+    (0x1d54a, 0x1d550, V9_0), (0x1d552, 0x1d6a5, V9_0), (0x1d6a8, 0x1d6c0, V9_0),
+    (0x1d6c2, 0x1d6da, V9_0), (0x1d6dc, 0x1d6fa, V9_0), (0x1d6fc, 0x1d714, V9_0),
+    (0x1d716, 0x1d734, V9_0), (0x1d736, 0x1d74e, V9_0), (0x1d750, 0x1d76e, V9_0),
+    (0x1d770, 0x1d788, V9_0), (0x1d78a, 0x1d7a8, V9_0), (0x1d7aa, 0x1d7c2, V9_0),
+    (0x1d7c4, 0x1d7cb, V9_0), (0x1df00, 0x1df1e, V14_0), (0x1df25, 0x1df2a, V15_0),
+    (0x1e030, 0x1e06d, V15_0), (0x1e100, 0x1e12c, V12_0), (0x1e137, 0x1e13d, V12_0),
+    (0x1e14e, 0x1e14e, V12_0), (0x1e290, 0x1e2ad, V14_0), (0x1e2c0, 0x1e2eb, V12_0),
+    (0x1e4d0, 0x1e4eb, V15_0), (0x1e5d0, 0x1e5ed, V16_0), (0x1e5f0, 0x1e5f0, V16_0),
+    (0x1e7e0, 0x1e7e6, V14_0), (0x1e7e8, 0x1e7eb, V14_0), (0x1e7ed, 0x1e7ee, V14_0),
+    (0x1e7f0, 0x1e7fe, V14_0), (0x1e800, 0x1e8c4, V9_0), (0x1e900, 0x1e943, V9_0),
+    (0x1e94b, 0x1e94b, V12_0), (0x1ee00, 0x1ee03, V9_0), (0x1ee05, 0x1ee1f, V9_0),
+    (0x1ee21, 0x1ee22, V9_0), (0x1ee24, 0x1ee24, V9_0), (0x1ee27, 0x1ee27, V9_0),
+    (0x1ee29, 0x1ee32, V9_0), (0x1ee34, 0x1ee37, V9_0), (0x1ee39, 0x1ee39, V9_0),
+    (0x1ee3b, 0x1ee3b, V9_0), (0x1ee42, 0x1ee42, V9_0), (0x1ee47, 0x1ee47, V9_0),
+    (0x1ee49, 0x1ee49, V9_0), (0x1ee4b, 0x1ee4b, V9_0), (0x1ee4d, 0x1ee4f, V9_0),
+    (0x1ee51, 0x1ee52, V9_0), (0x1ee54, 0x1ee54, V9_0), (0x1ee57, 0x1ee57, V9_0),
+    (0x1ee59, 0x1ee59, V9_0), (0x1ee5b, 0x1ee5b, V9_0), (0x1ee5d, 0x1ee5d, V9_0),
+    (0x1ee5f, 0x1ee5f, V9_0), (0x1ee61, 0x1ee62, V9_0), (0x1ee64, 0x1ee64, V9_0),
+    (0x1ee67, 0x1ee6a, V9_0), (0x1ee6c, 0x1ee72, V9_0), (0x1ee74, 0x1ee77, V9_0),
+    (0x1ee79, 0x1ee7c, V9_0), (0x1ee7e, 0x1ee7e, V9_0), (0x1ee80, 0x1ee89, V9_0),
+    (0x1ee8b, 0x1ee9b, V9_0), (0x1eea1, 0x1eea3, V9_0), (0x1eea5, 0x1eea9, V9_0),
+    (0x1eeab, 0x1eebb, V9_0), (0x20000, 0x2a6d6, V9_0), (0x2a6d7, 0x2a6dd, V13_0),
+    (0x2a6de, 0x2a6df, V14_0), (0x2a700, 0x2b734, V9_0), (0x2b735, 0x2b738, V14_0),
+    (0x2b739, 0x2b739, V15_0), (0x2b740, 0x2b81d, V9_0), (0x2b820, 0x2cea1, V9_0),
+    (0x2ceb0, 0x2ebe0, V10_0), (0x2ebf0, 0x2ee5d, V15_1), (0x2f800, 0x2fa1d, V9_0),
+    (0x30000, 0x3134a, V13_0), (0x31350, 0x323af, V15_0),
+];
+
+/// Non-ASCII code points that were in `ID_Continue` but not in `ID_Start` when they joined
+/// `ID_Continue`, tagged like `LARGE_ID_START_RANGES`.
+pub static LARGE_ID_CONTINUE_RANGES: &[(u32, u32, UnicodeVersion)] = &[
+    (0xb7, 0xb7, V9_0), (0x300, 0x36f, V9_0), (0x387, 0x387, V9_0), (0x483, 0x487, V9_0),
+    (0x591, 0x5bd, V9_0), (0x5bf, 0x5bf, V9_0), (0x5c1, 0x5c2, V9_0), (0x5c4, 0x5c5, V9_0),
+    (0x5c7, 0x5c7, V9_0), (0x610, 0x61a, V9_0), (0x64b, 0x669, V9_0), (0x670, 0x670, V9_0),
+    (0x6d6, 0x6dc, V9_0), (0x6df, 0x6e4, V9_0), (0x6e7, 0x6e8, V9_0), (0x6ea, 0x6ed, V9_0),
+    (0x6f0, 0x6f9, V9_0), (0x711, 0x711, V9_0), (0x730, 0x74a, V9_0), (0x7a6, 0x7b0, V9_0),
+    (0x7c0, 0x7c9, V9_0), (0x7eb, 0x7f3, V9_0), (0x7fd, 0x7fd, V11_0), (0x816, 0x819, V9_0),
+    (0x81b, 0x823, V9_0), (0x825, 0x827, V9_0), (0x829, 0x82d, V9_0), (0x859, 0x85b, V9_0),
+    (0x897, 0x897, V16_0), (0x898, 0x89f, V14_0), (0x8ca, 0x8d2, V14_0), (0x8d3, 0x8d3, V11_0),
+    (0x8d4, 0x8e1, V9_0), (0x8e3, 0x903, V9_0), (0x93a, 0x93c, V9_0), (0x93e, 0x94f, V9_0),
+    (0x951, 0x957, V9_0), (0x962, 0x963, V9_0), (0x966, 0x96f, V9_0), (0x981, 0x983, V9_0),
+    (0x9bc, 0x9bc, V9_0), (0x9be, 0x9c4, V9_0), (0x9c7, 0x9c8, V9_0), (0x9cb, 0x9cd, V9_0),
+    (0x9d7, 0x9d7, V9_0), (0x9e2, 0x9e3, V9_0), (0x9e6, 0x9ef, V9_0), (0x9fe, 0x9fe, V11_0),
+    (0xa01, 0xa03, V9_0), (0xa3c, 0xa3c, V9_0), (0xa3e, 0xa42, V9_0), (0xa47, 0xa48, V9_0),
+    (0xa4b, 0xa4d, V9_0), (0xa51, 0xa51, V9_0), (0xa66, 0xa71, V9_0), (0xa75, 0xa75, V9_0),
+    (0xa81, 0xa83, V9_0), (0xabc, 0xabc, V9_0), (0xabe, 0xac5, V9_0), (0xac7, 0xac9, V9_0),
+    (0xacb, 0xacd, V9_0), (0xae2, 0xae3, V9_0), (0xae6, 0xaef, V9_0), (0xafa, 0xaff, V10_0),
+    (0xb01, 0xb03, V9_0), (0xb3c, 0xb3c, V9_0), (0xb3e, 0xb44, V9_0), (0xb47, 0xb48, V9_0),
+    (0xb4b, 0xb4d, V9_0), (0xb55, 0xb55, V13_0), (0xb56, 0xb57, V9_0), (0xb62, 0xb63, V9_0),
+    (0xb66, 0xb6f, V9_0), (0xb82, 0xb82, V9_0), (0xbbe, 0xbc2, V9_0), (0xbc6, 0xbc8, V9_0),
+    (0xbca, 0xbcd, V9_0), (0xbd7, 0xbd7, V9_0), (0xbe6, 0xbef, V9_0), (0xc00, 0xc03, V9_0),
+    (0xc04, 0xc04, V11_0), (0xc3c, 0xc3c, V14_0), (0xc3e, 0xc44, V9_0), (0xc46, 0xc48, V9_0),
+    (0xc4a, 0xc4d, V9_0), (0xc55, 0xc56, V9_0), (0xc62, 0xc63, V9_0), (0xc66, 0xc6f, V9_0),
+    (0xc81, 0xc83, V9_0), (0xcbc, 0xcbc, V9_0), (0xcbe, 0xcc4, V9_0), (0xcc6, 0xcc8, V9_0),
+    (0xcca, 0xccd, V9_0), (0xcd5, 0xcd6, V9_0), (0xce2, 0xce3, V9_0), (0xce6, 0xcef, V9_0),
+    (0xcf3, 0xcf3, V15_0), (0xd00, 0xd00, V10_0), (0xd01, 0xd03, V9_0), (0xd3b, 0xd3c, V10_0),
+    (0xd3e, 0xd44, V9_0), (0xd46, 0xd48, V9_0), (0xd4a, 0xd4d, V9_0), (0xd57, 0xd57, V9_0),
+    (0xd62, 0xd63, V9_0), (0xd66, 0xd6f, V9_0), (0xd81, 0xd81, V13_0), (0xd82, 0xd83, V9_0),
+    (0xdca, 0xdca, V9_0), (0xdcf, 0xdd4, V9_0), (0xdd6, 0xdd6, V9_0), (0xdd8, 0xddf, V9_0),
+    (0xde6, 0xdef, V9_0), (0xdf2, 0xdf3, V9_0), (0xe31, 0xe31, V9_0), (0xe34, 0xe3a, V9_0),
+    (0xe47, 0xe4e, V9_0), (0xe50, 0xe59, V9_0), (0xeb1, 0xeb1, V9_0), (0xeb4, 0xeb9, V9_0),
+    (0xeba, 0xeba, V12_0), (0xebb, 0xebc, V9_0), (0xec8, 0xecd, V9_0), (0xece, 0xece, V15_0),
+    (0xed0, 0xed9, V9_0), (0xf18, 0xf19, V9_0), (0xf20, 0xf29, V9_0), (0xf35, 0xf35, V9_0),
+    (0xf37, 0xf37, V9_0), (0xf39, 0xf39, V9_0), (0xf3e, 0xf3f, V9_0), (0xf71, 0xf84, V9_0),
+    (0xf86, 0xf87, V9_0), (0xf8d, 0xf97, V9_0), (0xf99, 0xfbc, V9_0), (0xfc6, 0xfc6, V9_0),
+    (0x102b, 0x103e, V9_0), (0x1040, 0x1049, V9_0), (0x1056, 0x1059, V9_0), (0x105e, 0x1060, V9_0),
+    (0x1062, 0x1064, V9_0), (0x1067, 0x106d, V9_0), (0x1071, 0x1074, V9_0), (0x1082, 0x108d, V9_0),
+    (0x108f, 0x109d, V9_0), (0x135d, 0x135f, V9_0), (0x1369, 0x1371, V9_0), (0x1712, 0x1714, V9_0),
+    (0x1715, 0x1715, V14_0), (0x1732, 0x1734, V9_0), (0x1752, 0x1753, V9_0), (0x1772, 0x1773, V9_0),
+    (0x17b4, 0x17d3, V9_0), (0x17dd, 0x17dd, V9_0), (0x17e0, 0x17e9, V9_0), (0x180b, 0x180d, V9_0),
+    (0x180f, 0x180f, V14_0), (0x1810, 0x1819, V9_0), (0x18a9, 0x18a9, V9_0), (0x1920, 0x192b, V9_0),
+    (0x1930, 0x193b, V9_0), (0x1946, 0x194f, V9_0), (0x19d0, 0x19da, V9_0), (0x1a17, 0x1a1b, V9_0),
+    (0x1a55, 0x1a5e, V9_0), (0x1a60, 0x1a7c, V9_0), (0x1a7f, 0x1a89, V9_0), (0x1a90, 0x1a99, V9_0),
+    (0x1ab0, 0x1abd, V9_0), (0x1abf, 0x1ac0, V13_0), (0x1ac1, 0x1ace, V14_0),
+    (0x1b00, 0x1b04, V9_0), (0x1b34, 0x1b44, V9_0), (0x1b50, 0x1b59, V9_0), (0x1b6b, 0x1b73, V9_0),
+    (0x1b80, 0x1b82, V9_0), (0x1ba1, 0x1bad, V9_0), (0x1bb0, 0x1bb9, V9_0), (0x1be6, 0x1bf3, V9_0),
+    (0x1c24, 0x1c37, V9_0), (0x1c40, 0x1c49, V9_0), (0x1c50, 0x1c59, V9_0), (0x1cd0, 0x1cd2, V9_0),
+    (0x1cd4, 0x1ce8, V9_0), (0x1ced, 0x1ced, V9_0), (0x1cf2, 0x1cf4, V9_0), (0x1cf7, 0x1cf7, V10_0),
+    (0x1cf8, 0x1cf9, V9_0), (0x1dc0, 0x1df5, V9_0), (0x1df6, 0x1df9, V10_0),
+    (0x1dfa, 0x1dfa, V14_0), (0x1dfb, 0x1dff, V9_0), (0x200c, 0x200d, V15_1),
+    (0x203f, 0x2040, V9_0), (0x2054, 0x2054, V9_0), (0x20d0, 0x20dc, V9_0), (0x20e1, 0x20e1, V9_0),
+    (0x20e5, 0x20f0, V9_0), (0x2cef, 0x2cf1, V9_0), (0x2d7f, 0x2d7f, V9_0), (0x2de0, 0x2dff, V9_0),
+    (0x302a, 0x302f, V9_0), (0x3099, 0x309a, V9_0), (0x30fb, 0x30fb, V15_1), (0xa620, 0xa629, V9_0),
+    (0xa66f, 0xa66f, V9_0), (0xa674, 0xa67d, V9_0), (0xa69e, 0xa69f, V9_0), (0xa6f0, 0xa6f1, V9_0),
+    (0xa802, 0xa802, V9_0), (0xa806, 0xa806, V9_0), (0xa80b, 0xa80b, V9_0), (0xa823, 0xa827, V9_0),
+    (0xa82c, 0xa82c, V13_0), (0xa880, 0xa881, V9_0), (0xa8b4, 0xa8c5, V9_0), (0xa8d0, 0xa8d9, V9_0),
+    (0xa8e0, 0xa8f1, V9_0), (0xa8ff, 0xa8ff, V11_0), (0xa900, 0xa909, V9_0), (0xa926, 0xa92d, V9_0),
+    (0xa947, 0xa953, V9_0), (0xa980, 0xa983, V9_0), (0xa9b3, 0xa9c0, V9_0), (0xa9d0, 0xa9d9, V9_0),
+    (0xa9e5, 0xa9e5, V9_0), (0xa9f0, 0xa9f9, V9_0), (0xaa29, 0xaa36, V9_0), (0xaa43, 0xaa43, V9_0),
+    (0xaa4c, 0xaa4d, V9_0), (0xaa50, 0xaa59, V9_0), (0xaa7b, 0xaa7d, V9_0), (0xaab0, 0xaab0, V9_0),
+    (0xaab2, 0xaab4, V9_0), (0xaab7, 0xaab8, V9_0), (0xaabe, 0xaabf, V9_0), (0xaac1, 0xaac1, V9_0),
+    (0xaaeb, 0xaaef, V9_0), (0xaaf5, 0xaaf6, V9_0), (0xabe3, 0xabea, V9_0), (0xabec, 0xabed, V9_0),
+    (0xabf0, 0xabf9, V9_0), (0xfb1e, 0xfb1e, V9_0), (0xfe00, 0xfe0f, V9_0), (0xfe20, 0xfe2f, V9_0),
+    (0xfe33, 0xfe34, V9_0), (0xfe4d, 0xfe4f, V9_0), (0xff10, 0xff19, V9_0), (0xff3f, 0xff3f, V9_0),
+    (0xff65, 0xff65, V15_1), (0x101fd, 0x101fd, V9_0), (0x102e0, 0x102e0, V9_0),
+    (0x10376, 0x1037a, V9_0), (0x104a0, 0x104a9, V9_0), (0x10a01, 0x10a03, V9_0),
+    (0x10a05, 0x10a06, V9_0), (0x10a0c, 0x10a0f, V9_0), (0x10a38, 0x10a3a, V9_0),
+    (0x10a3f, 0x10a3f, V9_0), (0x10ae5, 0x10ae6, V9_0), (0x10d24, 0x10d27, V11_0),
+    (0x10d30, 0x10d39, V11_0), (0x10d40, 0x10d49, V16_0), (0x10d69, 0x10d6d, V16_0),
+    (0x10eab, 0x10eac, V13_0), (0x10efc, 0x10efc, V16_0), (0x10efd, 0x10eff, V15_0),
+    (0x10f46, 0x10f50, V11_0), (0x10f82, 0x10f85, V14_0), (0x11000, 0x11002, V9_0),
+    (0x11038, 0x11046, V9_0), (0x11066, 0x1106f, V9_0), (0x11070, 0x11070, V14_0),
+    (0x11073, 0x11074, V14_0), (0x1107f, 0x11082, V9_0), (0x110b0, 0x110ba, V9_0),
+    (0x110c2, 0x110c2, V14_0), (0x110f0, 0x110f9, V9_0), (0x11100, 0x11102, V9_0),
+    (0x11127, 0x11134, V9_0), (0x11136, 0x1113f, V9_0), (0x11145, 0x11146, V11_0),
+    (0x11173, 0x11173, V9_0), (0x11180, 0x11182, V9_0), (0x111b3, 0x111c0, V9_0),
+    (0x111c9, 0x111c9, V10_0), (0x111ca, 0x111cc, V9_0), (0x111ce, 0x111cf, V13_0),
+    (0x111d0, 0x111d9, V9_0), (0x1122c, 0x11237, V9_0), (0x1123e, 0x1123e, V9_0),
+    (0x11241, 0x11241, V15_0), (0x112df, 0x112ea, V9_0), (0x112f0, 0x112f9, V9_0),
+    (0x11300, 0x11303, V9_0), (0x1133b, 0x1133b, V11_0), (0x1133c, 0x1133c, V9_0),
+    (0x1133e, 0x11344, V9_0), (0x11347, 0x11348, V9_0), (0x1134b, 0x1134d, V9_0),
+    (0x11357, 0x11357, V9_0), (0x11362, 0x11363, V9_0), (0x11366, 0x1136c, V9_0),
+    (0x11370, 0x11374, V9_0), (0x113b8, 0x113c0, V16_0), (0x113c2, 0x113c2, V16_0),
+    (0x113c5, 0x113c5, V16_0), (0x113c7, 0x113ca, V16_0), (0x113cc, 0x113d0, V16_0),
+    (0x113d2, 0x113d2, V16_0), (0x113e1, 0x113e2, V16_0), (0x11435, 0x11446, V9_0),
+    (0x11450, 0x11459, V9_0), (0x1145e, 0x1145e, V11_0), (0x114b0, 0x114c3, V9_0),
+    (0x114d0, 0x114d9, V9_0), (0x115af, 0x115b5, V9_0), (0x115b8, 0x115c0, V9_0),
+    (0x115dc, 0x115dd, V9_0), (0x11630, 0x11640, V9_0), (0x11650, 0x11659, V9_0),
+    (0x116ab, 0x116b7, V9_0), (0x116c0, 0x116c9, V9_0), (0x116d0, 0x116e3, V16_0),
+    (0x1171d, 0x1172b, V9_0), (0x11730, 0x11739, V9_0), (0x1182c, 0x1183a, V11_0),
+    (0x118e0, 0x118e9, V9_0), (0x11930, 0x11935, V13_0), (0x11937, 0x11938, V13_0),
+    (0x1193b, 0x1193e, V13_0), (0x11940, 0x11940, V13_0), (0x11942, 0x11943, V13_0),
+    (0x11950, 0x11959, V13_0), (0x119d1, 0x119d7, V12_0), (0x119da, 0x119e0, V12_0),
+    (0x119e4, 0x119e4, V12_0), (0x11a01, 0x11a0a, V10_0), (0x11a33, 0x11a39, V10_0),
+    (0x11a3b, 0x11a3e, V10_0), (0x11a47, 0x11a47, V10_0), (0x11a51, 0x11a5b, V10_0),
+    (0x11a8a, 0x11a99, V10_0), (0x11bf0, 0x11bf9, V16_0), (0x11c2f, 0x11c36, V9_0),
+    (0x11c38, 0x11c3f, V9_0), (0x11c50, 0x11c59, V9_0), (0x11c92, 0x11ca7, V9_0),
+    (0x11ca9, 0x11cb6, V9_0), (0x11d31, 0x11d36, V10_0), (0x11d3a, 0x11d3a, V10_0),
+    (0x11d3c, 0x11d3d, V10_0), (0x11d3f, 0x11d45, V10_0), (0x11d47, 0x11d47, V10_0),
+    (0x11d50, 0x11d59, V10_0), (0x11d8a, 0x11d8e, V11_0), (0x11d90, 0x11d91, V11_0),
+    (0x11d93, 0x11d97, V11_0), (0x11da0, 0x11da9, V11_0), (0x11ef3, 0x11ef6, V11_0),
+    (0x11f00, 0x11f01, V15_0), (0x11f03, 0x11f03, V15_0), (0x11f34, 0x11f3a, V15_0),
+    (0x11f3e, 0x11f42, V15_0), (0x11f50, 0x11f59, V15_0), (0x11f5a, 0x11f5a, V16_0),
+    (0x13440, 0x13440, V15_0), (0x13447, 0x13455, V15_0), (0x1611e, 0x16139, V16_0),
+    (0x16a60, 0x16a69, V9_0), (0x16ac0, 0x16ac9, V14_0), (0x16af0, 0x16af4, V9_0),
+    (0x16b30, 0x16b36, V9_0), (0x16b50, 0x16b59, V9_0), (0x16d70, 0x16d79, V16_0),
+    (0x16f4f, 0x16f4f, V12_0), (0x16f51, 0x16f7e, V9_0), (0x16f7f, 0x16f87, V12_0),
+    (0x16f8f, 0x16f92, V9_0), (0x16fe4, 0x16fe4, V13_0), (0x16ff0, 0x16ff1, V13_0),
+    (0x1bc9d, 0x1bc9e, V9_0), (0x1ccf0, 0x1ccf9, V16_0), (0x1cf00, 0x1cf2d, V14_0),
+    (0x1cf30, 0x1cf46, V14_0), (0x1d165, 0x1d169, V9_0), (0x1d16d, 0x1d172, V9_0),
+    (0x1d17b, 0x1d182, V9_0), (0x1d185, 0x1d18b, V9_0), (0x1d1aa, 0x1d1ad, V9_0),
+    (0x1d242, 0x1d244, V9_0), (0x1d7ce, 0x1d7ff, V9_0), (0x1da00, 0x1da36, V9_0),
+    (0x1da3b, 0x1da6c, V9_0), (0x1da75, 0x1da75, V9_0), (0x1da84, 0x1da84, V9_0),
+    (0x1da9b, 0x1da9f, V9_0), (0x1daa1, 0x1daaf, V9_0), (0x1e000, 0x1e006, V9_0),
+    (0x1e008, 0x1e018, V9_0), (0x1e01b, 0x1e021, V9_0), (0x1e023, 0x1e024, V9_0),
+    (0x1e026, 0x1e02a, V9_0), (0x1e08f, 0x1e08f, V15_0), (0x1e130, 0x1e136, V12_0),
+    (0x1e140, 0x1e149, V12_0), (0x1e2ae, 0x1e2ae, V14_0), (0x1e2ec, 0x1e2f9, V12_0),
+    (0x1e4ec, 0x1e4f9, V15_0), (0x1e5ee, 0x1e5ef, V16_0), (0x1e5f1, 0x1e5fa, V16_0),
+    (0x1e8d0, 0x1e8d6, V9_0), (0x1e944, 0x1e94a, V9_0), (0x1e950, 0x1e959, V9_0),
+    (0x1fbf0, 0x1fbf9, V13_0), (0xe0100, 0xe01ef, V9_0),
+];
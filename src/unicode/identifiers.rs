@@ -0,0 +1,94 @@
+// Originally from: https://github.com/mysticatea/regexpp
+//
+// Rust port Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use core::cmp::Ordering;
+
+use super::id_tables::{LARGE_ID_CONTINUE_RANGES, LARGE_ID_START_RANGES};
+use super::UnicodeVersion;
+
+pub fn is_large_id_start(cp: u32, version: UnicodeVersion) -> bool {
+    is_in_range(cp, version, LARGE_ID_START_RANGES)
+}
+
+pub fn is_large_id_continue(cp: u32, version: UnicodeVersion) -> bool {
+    is_in_range(cp, version, LARGE_ID_CONTINUE_RANGES)
+}
+
+/// Returns the Unicode version in which `cp` joined `ID_Start`, if it is in it.
+pub fn large_id_start_version(cp: u32) -> Option<UnicodeVersion> {
+    range_version(cp, LARGE_ID_START_RANGES)
+}
+
+/// Returns the Unicode version in which `cp` joined `ID_Continue`, if it is in it.
+pub fn large_id_continue_version(cp: u32) -> Option<UnicodeVersion> {
+    let start = range_version(cp, LARGE_ID_START_RANGES);
+    let continue_ = range_version(cp, LARGE_ID_CONTINUE_RANGES);
+    match (start, continue_) {
+        (Some(start), Some(continue_)) => Some(start.min(continue_)),
+        _ => start.or(continue_),
+    }
+}
+
+fn is_in_range(cp: u32, version: UnicodeVersion, ranges: &[(u32, u32, UnicodeVersion)]) -> bool {
+    range_version(cp, ranges).is_some_and(|v| v <= version)
+}
+
+fn range_version(cp: u32, ranges: &[(u32, u32, UnicodeVersion)]) -> Option<UnicodeVersion> {
+    let i = ranges
+        .binary_search_by(|&(min, max, _)| {
+            if cp < min {
+                Ordering::Greater
+            } else if cp > max {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()?;
+    Some(ranges[i].2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unicode::UnicodeVersion::*;
+
+    #[test]
+    fn tables_are_sorted() {
+        for ranges in &[LARGE_ID_START_RANGES, LARGE_ID_CONTINUE_RANGES] {
+            assert!(ranges.iter().all(|&(min, max, _)| min <= max));
+            assert!(ranges.windows(2).all(|w| w[0].1 < w[1].0));
+        }
+    }
+
+    #[test]
+    fn id_ranges() {
+        assert!(is_large_id_start(0xaa, V9_0));
+        assert!(is_large_id_start(0x3b1, V9_0)); // α
+        assert!(!is_large_id_start(0xab, V16_0));
+        assert!(is_large_id_continue(0x300, V9_0));
+        assert!(!is_large_id_continue(0x3b1, V16_0));
+    }
+
+    #[test]
+    fn id_ranges_by_version() {
+        // NUSHU CHARACTER-1B170 was added in Unicode 10.0
+        assert!(!is_large_id_start(0x1b170, V9_0));
+        assert!(is_large_id_start(0x1b170, V10_0));
+        // KAWI LETTER A was added in Unicode 15.0
+        assert!(!is_large_id_start(0x11f04, V14_0));
+        assert!(is_large_id_start(0x11f04, V15_0));
+        // KATAKANA MIDDLE DOT joined ID_Continue in Unicode 15.1
+        assert!(!is_large_id_continue(0x30fb, V15_0));
+        assert!(is_large_id_continue(0x30fb, V15_1));
+        // VEDIC SIGN ARDHAVISARGA moved from ID_Continue to ID_Start in Unicode 12.0
+        assert!(!is_large_id_start(0x1cf2, V11_0));
+        assert!(is_large_id_continue(0x1cf2, V11_0));
+        assert!(is_large_id_start(0x1cf2, V12_0));
+        assert_eq!(large_id_start_version(0x11f04), Some(V15_0));
+        assert_eq!(large_id_continue_version(0x1cf2), Some(V9_0));
+        assert_eq!(large_id_continue_version(0xab), None);
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+//! Unicode data used by the validator.
+//!
+//! The property tables are only needed for `\p{...}` escapes and the identifier tables only
+//! for non-ASCII capture group names. Each can be left out of the build by disabling the
+//! `unicode-properties` or `unicode-identifiers` feature respectively.
+//!
+//! Every property value and identifier range is tagged with the `UnicodeVersion` that
+//! introduced it, so lookups can be restricted to the data of a given Unicode version.
+//! The tables in `property_tables.rs` and `id_tables.rs` are generated by the `unicode-gen`
+//! crate of this workspace.
+
+#[cfg(not(feature = "unicode-properties"))]
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use crate::validator::EcmaVersion;
+
+#[cfg(feature = "unicode-identifiers")]
+mod id_tables;
+#[cfg(feature = "unicode-identifiers")]
+mod identifiers;
+#[cfg(feature = "unicode-properties")]
+mod properties;
+#[cfg(feature = "unicode-properties")]
+mod property_tables;
+#[cfg(feature = "unicode-properties")]
+mod suggest;
+
+#[cfg(feature = "unicode-identifiers")]
+pub use identifiers::{
+    is_large_id_continue, is_large_id_start, large_id_continue_version, large_id_start_version,
+};
+#[cfg(feature = "unicode-properties")]
+pub use properties::{
+    canonical_property_name, is_valid_lone_unicode_property, is_valid_string_property,
+    is_valid_unicode_property, lone_unicode_property_version, lookup, property_names,
+    unicode_property_version, value_table, ValueTable,
+};
+#[cfg(feature = "unicode-properties")]
+pub use suggest::{lone_property_error, property_error};
+#[cfg(feature = "unicode-properties")]
+pub use property_tables::{BIN_PROPERTY_PATTERNS, GC_VALUE_PATTERNS, SC_VALUE_PATTERNS};
+
+/// A version of the Unicode Standard, selecting which property values and identifier
+/// characters are known to the validator.
+///
+/// `V9_0` is the oldest version the tables distinguish and also stands for all versions
+/// before it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum UnicodeVersion {
+    V9_0,
+    V10_0,
+    V11_0,
+    V12_0,
+    V12_1,
+    V13_0,
+    V14_0,
+    V15_0,
+    V15_1,
+    V16_0,
+}
+
+impl UnicodeVersion {
+    /// The newest Unicode version the tables contain.
+    pub const LATEST: UnicodeVersion = UnicodeVersion::V16_0;
+
+    /// Returns the Unicode version a specification requires engines to support at least.
+    pub fn of_ecma_version(ecma_version: EcmaVersion) -> Self {
+        match ecma_version {
+            EcmaVersion::ES5 | EcmaVersion::ES2015 | EcmaVersion::ES2016 | EcmaVersion::ES2017 => {
+                UnicodeVersion::V9_0
+            }
+            EcmaVersion::ES2018 => UnicodeVersion::V10_0,
+            EcmaVersion::ES2019 => UnicodeVersion::V11_0,
+            EcmaVersion::ES2020 => UnicodeVersion::V12_1,
+            EcmaVersion::ES2021 => UnicodeVersion::V13_0,
+            EcmaVersion::ES2022 => UnicodeVersion::V14_0,
+        }
+    }
+}
+
+impl Default for UnicodeVersion {
+    fn default() -> Self {
+        UnicodeVersion::LATEST
+    }
+}
+
+impl fmt::Display for UnicodeVersion {
+    /// Formats the version like the Unicode Standard does, e.g. `15.1`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            UnicodeVersion::V9_0 => "9.0",
+            UnicodeVersion::V10_0 => "10.0",
+            UnicodeVersion::V11_0 => "11.0",
+            UnicodeVersion::V12_0 => "12.0",
+            UnicodeVersion::V12_1 => "12.1",
+            UnicodeVersion::V13_0 => "13.0",
+            UnicodeVersion::V14_0 => "14.0",
+            UnicodeVersion::V15_0 => "15.0",
+            UnicodeVersion::V15_1 => "15.1",
+            UnicodeVersion::V16_0 => "16.0",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Whether this build contains the tables for Unicode property escapes.
+pub const HAS_PROPERTY_TABLES: bool = cfg!(feature = "unicode-properties");
+
+#[cfg(not(feature = "unicode-properties"))]
+pub fn is_valid_unicode_property(_: UnicodeVersion, _: &str, _: &str) -> bool {
+    false
+}
+
+#[cfg(not(feature = "unicode-properties"))]
+pub fn is_valid_lone_unicode_property(_: UnicodeVersion, _: &str) -> bool {
+    false
+}
+
+#[cfg(not(feature = "unicode-properties"))]
+pub fn is_valid_string_property(_: UnicodeVersion, _: &str) -> bool {
+    false
+}
+
+#[cfg(not(feature = "unicode-properties"))]
+pub fn unicode_property_version(_: &str, _: &str) -> Option<UnicodeVersion> {
+    None
+}
+
+#[cfg(not(feature = "unicode-properties"))]
+pub fn lone_unicode_property_version(_: &str) -> Option<UnicodeVersion> {
+    None
+}
+
+#[cfg(not(feature = "unicode-properties"))]
+pub fn property_error(_: UnicodeVersion, _: &str, _: &str) -> String {
+    "Invalid property name".to_string()
+}
+
+#[cfg(not(feature = "unicode-properties"))]
+pub fn lone_property_error(_: UnicodeVersion, _: &str) -> String {
+    "Invalid property name".to_string()
+}
+
+/// Without the identifier tables, `Alphabetic` from `core` stands in for `ID_Start`.
+#[cfg(not(feature = "unicode-identifiers"))]
+pub fn is_large_id_start(cp: u32, _: UnicodeVersion) -> bool {
+    core::char::from_u32(cp).is_some_and(char::is_alphabetic)
+}
+
+/// Without the identifier tables the Unicode version of a character is unknown.
+#[cfg(not(feature = "unicode-identifiers"))]
+pub fn large_id_start_version(_: u32) -> Option<UnicodeVersion> {
+    None
+}
+
+#[cfg(not(feature = "unicode-identifiers"))]
+pub fn large_id_continue_version(_: u32) -> Option<UnicodeVersion> {
+    None
+}
+
+/// Without the identifier tables, `Numeric` from `core` stands in for the code points that
+/// are in `ID_Continue` but not in `ID_Start`.
+#[cfg(not(feature = "unicode-identifiers"))]
+pub fn is_large_id_continue(cp: u32, _: UnicodeVersion) -> bool {
+    core::char::from_u32(cp).is_some_and(char::is_numeric)
+}
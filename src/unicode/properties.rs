@@ -0,0 +1,123 @@
+// Originally from: https://github.com/mysticatea/regexpp
+//
+// Rust port Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use super::property_tables::*;
+use super::UnicodeVersion;
+
+/// A table of names with their canonical names and the Unicode version that introduced them,
+/// sorted by name.
+pub type ValueTable = &'static [(&'static str, &'static str, UnicodeVersion)];
+
+pub fn is_valid_unicode_property(version: UnicodeVersion, name: &str, value: &str) -> bool {
+    canonical_property_name(name)
+        .and_then(value_table)
+        .and_then(|table| lookup(table, version, value))
+        .is_some()
+}
+
+pub fn is_valid_lone_unicode_property(version: UnicodeVersion, value: &str) -> bool {
+    lookup(BIN_PROPERTY_PATTERNS, version, value).is_some()
+}
+
+pub fn is_valid_string_property(version: UnicodeVersion, value: &str) -> bool {
+    lookup(STRING_PROPERTY_PATTERNS, version, value).is_some()
+}
+
+/// Returns the Unicode version that introduced `value` of the property `name`, whatever
+/// version the validator is configured with.
+pub fn unicode_property_version(name: &str, value: &str) -> Option<UnicodeVersion> {
+    let table = canonical_property_name(name).and_then(value_table)?;
+    lookup(table, UnicodeVersion::LATEST, value).map(|entry| entry.2)
+}
+
+/// Returns the Unicode version that introduced the `General_Category` value or binary
+/// property `value`.
+pub fn lone_unicode_property_version(value: &str) -> Option<UnicodeVersion> {
+    unicode_property_version("General_Category", value)
+        .or_else(|| lookup(BIN_PROPERTY_PATTERNS, UnicodeVersion::LATEST, value).map(|e| e.2))
+}
+
+/// Returns the canonical name of a property that takes a value, like `Script` for `sc`.
+pub fn canonical_property_name(name: &str) -> Option<&'static str> {
+    for names in &[GC_NAME_PATTERN, SC_NAME_PATTERN] {
+        if let Ok(i) = names.binary_search_by(|&(n, _)| n.cmp(name)) {
+            return Some(names[i].1);
+        }
+    }
+    None
+}
+
+/// Returns the names of all properties that take a value, with their canonical names.
+pub fn property_names() -> impl Iterator<Item = &'static (&'static str, &'static str)> {
+    GC_NAME_PATTERN.iter().chain(SC_NAME_PATTERN)
+}
+
+/// Returns the values of the property with the given canonical name.
+pub fn value_table(canonical_name: &str) -> Option<ValueTable> {
+    match canonical_name {
+        "General_Category" => Some(GC_VALUE_PATTERNS),
+        "Script" | "Script_Extensions" => Some(SC_VALUE_PATTERNS),
+        _ => None,
+    }
+}
+
+/// Finds `value` in `table` if it was introduced in `version` or before.
+pub fn lookup(
+    table: ValueTable,
+    version: UnicodeVersion,
+    value: &str,
+) -> Option<&'static (&'static str, &'static str, UnicodeVersion)> {
+    let i = table.binary_search_by(|&(v, _, _)| v.cmp(value)).ok()?;
+    Some(&table[i]).filter(|entry| entry.2 <= version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unicode::UnicodeVersion::*;
+
+    #[test]
+    fn tables_are_sorted() {
+        for names in &[GC_NAME_PATTERN, SC_NAME_PATTERN] {
+            assert!(names.windows(2).all(|w| w[0].0 < w[1].0));
+        }
+        for values in &[
+            GC_VALUE_PATTERNS,
+            SC_VALUE_PATTERNS,
+            BIN_PROPERTY_PATTERNS,
+            STRING_PROPERTY_PATTERNS,
+        ] {
+            assert!(values.windows(2).all(|w| w[0].0 < w[1].0));
+        }
+    }
+
+    #[test]
+    fn values_by_version() {
+        assert!(is_valid_unicode_property(V9_0, "Script", "Greek"));
+        assert!(!is_valid_unicode_property(V9_0, "Script", "Nushu"));
+        assert!(is_valid_unicode_property(V10_0, "sc", "Nushu"));
+        assert!(!is_valid_unicode_property(V14_0, "scx", "Kawi"));
+        assert!(is_valid_unicode_property(V15_0, "scx", "Kawi"));
+        assert!(is_valid_unicode_property(V16_0, "Script", "Tulu_Tigalari"));
+        let ext_pict = "Extended_Pictographic";
+        assert!(!is_valid_lone_unicode_property(V10_0, ext_pict));
+        assert!(is_valid_lone_unicode_property(V11_0, ext_pict));
+        assert_eq!(unicode_property_version("sc", "Elymaic"), Some(V12_0));
+        assert_eq!(unicode_property_version("sc", "Unknown_Script"), None);
+        assert_eq!(lone_unicode_property_version("Lu"), Some(V9_0));
+        assert_eq!(lone_unicode_property_version(ext_pict), Some(V11_0));
+    }
+
+    #[test]
+    fn canonical_names() {
+        assert_eq!(canonical_property_name("gc"), Some("General_Category"));
+        assert_eq!(canonical_property_name("scx"), Some("Script_Extensions"));
+        assert_eq!(canonical_property_name("Alpha"), None);
+        let table = value_table("Script_Extensions").unwrap();
+        assert_eq!(lookup(table, V9_0, "Grek"), Some(&("Grek", "Greek", V9_0)));
+        assert_eq!(lookup(table, V9_0, "Nshu"), None);
+        assert_eq!(lookup(BIN_PROPERTY_PATTERNS, V9_0, "space").unwrap().1, "White_Space");
+    }
+}
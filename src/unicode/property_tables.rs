@@ -0,0 +1,547 @@
+// This file was generated by unicode-gen. Do not edit it by hand.
+//
+// Unicode Character Database versions:
+// 9.0.0 10.0.0 11.0.0 12.0.0 12.1.0 13.0.0 14.0.0 15.0.0 15.1.0 16.0.0
+
+use super::UnicodeVersion::{self, *};
+
+pub static GC_NAME_PATTERN: &[(&str, &str)] = &[
+    ("General_Category", "General_Category"),
+    ("gc", "General_Category"),
+];
+
+pub static SC_NAME_PATTERN: &[(&str, &str)] = &[
+    ("Script", "Script"),
+    ("Script_Extensions", "Script_Extensions"),
+    ("sc", "Script"),
+    ("scx", "Script_Extensions"),
+];
+
+// Property values with their canonical names, each tagged with the Unicode version that
+// introduced it.
+
+pub static GC_VALUE_PATTERNS: &[(&str, &str, UnicodeVersion)] = &[
+    ("C", "Other", V9_0),
+    ("Cased_Letter", "Cased_Letter", V9_0),
+    ("Cc", "Control", V9_0),
+    ("Cf", "Format", V9_0),
+    ("Close_Punctuation", "Close_Punctuation", V9_0),
+    ("Cn", "Unassigned", V9_0),
+    ("Co", "Private_Use", V9_0),
+    ("Combining_Mark", "Mark", V9_0),
+    ("Connector_Punctuation", "Connector_Punctuation", V9_0),
+    ("Control", "Control", V9_0),
+    ("Cs", "Surrogate", V9_0),
+    ("Currency_Symbol", "Currency_Symbol", V9_0),
+    ("Dash_Punctuation", "Dash_Punctuation", V9_0),
+    ("Decimal_Number", "Decimal_Number", V9_0),
+    ("Enclosing_Mark", "Enclosing_Mark", V9_0),
+    ("Final_Punctuation", "Final_Punctuation", V9_0),
+    ("Format", "Format", V9_0),
+    ("Initial_Punctuation", "Initial_Punctuation", V9_0),
+    ("L", "Letter", V9_0),
+    ("LC", "Cased_Letter", V9_0),
+    ("Letter", "Letter", V9_0),
+    ("Letter_Number", "Letter_Number", V9_0),
+    ("Line_Separator", "Line_Separator", V9_0),
+    ("Ll", "Lowercase_Letter", V9_0),
+    ("Lm", "Modifier_Letter", V9_0),
+    ("Lo", "Other_Letter", V9_0),
+    ("Lowercase_Letter", "Lowercase_Letter", V9_0),
+    ("Lt", "Titlecase_Letter", V9_0),
+    ("Lu", "Uppercase_Letter", V9_0),
+    ("M", "Mark", V9_0),
+    ("Mark", "Mark", V9_0),
+    ("Math_Symbol", "Math_Symbol", V9_0),
+    ("Mc", "Spacing_Mark", V9_0),
+    ("Me", "Enclosing_Mark", V9_0),
+    ("Mn", "Nonspacing_Mark", V9_0),
+    ("Modifier_Letter", "Modifier_Letter", V9_0),
+    ("Modifier_Symbol", "Modifier_Symbol", V9_0),
+    ("N", "Number", V9_0),
+    ("Nd", "Decimal_Number", V9_0),
+    ("Nl", "Letter_Number", V9_0),
+    ("No", "Other_Number", V9_0),
+    ("Nonspacing_Mark", "Nonspacing_Mark", V9_0),
+    ("Number", "Number", V9_0),
+    ("Open_Punctuation", "Open_Punctuation", V9_0),
+    ("Other", "Other", V9_0),
+    ("Other_Letter", "Other_Letter", V9_0),
+    ("Other_Number", "Other_Number", V9_0),
+    ("Other_Punctuation", "Other_Punctuation", V9_0),
+    ("Other_Symbol", "Other_Symbol", V9_0),
+    ("P", "Punctuation", V9_0),
+    ("Paragraph_Separator", "Paragraph_Separator", V9_0),
+    ("Pc", "Connector_Punctuation", V9_0),
+    ("Pd", "Dash_Punctuation", V9_0),
+    ("Pe", "Close_Punctuation", V9_0),
+    ("Pf", "Final_Punctuation", V9_0),
+    ("Pi", "Initial_Punctuation", V9_0),
+    ("Po", "Other_Punctuation", V9_0),
+    ("Private_Use", "Private_Use", V9_0),
+    ("Ps", "Open_Punctuation", V9_0),
+    ("Punctuation", "Punctuation", V9_0),
+    ("S", "Symbol", V9_0),
+    ("Sc", "Currency_Symbol", V9_0),
+    ("Separator", "Separator", V9_0),
+    ("Sk", "Modifier_Symbol", V9_0),
+    ("Sm", "Math_Symbol", V9_0),
+    ("So", "Other_Symbol", V9_0),
+    ("Space_Separator", "Space_Separator", V9_0),
+    ("Spacing_Mark", "Spacing_Mark", V9_0),
+    ("Surrogate", "Surrogate", V9_0),
+    ("Symbol", "Symbol", V9_0),
+    ("Titlecase_Letter", "Titlecase_Letter", V9_0),
+    ("Unassigned", "Unassigned", V9_0),
+    ("Uppercase_Letter", "Uppercase_Letter", V9_0),
+    ("Z", "Separator", V9_0),
+    ("Zl", "Line_Separator", V9_0),
+    ("Zp", "Paragraph_Separator", V9_0),
+    ("Zs", "Space_Separator", V9_0),
+    ("cntrl", "Control", V9_0),
+    ("digit", "Decimal_Number", V9_0),
+    ("punct", "Punctuation", V9_0),
+];
+
+pub static SC_VALUE_PATTERNS: &[(&str, &str, UnicodeVersion)] = &[
+    ("Adlam", "Adlam", V9_0),
+    ("Adlm", "Adlam", V9_0),
+    ("Aghb", "Caucasian_Albanian", V9_0),
+    ("Ahom", "Ahom", V9_0),
+    ("Anatolian_Hieroglyphs", "Anatolian_Hieroglyphs", V9_0),
+    ("Arab", "Arabic", V9_0),
+    ("Arabic", "Arabic", V9_0),
+    ("Armenian", "Armenian", V9_0),
+    ("Armi", "Imperial_Aramaic", V9_0),
+    ("Armn", "Armenian", V9_0),
+    ("Avestan", "Avestan", V9_0),
+    ("Avst", "Avestan", V9_0),
+    ("Bali", "Balinese", V9_0),
+    ("Balinese", "Balinese", V9_0),
+    ("Bamu", "Bamum", V9_0),
+    ("Bamum", "Bamum", V9_0),
+    ("Bass", "Bassa_Vah", V9_0),
+    ("Bassa_Vah", "Bassa_Vah", V9_0),
+    ("Batak", "Batak", V9_0),
+    ("Batk", "Batak", V9_0),
+    ("Beng", "Bengali", V9_0),
+    ("Bengali", "Bengali", V9_0),
+    ("Bhaiksuki", "Bhaiksuki", V9_0),
+    ("Bhks", "Bhaiksuki", V9_0),
+    ("Bopo", "Bopomofo", V9_0),
+    ("Bopomofo", "Bopomofo", V9_0),
+    ("Brah", "Brahmi", V9_0),
+    ("Brahmi", "Brahmi", V9_0),
+    ("Brai", "Braille", V9_0),
+    ("Braille", "Braille", V9_0),
+    ("Bugi", "Buginese", V9_0),
+    ("Buginese", "Buginese", V9_0),
+    ("Buhd", "Buhid", V9_0),
+    ("Buhid", "Buhid", V9_0),
+    ("Cakm", "Chakma", V9_0),
+    ("Canadian_Aboriginal", "Canadian_Aboriginal", V9_0),
+    ("Cans", "Canadian_Aboriginal", V9_0),
+    ("Cari", "Carian", V9_0),
+    ("Carian", "Carian", V9_0),
+    ("Caucasian_Albanian", "Caucasian_Albanian", V9_0),
+    ("Chakma", "Chakma", V9_0),
+    ("Cham", "Cham", V9_0),
+    ("Cher", "Cherokee", V9_0),
+    ("Cherokee", "Cherokee", V9_0),
+    ("Chorasmian", "Chorasmian", V13_0),
+    ("Chrs", "Chorasmian", V13_0),
+    ("Common", "Common", V9_0),
+    ("Copt", "Coptic", V9_0),
+    ("Coptic", "Coptic", V9_0),
+    ("Cpmn", "Cypro_Minoan", V14_0),
+    ("Cprt", "Cypriot", V9_0),
+    ("Cuneiform", "Cuneiform", V9_0),
+    ("Cypriot", "Cypriot", V9_0),
+    ("Cypro_Minoan", "Cypro_Minoan", V14_0),
+    ("Cyrillic", "Cyrillic", V9_0),
+    ("Cyrl", "Cyrillic", V9_0),
+    ("Deseret", "Deseret", V9_0),
+    ("Deva", "Devanagari", V9_0),
+    ("Devanagari", "Devanagari", V9_0),
+    ("Diak", "Dives_Akuru", V13_0),
+    ("Dives_Akuru", "Dives_Akuru", V13_0),
+    ("Dogr", "Dogra", V11_0),
+    ("Dogra", "Dogra", V11_0),
+    ("Dsrt", "Deseret", V9_0),
+    ("Dupl", "Duployan", V9_0),
+    ("Duployan", "Duployan", V9_0),
+    ("Egyp", "Egyptian_Hieroglyphs", V9_0),
+    ("Egyptian_Hieroglyphs", "Egyptian_Hieroglyphs", V9_0),
+    ("Elba", "Elbasan", V9_0),
+    ("Elbasan", "Elbasan", V9_0),
+    ("Elym", "Elymaic", V12_0),
+    ("Elymaic", "Elymaic", V12_0),
+    ("Ethi", "Ethiopic", V9_0),
+    ("Ethiopic", "Ethiopic", V9_0),
+    ("Gara", "Garay", V16_0),
+    ("Garay", "Garay", V16_0),
+    ("Geor", "Georgian", V9_0),
+    ("Georgian", "Georgian", V9_0),
+    ("Glag", "Glagolitic", V9_0),
+    ("Glagolitic", "Glagolitic", V9_0),
+    ("Gong", "Gunjala_Gondi", V11_0),
+    ("Gonm", "Masaram_Gondi", V10_0),
+    ("Goth", "Gothic", V9_0),
+    ("Gothic", "Gothic", V9_0),
+    ("Gran", "Grantha", V9_0),
+    ("Grantha", "Grantha", V9_0),
+    ("Greek", "Greek", V9_0),
+    ("Grek", "Greek", V9_0),
+    ("Gujarati", "Gujarati", V9_0),
+    ("Gujr", "Gujarati", V9_0),
+    ("Gukh", "Gurung_Khema", V16_0),
+    ("Gunjala_Gondi", "Gunjala_Gondi", V11_0),
+    ("Gurmukhi", "Gurmukhi", V9_0),
+    ("Guru", "Gurmukhi", V9_0),
+    ("Gurung_Khema", "Gurung_Khema", V16_0),
+    ("Han", "Han", V9_0),
+    ("Hang", "Hangul", V9_0),
+    ("Hangul", "Hangul", V9_0),
+    ("Hani", "Han", V9_0),
+    ("Hanifi_Rohingya", "Hanifi_Rohingya", V11_0),
+    ("Hano", "Hanunoo", V9_0),
+    ("Hanunoo", "Hanunoo", V9_0),
+    ("Hatr", "Hatran", V9_0),
+    ("Hatran", "Hatran", V9_0),
+    ("Hebr", "Hebrew", V9_0),
+    ("Hebrew", "Hebrew", V9_0),
+    ("Hira", "Hiragana", V9_0),
+    ("Hiragana", "Hiragana", V9_0),
+    ("Hluw", "Anatolian_Hieroglyphs", V9_0),
+    ("Hmng", "Pahawh_Hmong", V9_0),
+    ("Hmnp", "Nyiakeng_Puachue_Hmong", V12_0),
+    ("Hung", "Old_Hungarian", V9_0),
+    ("Imperial_Aramaic", "Imperial_Aramaic", V9_0),
+    ("Inherited", "Inherited", V9_0),
+    ("Inscriptional_Pahlavi", "Inscriptional_Pahlavi", V9_0),
+    ("Inscriptional_Parthian", "Inscriptional_Parthian", V9_0),
+    ("Ital", "Old_Italic", V9_0),
+    ("Java", "Javanese", V9_0),
+    ("Javanese", "Javanese", V9_0),
+    ("Kaithi", "Kaithi", V9_0),
+    ("Kali", "Kayah_Li", V9_0),
+    ("Kana", "Katakana", V9_0),
+    ("Kannada", "Kannada", V9_0),
+    ("Katakana", "Katakana", V9_0),
+    ("Kawi", "Kawi", V15_0),
+    ("Kayah_Li", "Kayah_Li", V9_0),
+    ("Khar", "Kharoshthi", V9_0),
+    ("Kharoshthi", "Kharoshthi", V9_0),
+    ("Khitan_Small_Script", "Khitan_Small_Script", V13_0),
+    ("Khmer", "Khmer", V9_0),
+    ("Khmr", "Khmer", V9_0),
+    ("Khoj", "Khojki", V9_0),
+    ("Khojki", "Khojki", V9_0),
+    ("Khudawadi", "Khudawadi", V9_0),
+    ("Kirat_Rai", "Kirat_Rai", V16_0),
+    ("Kits", "Khitan_Small_Script", V13_0),
+    ("Knda", "Kannada", V9_0),
+    ("Krai", "Kirat_Rai", V16_0),
+    ("Kthi", "Kaithi", V9_0),
+    ("Lana", "Tai_Tham", V9_0),
+    ("Lao", "Lao", V9_0),
+    ("Laoo", "Lao", V9_0),
+    ("Latin", "Latin", V9_0),
+    ("Latn", "Latin", V9_0),
+    ("Lepc", "Lepcha", V9_0),
+    ("Lepcha", "Lepcha", V9_0),
+    ("Limb", "Limbu", V9_0),
+    ("Limbu", "Limbu", V9_0),
+    ("Lina", "Linear_A", V9_0),
+    ("Linb", "Linear_B", V9_0),
+    ("Linear_A", "Linear_A", V9_0),
+    ("Linear_B", "Linear_B", V9_0),
+    ("Lisu", "Lisu", V9_0),
+    ("Lyci", "Lycian", V9_0),
+    ("Lycian", "Lycian", V9_0),
+    ("Lydi", "Lydian", V9_0),
+    ("Lydian", "Lydian", V9_0),
+    ("Mahajani", "Mahajani", V9_0),
+    ("Mahj", "Mahajani", V9_0),
+    ("Maka", "Makasar", V11_0),
+    ("Makasar", "Makasar", V11_0),
+    ("Malayalam", "Malayalam", V9_0),
+    ("Mand", "Mandaic", V9_0),
+    ("Mandaic", "Mandaic", V9_0),
+    ("Mani", "Manichaean", V9_0),
+    ("Manichaean", "Manichaean", V9_0),
+    ("Marc", "Marchen", V9_0),
+    ("Marchen", "Marchen", V9_0),
+    ("Masaram_Gondi", "Masaram_Gondi", V10_0),
+    ("Medefaidrin", "Medefaidrin", V11_0),
+    ("Medf", "Medefaidrin", V11_0),
+    ("Meetei_Mayek", "Meetei_Mayek", V9_0),
+    ("Mend", "Mende_Kikakui", V9_0),
+    ("Mende_Kikakui", "Mende_Kikakui", V9_0),
+    ("Merc", "Meroitic_Cursive", V9_0),
+    ("Mero", "Meroitic_Hieroglyphs", V9_0),
+    ("Meroitic_Cursive", "Meroitic_Cursive", V9_0),
+    ("Meroitic_Hieroglyphs", "Meroitic_Hieroglyphs", V9_0),
+    ("Miao", "Miao", V9_0),
+    ("Mlym", "Malayalam", V9_0),
+    ("Modi", "Modi", V9_0),
+    ("Mong", "Mongolian", V9_0),
+    ("Mongolian", "Mongolian", V9_0),
+    ("Mro", "Mro", V9_0),
+    ("Mroo", "Mro", V9_0),
+    ("Mtei", "Meetei_Mayek", V9_0),
+    ("Mult", "Multani", V9_0),
+    ("Multani", "Multani", V9_0),
+    ("Myanmar", "Myanmar", V9_0),
+    ("Mymr", "Myanmar", V9_0),
+    ("Nabataean", "Nabataean", V9_0),
+    ("Nag_Mundari", "Nag_Mundari", V15_0),
+    ("Nagm", "Nag_Mundari", V15_0),
+    ("Nand", "Nandinagari", V12_0),
+    ("Nandinagari", "Nandinagari", V12_0),
+    ("Narb", "Old_North_Arabian", V9_0),
+    ("Nbat", "Nabataean", V9_0),
+    ("New_Tai_Lue", "New_Tai_Lue", V9_0),
+    ("Newa", "Newa", V9_0),
+    ("Nko", "Nko", V9_0),
+    ("Nkoo", "Nko", V9_0),
+    ("Nshu", "Nushu", V10_0),
+    ("Nushu", "Nushu", V10_0),
+    ("Nyiakeng_Puachue_Hmong", "Nyiakeng_Puachue_Hmong", V12_0),
+    ("Ogam", "Ogham", V9_0),
+    ("Ogham", "Ogham", V9_0),
+    ("Ol_Chiki", "Ol_Chiki", V9_0),
+    ("Ol_Onal", "Ol_Onal", V16_0),
+    ("Olck", "Ol_Chiki", V9_0),
+    ("Old_Hungarian", "Old_Hungarian", V9_0),
+    ("Old_Italic", "Old_Italic", V9_0),
+    ("Old_North_Arabian", "Old_North_Arabian", V9_0),
+    ("Old_Permic", "Old_Permic", V9_0),
+    ("Old_Persian", "Old_Persian", V9_0),
+    ("Old_Sogdian", "Old_Sogdian", V11_0),
+    ("Old_South_Arabian", "Old_South_Arabian", V9_0),
+    ("Old_Turkic", "Old_Turkic", V9_0),
+    ("Old_Uyghur", "Old_Uyghur", V14_0),
+    ("Onao", "Ol_Onal", V16_0),
+    ("Oriya", "Oriya", V9_0),
+    ("Orkh", "Old_Turkic", V9_0),
+    ("Orya", "Oriya", V9_0),
+    ("Osage", "Osage", V9_0),
+    ("Osge", "Osage", V9_0),
+    ("Osma", "Osmanya", V9_0),
+    ("Osmanya", "Osmanya", V9_0),
+    ("Ougr", "Old_Uyghur", V14_0),
+    ("Pahawh_Hmong", "Pahawh_Hmong", V9_0),
+    ("Palm", "Palmyrene", V9_0),
+    ("Palmyrene", "Palmyrene", V9_0),
+    ("Pau_Cin_Hau", "Pau_Cin_Hau", V9_0),
+    ("Pauc", "Pau_Cin_Hau", V9_0),
+    ("Perm", "Old_Permic", V9_0),
+    ("Phag", "Phags_Pa", V9_0),
+    ("Phags_Pa", "Phags_Pa", V9_0),
+    ("Phli", "Inscriptional_Pahlavi", V9_0),
+    ("Phlp", "Psalter_Pahlavi", V9_0),
+    ("Phnx", "Phoenician", V9_0),
+    ("Phoenician", "Phoenician", V9_0),
+    ("Plrd", "Miao", V9_0),
+    ("Prti", "Inscriptional_Parthian", V9_0),
+    ("Psalter_Pahlavi", "Psalter_Pahlavi", V9_0),
+    ("Qaac", "Coptic", V9_0),
+    ("Qaai", "Inherited", V9_0),
+    ("Rejang", "Rejang", V9_0),
+    ("Rjng", "Rejang", V9_0),
+    ("Rohg", "Hanifi_Rohingya", V11_0),
+    ("Runic", "Runic", V9_0),
+    ("Runr", "Runic", V9_0),
+    ("Samaritan", "Samaritan", V9_0),
+    ("Samr", "Samaritan", V9_0),
+    ("Sarb", "Old_South_Arabian", V9_0),
+    ("Saur", "Saurashtra", V9_0),
+    ("Saurashtra", "Saurashtra", V9_0),
+    ("Sgnw", "SignWriting", V9_0),
+    ("Sharada", "Sharada", V9_0),
+    ("Shavian", "Shavian", V9_0),
+    ("Shaw", "Shavian", V9_0),
+    ("Shrd", "Sharada", V9_0),
+    ("Sidd", "Siddham", V9_0),
+    ("Siddham", "Siddham", V9_0),
+    ("SignWriting", "SignWriting", V9_0),
+    ("Sind", "Khudawadi", V9_0),
+    ("Sinh", "Sinhala", V9_0),
+    ("Sinhala", "Sinhala", V9_0),
+    ("Sogd", "Sogdian", V11_0),
+    ("Sogdian", "Sogdian", V11_0),
+    ("Sogo", "Old_Sogdian", V11_0),
+    ("Sora", "Sora_Sompeng", V9_0),
+    ("Sora_Sompeng", "Sora_Sompeng", V9_0),
+    ("Soyo", "Soyombo", V10_0),
+    ("Soyombo", "Soyombo", V10_0),
+    ("Sund", "Sundanese", V9_0),
+    ("Sundanese", "Sundanese", V9_0),
+    ("Sunu", "Sunuwar", V16_0),
+    ("Sunuwar", "Sunuwar", V16_0),
+    ("Sylo", "Syloti_Nagri", V9_0),
+    ("Syloti_Nagri", "Syloti_Nagri", V9_0),
+    ("Syrc", "Syriac", V9_0),
+    ("Syriac", "Syriac", V9_0),
+    ("Tagalog", "Tagalog", V9_0),
+    ("Tagb", "Tagbanwa", V9_0),
+    ("Tagbanwa", "Tagbanwa", V9_0),
+    ("Tai_Le", "Tai_Le", V9_0),
+    ("Tai_Tham", "Tai_Tham", V9_0),
+    ("Tai_Viet", "Tai_Viet", V9_0),
+    ("Takr", "Takri", V9_0),
+    ("Takri", "Takri", V9_0),
+    ("Tale", "Tai_Le", V9_0),
+    ("Talu", "New_Tai_Lue", V9_0),
+    ("Tamil", "Tamil", V9_0),
+    ("Taml", "Tamil", V9_0),
+    ("Tang", "Tangut", V9_0),
+    ("Tangsa", "Tangsa", V14_0),
+    ("Tangut", "Tangut", V9_0),
+    ("Tavt", "Tai_Viet", V9_0),
+    ("Telu", "Telugu", V9_0),
+    ("Telugu", "Telugu", V9_0),
+    ("Tfng", "Tifinagh", V9_0),
+    ("Tglg", "Tagalog", V9_0),
+    ("Thaa", "Thaana", V9_0),
+    ("Thaana", "Thaana", V9_0),
+    ("Thai", "Thai", V9_0),
+    ("Tibetan", "Tibetan", V9_0),
+    ("Tibt", "Tibetan", V9_0),
+    ("Tifinagh", "Tifinagh", V9_0),
+    ("Tirh", "Tirhuta", V9_0),
+    ("Tirhuta", "Tirhuta", V9_0),
+    ("Tnsa", "Tangsa", V14_0),
+    ("Todhri", "Todhri", V16_0),
+    ("Todr", "Todhri", V16_0),
+    ("Toto", "Toto", V14_0),
+    ("Tulu_Tigalari", "Tulu_Tigalari", V16_0),
+    ("Tutg", "Tulu_Tigalari", V16_0),
+    ("Ugar", "Ugaritic", V9_0),
+    ("Ugaritic", "Ugaritic", V9_0),
+    ("Vai", "Vai", V9_0),
+    ("Vaii", "Vai", V9_0),
+    ("Vith", "Vithkuqi", V14_0),
+    ("Vithkuqi", "Vithkuqi", V14_0),
+    ("Wancho", "Wancho", V12_0),
+    ("Wara", "Warang_Citi", V9_0),
+    ("Warang_Citi", "Warang_Citi", V9_0),
+    ("Wcho", "Wancho", V12_0),
+    ("Xpeo", "Old_Persian", V9_0),
+    ("Xsux", "Cuneiform", V9_0),
+    ("Yezi", "Yezidi", V13_0),
+    ("Yezidi", "Yezidi", V13_0),
+    ("Yi", "Yi", V9_0),
+    ("Yiii", "Yi", V9_0),
+    ("Zanabazar_Square", "Zanabazar_Square", V10_0),
+    ("Zanb", "Zanabazar_Square", V10_0),
+    ("Zinh", "Inherited", V9_0),
+    ("Zyyy", "Common", V9_0),
+];
+
+pub static BIN_PROPERTY_PATTERNS: &[(&str, &str, UnicodeVersion)] = &[
+    ("AHex", "ASCII_Hex_Digit", V9_0),
+    ("ASCII", "ASCII", V9_0),
+    ("ASCII_Hex_Digit", "ASCII_Hex_Digit", V9_0),
+    ("Alpha", "Alphabetic", V9_0),
+    ("Alphabetic", "Alphabetic", V9_0),
+    ("Any", "Any", V9_0),
+    ("Assigned", "Assigned", V9_0),
+    ("Bidi_C", "Bidi_Control", V9_0),
+    ("Bidi_Control", "Bidi_Control", V9_0),
+    ("Bidi_M", "Bidi_Mirrored", V9_0),
+    ("Bidi_Mirrored", "Bidi_Mirrored", V9_0),
+    ("CI", "Case_Ignorable", V9_0),
+    ("CWCF", "Changes_When_Casefolded", V9_0),
+    ("CWCM", "Changes_When_Casemapped", V9_0),
+    ("CWKCF", "Changes_When_NFKC_Casefolded", V9_0),
+    ("CWL", "Changes_When_Lowercased", V9_0),
+    ("CWT", "Changes_When_Titlecased", V9_0),
+    ("CWU", "Changes_When_Uppercased", V9_0),
+    ("Case_Ignorable", "Case_Ignorable", V9_0),
+    ("Cased", "Cased", V9_0),
+    ("Changes_When_Casefolded", "Changes_When_Casefolded", V9_0),
+    ("Changes_When_Casemapped", "Changes_When_Casemapped", V9_0),
+    ("Changes_When_Lowercased", "Changes_When_Lowercased", V9_0),
+    ("Changes_When_NFKC_Casefolded", "Changes_When_NFKC_Casefolded", V9_0),
+    ("Changes_When_Titlecased", "Changes_When_Titlecased", V9_0),
+    ("Changes_When_Uppercased", "Changes_When_Uppercased", V9_0),
+    ("DI", "Default_Ignorable_Code_Point", V9_0),
+    ("Dash", "Dash", V9_0),
+    ("Default_Ignorable_Code_Point", "Default_Ignorable_Code_Point", V9_0),
+    ("Dep", "Deprecated", V9_0),
+    ("Deprecated", "Deprecated", V9_0),
+    ("Dia", "Diacritic", V9_0),
+    ("Diacritic", "Diacritic", V9_0),
+    ("Emoji", "Emoji", V9_0),
+    ("Emoji_Component", "Emoji_Component", V9_0),
+    ("Emoji_Modifier", "Emoji_Modifier", V9_0),
+    ("Emoji_Modifier_Base", "Emoji_Modifier_Base", V9_0),
+    ("Emoji_Presentation", "Emoji_Presentation", V9_0),
+    ("Ext", "Extender", V9_0),
+    ("Extended_Pictographic", "Extended_Pictographic", V11_0),
+    ("Extender", "Extender", V9_0),
+    ("Gr_Base", "Grapheme_Base", V9_0),
+    ("Gr_Ext", "Grapheme_Extend", V9_0),
+    ("Grapheme_Base", "Grapheme_Base", V9_0),
+    ("Grapheme_Extend", "Grapheme_Extend", V9_0),
+    ("Hex", "Hex_Digit", V9_0),
+    ("Hex_Digit", "Hex_Digit", V9_0),
+    ("IDC", "ID_Continue", V9_0),
+    ("IDS", "ID_Start", V9_0),
+    ("IDSB", "IDS_Binary_Operator", V9_0),
+    ("IDST", "IDS_Trinary_Operator", V9_0),
+    ("IDS_Binary_Operator", "IDS_Binary_Operator", V9_0),
+    ("IDS_Trinary_Operator", "IDS_Trinary_Operator", V9_0),
+    ("ID_Continue", "ID_Continue", V9_0),
+    ("ID_Start", "ID_Start", V9_0),
+    ("Ideo", "Ideographic", V9_0),
+    ("Ideographic", "Ideographic", V9_0),
+    ("Join_C", "Join_Control", V9_0),
+    ("Join_Control", "Join_Control", V9_0),
+    ("LOE", "Logical_Order_Exception", V9_0),
+    ("Logical_Order_Exception", "Logical_Order_Exception", V9_0),
+    ("Lower", "Lowercase", V9_0),
+    ("Lowercase", "Lowercase", V9_0),
+    ("Math", "Math", V9_0),
+    ("NChar", "Noncharacter_Code_Point", V9_0),
+    ("Noncharacter_Code_Point", "Noncharacter_Code_Point", V9_0),
+    ("Pat_Syn", "Pattern_Syntax", V9_0),
+    ("Pat_WS", "Pattern_White_Space", V9_0),
+    ("Pattern_Syntax", "Pattern_Syntax", V9_0),
+    ("Pattern_White_Space", "Pattern_White_Space", V9_0),
+    ("QMark", "Quotation_Mark", V9_0),
+    ("Quotation_Mark", "Quotation_Mark", V9_0),
+    ("RI", "Regional_Indicator", V9_0),
+    ("Radical", "Radical", V9_0),
+    ("Regional_Indicator", "Regional_Indicator", V9_0),
+    ("SD", "Soft_Dotted", V9_0),
+    ("STerm", "Sentence_Terminal", V9_0),
+    ("Sentence_Terminal", "Sentence_Terminal", V9_0),
+    ("Soft_Dotted", "Soft_Dotted", V9_0),
+    ("Term", "Terminal_Punctuation", V9_0),
+    ("Terminal_Punctuation", "Terminal_Punctuation", V9_0),
+    ("UIdeo", "Unified_Ideograph", V9_0),
+    ("Unified_Ideograph", "Unified_Ideograph", V9_0),
+    ("Upper", "Uppercase", V9_0),
+    ("Uppercase", "Uppercase", V9_0),
+    ("VS", "Variation_Selector", V9_0),
+    ("Variation_Selector", "Variation_Selector", V9_0),
+    ("White_Space", "White_Space", V9_0),
+    ("XIDC", "XID_Continue", V9_0),
+    ("XIDS", "XID_Start", V9_0),
+    ("XID_Continue", "XID_Continue", V9_0),
+    ("XID_Start", "XID_Start", V9_0),
+    ("space", "White_Space", V9_0),
+];
+
+pub static STRING_PROPERTY_PATTERNS: &[(&str, &str, UnicodeVersion)] = &[
+    ("Basic_Emoji", "Basic_Emoji", V13_0),
+    ("Emoji_Keycap_Sequence", "Emoji_Keycap_Sequence", V13_0),
+    ("RGI_Emoji", "RGI_Emoji", V13_0),
+    ("RGI_Emoji_Flag_Sequence", "RGI_Emoji_Flag_Sequence", V13_0),
+    ("RGI_Emoji_Modifier_Sequence", "RGI_Emoji_Modifier_Sequence", V13_0),
+    ("RGI_Emoji_Tag_Sequence", "RGI_Emoji_Tag_Sequence", V13_0),
+    ("RGI_Emoji_ZWJ_Sequence", "RGI_Emoji_ZWJ_Sequence", V13_0),
+];
@@ -0,0 +1,246 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+//! Error messages for invalid property escapes, with suggestions for what was meant.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::properties::{canonical_property_name, lookup, property_names, value_table};
+use super::property_tables::*;
+use super::UnicodeVersion;
+
+/// The most suggestions one error message lists.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Explains why `\p{name=value}` is invalid in `version`.
+pub fn property_error(version: UnicodeVersion, name: &str, value: &str) -> String {
+    let canonical = match canonical_property_name(name) {
+        Some(canonical) => canonical,
+        None => {
+            if lookup(BIN_PROPERTY_PATTERNS, UnicodeVersion::LATEST, name).is_some() {
+                return format!("Property {} does not take a value", name);
+            }
+            let mut candidates = Vec::new();
+            for &(n, _) in property_names() {
+                candidates.push(Candidate::new(None, n, n));
+            }
+            return with_suggestions(format!("Invalid property name {}", name), name, candidates);
+        }
+    };
+
+    let table = value_table(canonical).unwrap_or(&[]);
+    if let Some(&(_, _, since)) = lookup(table, UnicodeVersion::LATEST, value) {
+        return newer_error(&format!("{}={}", name, value), since, version);
+    }
+    let mut candidates = Vec::new();
+    push_values(&mut candidates, None, table, version);
+    if canonical == "General_Category" {
+        push_values(&mut candidates, Some("Script"), SC_VALUE_PATTERNS, version);
+    } else {
+        push_values(&mut candidates, Some("General_Category"), GC_VALUE_PATTERNS, version);
+    }
+    return with_suggestions(
+        format!("Invalid property value {} for {}", value, canonical),
+        value,
+        candidates,
+    );
+}
+
+/// Explains why `\p{value}` is invalid in `version`.
+pub fn lone_property_error(version: UnicodeVersion, value: &str) -> String {
+    if let Some(canonical) = canonical_property_name(value) {
+        return format!("Property {} requires a value", canonical);
+    }
+    for table in &[GC_VALUE_PATTERNS, BIN_PROPERTY_PATTERNS, STRING_PROPERTY_PATTERNS] {
+        if let Some(&(_, _, since)) = lookup(table, UnicodeVersion::LATEST, value) {
+            return newer_error(value, since, version);
+        }
+    }
+    let mut candidates = Vec::new();
+    push_values(&mut candidates, None, GC_VALUE_PATTERNS, version);
+    push_values(&mut candidates, None, BIN_PROPERTY_PATTERNS, version);
+    push_values(&mut candidates, Some("Script"), SC_VALUE_PATTERNS, version);
+    return with_suggestions(format!("Invalid property name {}", value), value, candidates);
+}
+
+fn newer_error(expression: &str, since: UnicodeVersion, version: UnicodeVersion) -> String {
+    format!(
+        "Property {} requires Unicode {} (configured: Unicode {})",
+        expression, since, version
+    )
+}
+
+/// A name that might have been meant, optionally as the value of another property.
+struct Candidate {
+    property: Option<&'static str>,
+    name: &'static str,
+    canonical: &'static str,
+}
+
+impl Candidate {
+    fn new(property: Option<&'static str>, name: &'static str, canonical: &'static str) -> Self {
+        Candidate {
+            property,
+            name,
+            canonical,
+        }
+    }
+
+    fn render(&self) -> String {
+        match self.property {
+            Some(property) => format!("{}={}", property, self.name),
+            None => String::from(self.name),
+        }
+    }
+}
+
+fn push_values(
+    candidates: &mut Vec<Candidate>,
+    property: Option<&'static str>,
+    table: &'static [(&'static str, &'static str, UnicodeVersion)],
+    version: UnicodeVersion,
+) {
+    for &(name, canonical, since) in table {
+        if since <= version {
+            candidates.push(Candidate::new(property, name, canonical));
+        }
+    }
+}
+
+/// Appends the candidates closest to `input` to `message`, if any are close enough.
+/// Names that only differ in case, `_`, `-` and spaces are closest; otherwise up to one edit
+/// per three characters of `input` is allowed. Only one name of each canonical name is listed.
+fn with_suggestions(message: String, input: &str, candidates: Vec<Candidate>) -> String {
+    let input = loose(input);
+    let limit = (input.len() / 3).min(3);
+    let mut scored: Vec<(usize, &Candidate)> = candidates
+        .iter()
+        .map(|candidate| (edit_distance(&input, &loose(candidate.name)), candidate))
+        .filter(|&(distance, _)| distance <= limit)
+        .collect();
+    scored.sort_by_key(|&(distance, candidate)| (distance, candidate.property, candidate.name));
+
+    let mut suggestions: Vec<&Candidate> = Vec::new();
+    for (_, candidate) in scored {
+        let seen = suggestions
+            .iter()
+            .any(|s| s.property == candidate.property && s.canonical == candidate.canonical);
+        if !seen {
+            suggestions.push(candidate);
+        }
+        if suggestions.len() == MAX_SUGGESTIONS {
+            break;
+        }
+    }
+
+    match suggestions.len() {
+        0 => message,
+        1 => format!("{} (did you mean {}?)", message, suggestions[0].render()),
+        n => {
+            let rest: Vec<String> = suggestions[..n - 1].iter().map(|s| s.render()).collect();
+            format!(
+                "{} (did you mean {} or {}?)",
+                message,
+                rest.join(", "),
+                suggestions[n - 1].render()
+            )
+        }
+    }
+}
+
+/// Folds a name as UAX #44 loose matching does: ignore case, `_`, `-` and spaces.
+fn loose(name: &str) -> Vec<char> {
+    name.chars()
+        .filter(|c| !matches!(c, '_' | '-' | ' '))
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// The Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unicode::UnicodeVersion::*;
+
+    #[test]
+    fn edit_distance_test() {
+        let d = |a: &str, b: &str| edit_distance(&loose(a), &loose(b));
+        assert_eq!(d("Greek", "greek"), 0);
+        assert_eq!(d("Script_Extensions", "scriptextensions"), 0);
+        assert_eq!(d("Scrpt", "Script"), 1);
+        assert_eq!(d("kitten", "sitting"), 3);
+        assert_eq!(d("", "abc"), 3);
+    }
+
+    #[test]
+    fn value_errors() {
+        assert_eq!(
+            property_error(V16_0, "Script", "greek"),
+            "Invalid property value greek for Script (did you mean Greek?)"
+        );
+        assert_eq!(
+            property_error(V16_0, "General_Category", "Hiragana"),
+            "Invalid property value Hiragana for General_Category (did you mean Script=Hiragana?)"
+        );
+        assert_eq!(
+            property_error(V14_0, "sc", "Kawi"),
+            "Property sc=Kawi requires Unicode 15.0 (configured: Unicode 14.0)"
+        );
+        assert_eq!(
+            property_error(V16_0, "Script", "Xyzzy"),
+            "Invalid property value Xyzzy for Script"
+        );
+    }
+
+    #[test]
+    fn name_errors() {
+        assert_eq!(
+            property_error(V16_0, "Scrpt", "Greek"),
+            "Invalid property name Scrpt (did you mean Script?)"
+        );
+        assert_eq!(
+            property_error(V16_0, "Alpha", "Yes"),
+            "Property Alpha does not take a value"
+        );
+    }
+
+    #[test]
+    fn lone_errors() {
+        assert_eq!(
+            lone_property_error(V16_0, "letter"),
+            "Invalid property name letter (did you mean Letter?)"
+        );
+        assert_eq!(
+            lone_property_error(V16_0, "Letters"),
+            "Invalid property name Letters (did you mean Letter?)"
+        );
+        assert_eq!(
+            lone_property_error(V16_0, "Greek"),
+            "Invalid property name Greek (did you mean Script=Greek?)"
+        );
+        assert_eq!(
+            lone_property_error(V16_0, "Script"),
+            "Property Script requires a value"
+        );
+        assert_eq!(
+            lone_property_error(V10_0, "Extended_Pictographic"),
+            "Property Extended_Pictographic requires Unicode 11.0 (configured: Unicode 10.0)"
+        );
+    }
+}
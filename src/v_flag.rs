@@ -0,0 +1,220 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::validator::EcmaRegexValidator;
+
+/// Something that has to change when a pattern moves from the `u` to the `v` flag.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum VFlagChange {
+    /// A class character that is syntax with the `v` flag and has to be escaped:
+    /// `(`, `)`, `[`, `{`, `}`, `/`, `-` or `|`.
+    ClassSyntaxCharacter,
+    /// The first character of a double punctuator like `&&` or `!!` in a class, which is
+    /// reserved with the `v` flag and has to be escaped.
+    DoublePunctuator,
+    /// `\P{...}`, or a negated class containing a negated escape, with the `i` flag. The `v`
+    /// flag applies case folding before negating, so it can match different characters.
+    NegatedCaseFolding,
+}
+
+impl VFlagChange {
+    /// Whether escaping the character is enough to keep the meaning of the pattern.
+    pub fn is_escape(self) -> bool {
+        self != VFlagChange::NegatedCaseFolding
+    }
+}
+
+impl fmt::Display for VFlagChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            VFlagChange::ClassSyntaxCharacter => "class character must be escaped with the v flag",
+            VFlagChange::DoublePunctuator => "double punctuator is reserved with the v flag",
+            VFlagChange::NegatedCaseFolding => {
+                "negated class case-folds differently with the v flag"
+            }
+        })
+    }
+}
+
+/// Returns whether a class contains `\P{...}`, `\D`, `\S` or `\W`. Only these make a negated
+/// class case-fold differently: the `v` flag takes the complement over the characters that
+/// simple case folding leaves unchanged, which plain characters and ranges do not notice.
+pub(crate) fn has_negated_escape(class: &[u16]) -> bool {
+    let mut units = class.iter();
+    while let Some(&unit) = units.next() {
+        if unit == '\\' as u16 {
+            let negated = units
+                .next()
+                .is_some_and(|&u| ['P', 'D', 'S', 'W'].iter().any(|&c| u == c as u16));
+            if negated {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+/// A place in a pattern that has to change for the `v` flag.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct VFlagFinding {
+    pub change: VFlagChange,
+    /// The position in the pattern, counted in UTF-16 code units.
+    pub span: Range<usize>,
+}
+
+/// What has to change to move a pattern from the `u` to the `v` flag.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VFlagAnalysis {
+    /// Every place that has to change, in the order they appear.
+    pub findings: Vec<VFlagFinding>,
+    /// The pattern rewritten to mean the same with the `v` flag, unless a finding can not be
+    /// fixed by escaping.
+    pub rewrite: Option<String>,
+}
+
+impl EcmaRegexValidator {
+    /// Analyses what has to change to replace the `u` flag of the regular expression literal
+    /// `/source/flags` with the `v` flag. Returns an error if the flags do not contain `u` or
+    /// the regular expression is invalid.
+    pub fn analyze_v_flag(&mut self, source: &str, flags: &str) -> Result<VFlagAnalysis, String> {
+        self.validate_flags(flags)?;
+        if !flags.contains('u') {
+            return Err("The regular expression does not use the u flag".into());
+        }
+
+        self.v_changes = Some(Vec::new());
+        let result = self.validate_pattern(source, true);
+        let mut findings = self.v_changes.take().unwrap_or_default();
+        result?;
+        if !flags.contains('i') {
+            findings.retain(|finding| finding.change != VFlagChange::NegatedCaseFolding);
+        }
+        findings.sort_by_key(|finding| (finding.span.start, finding.span.end, finding.change));
+        findings.dedup();
+
+        let mut rewrite = None;
+        if findings.iter().all(|finding| finding.change.is_escape()) {
+            let units: Vec<u16> = source.encode_utf16().collect();
+            let mut escaped = Vec::with_capacity(units.len() + findings.len());
+            let mut last = 0;
+            for finding in &findings {
+                escaped.extend_from_slice(&units[last..finding.span.start]);
+                escaped.push('\\' as u16);
+                last = finding.span.start;
+            }
+            escaped.extend_from_slice(&units[last..]);
+            rewrite = String::from_utf16(&escaped).ok();
+        }
+        return Ok(VFlagAnalysis { findings, rewrite });
+    }
+
+    /// Records that the characters in `span` have to change for the `v` flag, if
+    /// `analyze_v_flag` asked for it.
+    pub(crate) fn record_v_change(&mut self, change: VFlagChange, span: Range<usize>) {
+        if let Some(findings) = &mut self.v_changes {
+            findings.push(VFlagFinding { change, span });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EcmaVersion;
+
+    fn analyze(source: &str, flags: &str) -> VFlagAnalysis {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        validator.analyze_v_flag(source, flags).unwrap()
+    }
+
+    #[test]
+    fn unchanged() {
+        let analysis = analyze("[a-z\\d]+(foo|bar)\\/", "u");
+        assert_eq!(analysis.findings, vec![]);
+        assert_eq!(analysis.rewrite.as_deref(), Some("[a-z\\d]+(foo|bar)\\/"));
+        assert_eq!(analyze("[^a]", "u").findings, vec![]);
+    }
+
+    #[test]
+    fn escapes() {
+        let analysis = analyze("[(a)|/{}[-]", "u");
+        assert_eq!(analysis.findings.len(), 8);
+        assert!(analysis
+            .findings
+            .iter()
+            .all(|f| f.change == VFlagChange::ClassSyntaxCharacter));
+        assert_eq!(
+            analysis.rewrite.as_deref(),
+            Some("[\\(a\\)\\|\\/\\{\\}\\[\\-]")
+        );
+        assert_eq!(analyze("[a-]", "u").rewrite.as_deref(), Some("[a\\-]"));
+        assert_eq!(analyze("[^-a]", "u").rewrite.as_deref(), Some("[^\\-a]"));
+        assert_eq!(analyze("[^- ]", "u").rewrite.as_deref(), Some("[^\\- ]"));
+    }
+
+    #[test]
+    fn double_punctuators() {
+        let analysis = analyze("[&&]", "u");
+        assert_eq!(
+            analysis.findings,
+            vec![VFlagFinding {
+                change: VFlagChange::DoublePunctuator,
+                span: 1..2,
+            }]
+        );
+        assert_eq!(analysis.rewrite.as_deref(), Some("[\\&&]"));
+        assert_eq!(
+            analyze("[a!!!]", "u").rewrite.as_deref(),
+            Some("[a\\!\\!!]")
+        );
+        assert_eq!(analyze("[^^]", "u").rewrite.as_deref(), Some("[^^]"));
+        assert_eq!(analyze("&&", "u").rewrite.as_deref(), Some("&&"));
+    }
+
+    #[test]
+    fn case_folding() {
+        assert_eq!(analyze("[^a]", "ui").rewrite.as_deref(), Some("[^a]"));
+        assert_eq!(analyze("[^a-z]", "ui").findings, vec![]);
+        assert_eq!(analyze("[^a]", "u").rewrite.as_deref(), Some("[^a]"));
+        assert_eq!(
+            analyze("[^\\Wa]", "ui").findings,
+            vec![VFlagFinding {
+                change: VFlagChange::NegatedCaseFolding,
+                span: 0..6,
+            }]
+        );
+        assert_eq!(
+            analyze("[^\\s\\d]", "ui").rewrite.as_deref(),
+            Some("[^\\s\\d]")
+        );
+        assert_eq!(
+            analyze("[^\\\\S]", "ui").rewrite.as_deref(),
+            Some("[^\\\\S]")
+        );
+        assert_eq!(analyze("[^\\D]", "ui").rewrite, None);
+        assert_eq!(analyze("[\\S]", "ui").rewrite.as_deref(), Some("[\\S]"));
+    }
+
+    #[cfg(feature = "unicode-properties")]
+    #[test]
+    fn negated_property_escapes() {
+        assert_eq!(analyze("\\P{Ll}", "ui").rewrite, None);
+        assert_eq!(analyze("\\P{Ll}", "u").rewrite.as_deref(), Some("\\P{Ll}"));
+        assert_eq!(
+            analyze("[^\\p{Ll}]", "ui").rewrite.as_deref(),
+            Some("[^\\p{Ll}]")
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        assert!(validator.analyze_v_flag("a", "g").is_err());
+        assert!(validator.analyze_v_flag("\\a", "u").is_err());
+    }
+}
@@ -1,80 +1,115 @@
 // Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
 // Distributed under terms of the MIT license.
 
-use std::collections::HashSet;
-use std::ops::{Deref, DerefMut};
-
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Deref, DerefMut, Range};
+
+use crate::annex_b::{AnnexB, AnnexBUsage};
+use crate::control::ControlCharacter;
+use crate::duplicates::{ClassMember, ClassMembers};
+use crate::empty::{EmptyConstruct, EmptyKind};
+use crate::features::Feature;
+use crate::misleading::MisleadingClass;
 use crate::reader::Reader;
+use crate::suspicious::{range_out_of_order, SuspiciousRange};
+use crate::u_flag::{UFlagChange, UFlagFinding};
 use crate::unicode::*;
+use crate::v_flag::{has_negated_escape, VFlagChange, VFlagFinding};
+
+pub(crate) fn is_syntax_character(cp: u32) -> bool {
+    return cp == '^' as u32
+        || cp == '$' as u32
+        || cp == '\\' as u32
+        || cp == '.' as u32
+        || cp == '*' as u32
+        || cp == '+' as u32
+        || cp == '?' as u32
+        || cp == '(' as u32
+        || cp == ')' as u32
+        || cp == '[' as u32
+        || cp == ']' as u32
+        || cp == '{' as u32
+        || cp == '}' as u32
+        || cp == '|' as u32;
+}
+
+fn is_latin_letter(cp: u32) -> bool {
+    (0x41..=0x5a).contains(&cp) || (0x61..=0x7a).contains(&cp)
+}
+
+fn is_decimal_digit(cp: u32) -> bool {
+    (0x30..=0x39).contains(&cp)
+}
+
+fn is_hex_digit(cp: u32) -> bool {
+    is_decimal_digit(cp) || (0x41..=0x46).contains(&cp) || (0x61..=0x66).contains(&cp)
+}
+
+fn is_octal_digit(cp: u32) -> bool {
+    (0x30..=0x37).contains(&cp)
+}
 
-fn is_syntax_character(cp: char) -> bool {
-    return cp == '^'
-        || cp == '$'
-        || cp == '\\'
-        || cp == '.'
-        || cp == '*'
-        || cp == '+'
-        || cp == '?'
-        || cp == '('
-        || cp == ')'
-        || cp == '['
-        || cp == ']'
-        || cp == '{'
-        || cp == '}'
-        || cp == '|';
+fn digit_value(cp: u32) -> i64 {
+    if is_decimal_digit(cp) {
+        (cp - 0x30) as i64
+    } else {
+        ((cp | 0x20) - 0x61 + 10) as i64
+    }
 }
 
-fn is_unicode_property_name_character(cp: char) -> bool {
-    cp.is_ascii_alphabetic() || cp == '_'
+fn is_unicode_property_name_character(cp: u32) -> bool {
+    is_latin_letter(cp) || cp == '_' as u32
 }
 
-fn is_unicode_property_value_character(cp: char) -> bool {
-    is_unicode_property_name_character(cp) || cp.is_digit(10)
+fn is_unicode_property_value_character(cp: u32) -> bool {
+    is_unicode_property_name_character(cp) || is_decimal_digit(cp)
 }
 
-fn is_regexp_identifier_start(cp: char) -> bool {
-    is_id_start(cp) || cp == '$' || cp == '_'
+fn is_regexp_identifier_start(cp: u32, version: UnicodeVersion) -> bool {
+    is_id_start(cp, version) || cp == '$' as u32 || cp == '_' as u32
 }
 
-fn is_regexp_identifier_part(cp: char) -> bool {
-    is_id_continue(cp) ||
-    cp == '$' ||
-    cp == '_' ||
-    cp == '\u{200c}' ||  // unicode zero-width non-joiner
-    cp == '\u{200d}' // unicode zero-width joiner
+fn is_regexp_identifier_part(cp: u32, version: UnicodeVersion) -> bool {
+    is_id_continue(cp, version) ||
+    cp == '$' as u32 ||
+    cp == '_' as u32 ||
+    cp == '\u{200c}' as u32 ||  // unicode zero-width non-joiner
+    cp == '\u{200d}' as u32 // unicode zero-width joiner
 }
 
-fn is_id_start(cp: char) -> bool {
-    if (cp as u32) < 0x41 {
+fn is_id_start(cp: u32, version: UnicodeVersion) -> bool {
+    if cp < 0x41 {
         false
-    } else if (cp as u32) < 0x5b {
+    } else if cp < 0x5b {
         true
-    } else if (cp as u32) < 0x61 {
+    } else if cp < 0x61 {
         false
-    } else if (cp as u32) < 0x7b {
+    } else if cp < 0x7b {
         true
     } else {
-        is_large_id_start(cp)
+        is_large_id_start(cp, version)
     }
 }
 
-fn is_id_continue(cp: char) -> bool {
-    if (cp as u32) < 0x30 {
+fn is_id_continue(cp: u32, version: UnicodeVersion) -> bool {
+    if cp < 0x30 {
         false
-    } else if (cp as u32) < 0x3a {
+    } else if cp < 0x3a {
         true
-    } else if (cp as u32) < 0x41 {
+    } else if cp < 0x41 {
         false
-    } else if (cp as u32) < 0x5b {
+    } else if cp < 0x5b || cp == 0x5f {
         true
-    } else if (cp as u32) == 0x5f {
-        true
-    } else if (cp as u32) < 0x61 {
+    } else if cp < 0x61 {
         false
-    } else if (cp as u32) < 0x7b {
+    } else if cp < 0x7b {
         true
     } else {
-        is_large_id_start(cp) || is_large_id_continue(cp)
+        is_large_id_start(cp, version) || is_large_id_continue(cp, version)
     }
 }
 
@@ -83,11 +118,11 @@ fn is_valid_unicode(cp: i64) -> bool {
 }
 
 fn is_lead_surrogate(cp: i64) -> bool {
-    cp >= 0xd800 && cp <= 0xdbff
+    (0xd800..=0xdbff).contains(&cp)
 }
 
 fn is_trail_surrogate(cp: i64) -> bool {
-    cp >= 0xdc00 && cp <= 0xdfff
+    (0xdc00..=0xdfff).contains(&cp)
 }
 
 fn combine_surrogate_pair(lead: i64, trail: i64) -> i64 {
@@ -104,6 +139,31 @@ pub enum EcmaVersion {
     ES2019,
     ES2020,
     ES2021,
+    ES2022,
+}
+
+impl EcmaVersion {
+    /// All editions, oldest first.
+    pub const ALL: &'static [EcmaVersion] = &[
+        EcmaVersion::ES5,
+        EcmaVersion::ES2015,
+        EcmaVersion::ES2016,
+        EcmaVersion::ES2017,
+        EcmaVersion::ES2018,
+        EcmaVersion::ES2019,
+        EcmaVersion::ES2020,
+        EcmaVersion::ES2021,
+        EcmaVersion::ES2022,
+    ];
+
+    /// The newest edition the validator knows.
+    pub const LATEST: EcmaVersion = EcmaVersion::ES2022;
+}
+
+impl fmt::Display for EcmaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
 }
 
 #[derive(Debug)]
@@ -111,6 +171,7 @@ pub struct EcmaRegexValidator {
     reader: Reader,
     strict: bool,
     ecma_version: EcmaVersion,
+    unicode_version: UnicodeVersion,
     u_flag: bool,
     n_flag: bool,
     last_int_value: i64,
@@ -121,8 +182,20 @@ pub struct EcmaRegexValidator {
     last_val_value: String,
     last_assertion_is_quantifiable: bool,
     num_capturing_parens: u32,
-    group_names: HashSet<String>,
-    backreference_names: HashSet<String>,
+    group_names: BTreeSet<String>,
+    backreference_names: BTreeSet<String>,
+    features: BTreeSet<Feature>,
+    pub(crate) annex_b: Option<Vec<AnnexBUsage>>,
+    pub(crate) u_changes: Option<Vec<UFlagFinding>>,
+    pub(crate) v_changes: Option<Vec<VFlagFinding>>,
+    pub(crate) misleading: Option<Vec<MisleadingClass>>,
+    pub(crate) control_characters: Option<Vec<ControlCharacter>>,
+    pub(crate) empty_constructs: Option<Vec<EmptyConstruct>>,
+    pub(crate) identity_escapes: Option<Vec<(u32, Range<usize>)>>,
+    pub(crate) suspicious_ranges: Option<Vec<SuspiciousRange>>,
+    pub(crate) class_chars: Vec<Option<(u32, Range<usize>)>>,
+    pub(crate) class_member_lists: Option<Vec<ClassMembers>>,
+    pub(crate) class_members: Vec<ClassMember>,
 }
 
 impl Deref for EcmaRegexValidator {
@@ -145,6 +218,7 @@ impl EcmaRegexValidator {
             reader: Reader::new(),
             strict: false,
             ecma_version,
+            unicode_version: UnicodeVersion::of_ecma_version(ecma_version),
             u_flag: false,
             n_flag: false,
             last_int_value: 0,
@@ -155,46 +229,106 @@ impl EcmaRegexValidator {
             last_val_value: "".to_string(),
             last_assertion_is_quantifiable: false,
             num_capturing_parens: 0,
-            group_names: HashSet::new(),
-            backreference_names: HashSet::new(),
+            group_names: BTreeSet::new(),
+            backreference_names: BTreeSet::new(),
+            features: BTreeSet::new(),
+            annex_b: None,
+            u_changes: None,
+            v_changes: None,
+            misleading: None,
+            control_characters: None,
+            empty_constructs: None,
+            identity_escapes: None,
+            suspicious_ranges: None,
+            class_chars: Vec::new(),
+            class_member_lists: None,
+            class_members: Vec::new(),
         }
     }
 
+    /// Returns the ECMAScript version this validator was created for.
+    pub fn ecma_version(&self) -> EcmaVersion {
+        self.ecma_version
+    }
+
+    /// Returns the Unicode version used for property escapes and group names.
+    pub fn unicode_version(&self) -> UnicodeVersion {
+        self.unicode_version
+    }
+
+    /// Sets the Unicode version used for property escapes and group names.
+    /// Defaults to the version the `EcmaVersion` requires, but engines often ship newer
+    /// Unicode data than that, e.g. Node 18 ships Unicode 15.0.
+    pub fn set_unicode_version(&mut self, unicode_version: UnicodeVersion) {
+        self.unicode_version = unicode_version;
+    }
+
     /// Validates flags of a EcmaScript regular expression.
     pub fn validate_flags(&self, flags: &str) -> Result<(), String> {
-        let mut existing_flags = HashSet::<char>::new();
-
-        for flag in flags.chars() {
-            if existing_flags.contains(&flag) {
+        for (i, flag) in flags.char_indices() {
+            if flags[..i].contains(flag) {
                 return Err(format!("Duplicated flag {}", flag));
             }
-            existing_flags.insert(flag);
 
-            if flag == 'g'
-                || flag == 'i'
-                || flag == 'm'
-                || (flag == 'u' && self.ecma_version >= EcmaVersion::ES2015)
-                || (flag == 'y' && self.ecma_version >= EcmaVersion::ES2015)
-                || (flag == 's' && self.ecma_version >= EcmaVersion::ES2018)
-            {
-                // do nothing
-            } else {
-                return Err(format!("Invalid flag {}", flag));
+            let required = match flag {
+                'g' | 'i' | 'm' => EcmaVersion::ES5,
+                'u' | 'y' => EcmaVersion::ES2015,
+                's' => EcmaVersion::ES2018,
+                'd' => EcmaVersion::ES2022,
+                _ => return Err(format!("Invalid flag {}", flag)),
+            };
+            if self.ecma_version < required {
+                return Err(self.version_error(&format!("The {} flag requires", flag), required));
             }
         }
         Ok(())
     }
 
+    /// Returns the features of the last validated pattern that are newer than ES5.
+    pub(crate) fn used_features(&self) -> &BTreeSet<Feature> {
+        &self.features
+    }
+
+    /// Records that the pattern relies on Unicode data introduced in `version`, keeping only
+    /// the newest version in `self.features`.
+    fn record_unicode_data(&mut self, version: UnicodeVersion) {
+        match self.features.iter().next_back() {
+            Some(&Feature::UnicodeData(newest)) if newest >= version => {}
+            Some(&Feature::UnicodeData(newest)) => {
+                self.features.remove(&Feature::UnicodeData(newest));
+                self.features.insert(Feature::UnicodeData(version));
+            }
+            _ => {
+                self.features.insert(Feature::UnicodeData(version));
+            }
+        }
+    }
+
+    /// Returns the number of capturing groups of the last validated pattern.
+    pub(crate) fn capturing_group_count(&self) -> u32 {
+        self.num_capturing_parens
+    }
+
+    /// Returns whether `\k` starts a named backreference in the last validated pattern.
+    pub(crate) fn has_named_references(&self) -> bool {
+        self.n_flag
+    }
+
+    /// Explains that a feature is newer than the configured `EcmaVersion`.
+    /// `feature` names it and ends in the verb, e.g. "Lookbehind assertions require".
+    fn version_error(&self, feature: &str, required: EcmaVersion) -> String {
+        format!("{} {} (configured: {})", feature, required, self.ecma_version)
+    }
+
     /// Validates the pattern of a EcmaScript regular expression.
     pub fn validate_pattern(&mut self, source: &str, u_flag: bool) -> Result<(), String> {
         self.strict = u_flag; // TODO: allow toggling strict independently of u flag
         self.u_flag = u_flag && self.ecma_version >= EcmaVersion::ES2015;
         self.n_flag = u_flag && self.ecma_version >= EcmaVersion::ES2018;
-        //self.reset(source, 0, source.len(), u_flag);
-        self.reset(source, 0, source.chars().count(), u_flag);
+        self.reset(source, 0, source.encode_utf16().count(), u_flag);
         self.consume_pattern()?;
 
-        if !self.n_flag && self.ecma_version >= EcmaVersion::ES2018 && self.group_names.len() > 0 {
+        if !self.n_flag && self.ecma_version >= EcmaVersion::ES2018 && !self.group_names.is_empty() {
             self.n_flag = true;
             self.rewind(0);
             self.consume_pattern()?;
@@ -209,24 +343,55 @@ impl EcmaRegexValidator {
     ///     Disjunction[?U, ?N]
     /// ```
     fn consume_pattern(&mut self) -> Result<(), String> {
+        // Validators are reused across patterns by `validate_many`, so everything that
+        // belongs to one pattern has to be cleared here.
         self.num_capturing_parens = self.count_capturing_parens();
         self.group_names.clear();
         self.backreference_names.clear();
+        self.features.clear();
+        if let Some(usages) = &mut self.annex_b {
+            usages.clear();
+        }
+        if let Some(findings) = &mut self.u_changes {
+            findings.clear();
+        }
+        if let Some(findings) = &mut self.v_changes {
+            findings.clear();
+        }
+        if let Some(findings) = &mut self.misleading {
+            findings.clear();
+        }
+        if let Some(found) = &mut self.control_characters {
+            found.clear();
+        }
+        if let Some(found) = &mut self.empty_constructs {
+            found.clear();
+        }
+        if let Some(escapes) = &mut self.identity_escapes {
+            escapes.clear();
+        }
+        if let Some(lists) = &mut self.class_member_lists {
+            lists.clear();
+        }
+        if let Some(found) = &mut self.suspicious_ranges {
+            found.clear();
+        }
 
         self.consume_disjunction()?;
 
         if let Some(cp) = self.code_point_with_offset(0) {
-            if cp == ')' {
+            if cp == ')' as u32 {
                 return Err("Unmatched ')'".to_string());
-            } else if cp == '\\' {
+            } else if cp == '\\' as u32 {
                 return Err("\\ at end of pattern".to_string());
-            } else if cp == ']' || cp == '}' {
+            } else if cp == ']' as u32 || cp == '}' as u32 {
                 return Err("Lone quantifier brackets".to_string());
             }
+            let cp = core::char::from_u32(cp).unwrap_or(core::char::REPLACEMENT_CHARACTER);
             return Err(format!("Unexpected character {}", cp));
         }
 
-        for name in self.backreference_names.difference(&self.group_names) {
+        if let Some(name) = self.backreference_names.difference(&self.group_names).next() {
             return Err(format!("Invalid named capture referenced: {}", name));
         }
         return Ok(());
@@ -239,10 +404,19 @@ impl EcmaRegexValidator {
     ///      Alternative[?U, ?N] `|` Disjunction[?U, ?N]
     /// ```
     fn consume_disjunction(&mut self) -> Result<(), String> {
-        self.consume_alternative()?;
-        while self.eat('|') {
+        let recording = self.empty_constructs.is_some();
+        let mut alternatives = Vec::new();
+        loop {
+            let start = self.index();
             self.consume_alternative()?;
+            if recording {
+                alternatives.push(start..self.index());
+            }
+            if !self.eat('|') {
+                break;
+            }
         }
+        self.record_empty_alternatives(&alternatives);
 
         if self.consume_quantifier(true)? {
             return Err("Nothing to repeat".to_string());
@@ -285,9 +459,14 @@ impl EcmaRegexValidator {
             return Ok(self.consume_assertion()?
                 || (self.consume_atom()? && self.consume_optional_quantifier()?));
         }
-        return Ok((self.consume_assertion()?
-            && (!self.last_assertion_is_quantifiable || self.consume_optional_quantifier()?))
-            || (self.consume_extended_atom()? && self.consume_optional_quantifier()?));
+        let start = self.index();
+        if self.consume_assertion()? {
+            if self.last_assertion_is_quantifiable && self.consume_quantifier(false)? {
+                self.record_annex_b(AnnexB::QuantifiedLookahead, start);
+            }
+            return Ok(true);
+        }
+        return Ok(self.consume_extended_atom()? && self.consume_optional_quantifier()?);
     }
 
     fn consume_optional_quantifier(&mut self) -> Result<bool, String> {
@@ -320,23 +499,44 @@ impl EcmaRegexValidator {
         let start = self.index();
         self.last_assertion_is_quantifiable = false;
 
-        if self.eat('^') || self.eat('$') || self.eat2('\\', 'B') || self.eat2('\\', 'b') {
+        if self.eat('^') || self.eat('$') {
+            return Ok(true);
+        }
+        if self.eat2('\\', 'B') || self.eat2('\\', 'b') {
+            self.record_u_change(UFlagChange::CaseFolding, start..self.index());
             return Ok(true);
         }
 
         // Lookahead / Lookbehind
         if self.eat2('(', '?') {
-            let lookbehind = self.ecma_version >= EcmaVersion::ES2018 && self.eat('<');
-            let mut flag = self.eat('=');
-            if !flag {
-                flag = self.eat('!');
+            let next = self.code_point_with_offset(1);
+            if self.ecma_version < EcmaVersion::ES2018
+                && self.code_point_with_offset(0) == Some('<' as u32)
+                && (next == Some('=' as u32) || next == Some('!' as u32))
+            {
+                let feature = "Lookbehind assertions require";
+                return Err(self.version_error(feature, EcmaVersion::ES2018));
             }
-            if flag {
+            let lookbehind = self.ecma_version >= EcmaVersion::ES2018 && self.eat('<');
+            let positive = self.eat('=');
+            if positive || self.eat('!') {
+                let body = self.index();
                 self.consume_disjunction()?;
                 if !self.eat(')') {
                     return Err("Unterminated group".to_string());
                 }
+                if self.index() == body + 1 {
+                    if positive {
+                        self.record_empty(EmptyKind::EmptyLookaround, start, Some(""));
+                    } else {
+                        let kind = EmptyKind::EmptyNegativeLookaround;
+                        self.record_empty(kind, start, Some("[^\\s\\S]"));
+                    }
+                }
                 self.last_assertion_is_quantifiable = !lookbehind && !self.strict;
+                if lookbehind {
+                    self.features.insert(Feature::Lookbehind);
+                }
                 return Ok(true);
             }
             self.rewind(start);
@@ -437,7 +637,9 @@ impl EcmaRegexValidator {
     /// ```
     /// Returns `true` if it consumed the next characters successfully.
     fn consume_dot(&mut self) -> bool {
+        let start = self.index();
         if self.eat('.') {
+            self.record_u_change(UFlagChange::Dot, start..self.index());
             return true;
         }
         return false;
@@ -465,11 +667,15 @@ impl EcmaRegexValidator {
     /// ```
     /// Returns `true` if it consumed the next characters successfully.
     fn consume_uncapturing_group(&mut self) -> Result<bool, String> {
+        let start = self.index();
         if self.eat3('(', '?', ':') {
             self.consume_disjunction()?;
             if !self.eat(')') {
                 return Err("Unterminated group".to_string());
             }
+            if self.index() == start + 4 {
+                self.record_empty(EmptyKind::EmptyGroup, start, Some(""));
+            }
             return Ok(true);
         }
         return Ok(false);
@@ -481,20 +687,28 @@ impl EcmaRegexValidator {
     /// ```
     /// Returns `true` if it consumed the next characters successfully.
     fn consume_capturing_group(&mut self) -> Result<bool, String> {
+        let start = self.index();
         if !self.eat('(') {
             return Ok(false);
         }
 
         if self.ecma_version >= EcmaVersion::ES2018 {
             self.consume_group_specifier()?;
-        } else if self.code_point_with_offset(0) == Some('?') {
+        } else if self.code_point_with_offset(0) == Some('?' as u32) {
+            if self.code_point_with_offset(1) == Some('<' as u32) {
+                return Err(self.version_error("Named capture groups require", EcmaVersion::ES2018));
+            }
             return Err("Invalid group".to_string());
         }
 
+        let body = self.index();
         self.consume_disjunction()?;
         if !self.eat(')') {
             return Err("Unterminated group".to_string());
         }
+        if self.index() == body + 1 {
+            self.record_empty(EmptyKind::EmptyGroup, start, None);
+        }
         Ok(true)
     }
 
@@ -512,7 +726,7 @@ impl EcmaRegexValidator {
     /// ```
     /// Returns `true` if it consumed the next characters successfully.
     fn consume_extended_atom(&mut self) -> Result<bool, String> {
-        Ok(self.eat('.')
+        Ok(self.consume_dot()
             || self.consume_reverse_solidus_atom_escape()?
             || self.consume_reverse_solidus_followed_by_c()
             || self.consume_character_class()?
@@ -528,11 +742,13 @@ impl EcmaRegexValidator {
     /// ```
     /// Returns `true` if it consumed the next characters successfully.
     fn consume_reverse_solidus_followed_by_c(&mut self) -> bool {
-        if self.code_point_with_offset(0) == Some('\\')
-            && self.code_point_with_offset(1) == Some('c')
+        if self.code_point_with_offset(0) == Some('\\' as u32)
+            && self.code_point_with_offset(1) == Some('c' as u32)
         {
             self.last_int_value = '\\' as i64;
+            let start = self.index();
             self.advance();
+            self.record_annex_b(AnnexB::LoneControlEscape, start);
             return true;
         }
         return false;
@@ -564,7 +780,9 @@ impl EcmaRegexValidator {
     fn consume_pattern_character(&mut self) -> bool {
         if let Some(cp) = self.code_point_with_offset(0) {
             if !is_syntax_character(cp) {
+                let start = self.index();
                 self.advance();
+                self.record_control_character(cp as i64, start);
                 return true;
             }
         }
@@ -580,19 +798,34 @@ impl EcmaRegexValidator {
     /// Returns `true` if it consumed the next characters successfully.
     fn consume_extended_pattern_character(&mut self) -> bool {
         if let Some(cp) = self.code_point_with_offset(0) {
-            if cp != '^'
-                && cp != '$'
-                && cp != '\\'
-                && cp != '.'
-                && cp != '*'
-                && cp != '+'
-                && cp != '?'
-                && cp != '('
-                && cp != ')'
-                && cp != '['
-                && cp != '|'
+            if cp != '^' as u32
+                && cp != '$' as u32
+                && cp != '\\' as u32
+                && cp != '.' as u32
+                && cp != '*' as u32
+                && cp != '+' as u32
+                && cp != '?' as u32
+                && cp != '(' as u32
+                && cp != ')' as u32
+                && cp != '[' as u32
+                && cp != '|' as u32
             {
+                let start = self.index();
+                if is_lead_surrogate(cp as i64) && self.u_changes.is_some() {
+                    let source = self.source();
+                    let trail = source.get(start + 1).copied();
+                    let quantifier = source.get(start + 2).copied();
+                    if trail.is_some_and(|u| is_trail_surrogate(u as i64))
+                        && quantifier.is_some_and(|u| "*+?{".encode_utf16().any(|q| q == u))
+                    {
+                        self.record_u_change(UFlagChange::AstralCharacter, start..start + 2);
+                    }
+                }
                 self.advance();
+                self.record_control_character(cp as i64, start);
+                if cp == ']' as u32 || cp == '{' as u32 || cp == '}' as u32 {
+                    self.record_annex_b(AnnexB::LoneBracket, start);
+                }
                 return true;
             }
         }
@@ -612,6 +845,7 @@ impl EcmaRegexValidator {
             if self.eat_group_name()? {
                 if !self.group_names.contains(&self.last_str_value) {
                     self.group_names.insert(self.last_str_value.clone());
+                    self.features.insert(Feature::NamedCaptureGroups);
                     return Ok(true);
                 }
                 return Err("Duplicate capture group name".to_string());
@@ -683,6 +917,7 @@ impl EcmaRegexValidator {
     /// ```
     /// Returns `true` if it consumed the next characters successfully.
     fn consume_character_class_escape(&mut self) -> Result<bool, String> {
+        let start = self.index() - 1;
         if self.eat('d')
             || self.eat('D')
             || self.eat('s')
@@ -691,6 +926,13 @@ impl EcmaRegexValidator {
             || self.eat('W')
         {
             self.last_int_value = -1;
+            let escape = self.source()[start + 1];
+            if escape == 'D' as u16 || escape == 'S' as u16 || escape == 'W' as u16 {
+                self.record_u_change(UFlagChange::NegatedClassEscape, start..self.index());
+            }
+            if escape == 'w' as u16 || escape == 'W' as u16 {
+                self.record_u_change(UFlagChange::CaseFolding, start..self.index());
+            }
             return Ok(true);
         }
 
@@ -699,11 +941,25 @@ impl EcmaRegexValidator {
             && (self.eat('p') || self.eat('P'))
         {
             self.last_int_value = -1;
+            if !HAS_PROPERTY_TABLES {
+                return Err("Property escapes are not supported in this build".to_string());
+            }
             if self.eat('{') && self.eat_unicode_property_value_expression()? && self.eat('}') {
+                self.features.insert(Feature::UnicodePropertyEscapes);
+                if self.source()[start + 1] == 'P' as u16 {
+                    self.record_v_change(VFlagChange::NegatedCaseFolding, start..self.index());
+                }
                 return Ok(true);
             }
             return Err("Invalid property name".to_string());
         }
+        let next = self.code_point_with_offset(0);
+        if self.u_flag
+            && self.ecma_version < EcmaVersion::ES2018
+            && (next == Some('p' as u32) || next == Some('P' as u32))
+        {
+            return Err(self.version_error("Unicode property escapes require", EcmaVersion::ES2018));
+        }
         Ok(false)
     }
 
@@ -720,13 +976,54 @@ impl EcmaRegexValidator {
     /// ```
     /// Returns `true` if it consumed the next characters successfully.
     fn consume_character_escape(&mut self) -> Result<bool, String> {
-        Ok(self.eat_control_escape()
-            || self.eat_c_control_letter()
-            || self.eat_zero()
+        let start = self.index() - 1;
+        if self.eat_control_escape() || self.eat_zero() {
+            return Ok(true);
+        }
+        if self.eat_c_control_letter()
             || self.eat_hex_escape_sequence()?
-            || self.eat_regexp_unicode_escape_sequence(false)?
-            || (!self.strict && !self.u_flag && self.eat_legacy_octal_escape_sequence())
-            || self.eat_identity_escape())
+            || self.consume_regexp_unicode_escape_sequence()?
+        {
+            self.record_control_character(self.last_int_value, start);
+            return Ok(true);
+        }
+        Ok(self.consume_legacy_octal_escape_sequence() || self.eat_identity_escape())
+    }
+
+    /// Validate the next characters as a RegExp `RegExpUnicodeEscapeSequence` production if
+    /// possible. Without the `u` flag, an escaped lead surrogate that is directly followed by
+    /// an escaped trail surrogate is noted for `analyze_u_flag`, as the `u` flag combines them.
+    /// Returns `true` if it consumed the next characters successfully.
+    fn consume_regexp_unicode_escape_sequence(&mut self) -> Result<bool, String> {
+        let start = self.index() - 1;
+        if !self.eat_regexp_unicode_escape_sequence(false)? {
+            return Ok(false);
+        }
+        if self.u_changes.is_some() && !self.u_flag && is_lead_surrogate(self.last_int_value) {
+            let (end, lead) = (self.index(), self.last_int_value);
+            if self.eat2('\\', 'u')
+                && self.eat_fixed_hex_digits(4)
+                && is_trail_surrogate(self.last_int_value)
+            {
+                self.record_u_change(UFlagChange::SurrogatePairEscape, start..self.index());
+            }
+            self.rewind(end);
+            self.last_int_value = lead;
+        }
+        Ok(true)
+    }
+
+    /// Validate the next characters as a `LegacyOctalEscapeSequence` if the web compatibility
+    /// grammar allows it.
+    /// Returns `true` if it consumed the next characters successfully.
+    fn consume_legacy_octal_escape_sequence(&mut self) -> bool {
+        let start = self.index() - 1;
+        if !self.strict && !self.u_flag && self.eat_legacy_octal_escape_sequence() {
+            self.record_annex_b(AnnexB::LegacyOctalEscape, start);
+            self.record_control_character(self.last_int_value, start);
+            return true;
+        }
+        return false;
     }
 
     /// Validate the next characters as the follwoing alternatives if possible.
@@ -754,13 +1051,30 @@ impl EcmaRegexValidator {
     /// ```
     /// Returns `true` if it consumed the next characters successfully.
     fn consume_character_class(&mut self) -> Result<bool, String> {
+        let start = self.index();
         if !self.eat('[') {
             return Ok(false);
         }
+        let negated = self.eat('^');
+        self.class_chars.clear();
+        self.class_members.clear();
         self.consume_class_ranges()?;
         if !self.eat(']') {
             return Err("Unterminated character class".to_string());
         }
+        self.check_misleading_class(start..self.index());
+        self.finish_class_members(start..self.index());
+        if self.index() == start + 2 {
+            self.record_empty(EmptyKind::EmptyClass, start, Some("[^\\s\\S]"));
+        } else if negated && self.index() == start + 3 {
+            self.record_empty(EmptyKind::AnyClass, start, Some("[\\s\\S]"));
+        }
+        if negated {
+            self.record_u_change(UFlagChange::NegatedClass, start..self.index());
+            if has_negated_escape(&self.source()[start..self.index()]) {
+                self.record_v_change(VFlagChange::NegatedCaseFolding, start..self.index());
+            }
+        }
         Ok(true)
     }
 
@@ -781,6 +1095,7 @@ impl EcmaRegexValidator {
     fn consume_class_ranges(&mut self) -> Result<(), String> {
         loop {
             // Consume the first ClassAtom
+            let start = self.index();
             if !self.consume_class_atom()? {
                 break;
             }
@@ -788,27 +1103,44 @@ impl EcmaRegexValidator {
 
             // Consume `-`
             if !self.eat('-') {
+                self.record_class_char(Some(min), start..self.index());
+                self.record_class_member(min, min, start..self.index());
                 continue;
             }
 
             // Consume the second ClassAtom
+            let max_start = self.index();
             if !self.consume_class_atom()? {
+                // The `-` was a literal at the end of the class.
+                let dash = self.index() - 1;
+                self.record_v_change(VFlagChange::ClassSyntaxCharacter, dash..dash + 1);
+                self.record_class_char(Some(min), start..dash);
+                self.record_class_char(Some('-' as i64), dash..dash + 1);
+                self.record_class_member(min, min, start..dash);
+                self.record_class_member('-' as i64, '-' as i64, dash..dash + 1);
                 break;
             }
             let max = self.last_int_value;
+            self.record_class_char(None, start..self.index());
+            if min == -1 || max == -1 {
+                self.record_class_member(-2, -2, start..self.index());
+            } else {
+                self.record_class_member(min, max, start..self.index());
+            }
 
             // Validate
             if min == -1 || max == -1 {
                 if self.strict {
                     return Err("Invalid character class".to_string());
                 }
+                self.record_annex_b(AnnexB::ClassEscapeInRange, start);
                 continue;
             }
 
-            println!("min: {},  max: {}", min, max);
             if min > max {
-                return Err("Range out of order in character class".to_string());
+                return Err(range_out_of_order(min, max));
             }
+            self.record_range(min, max, start, max_start);
         }
         Ok(())
     }
@@ -829,9 +1161,19 @@ impl EcmaRegexValidator {
         let start = self.index();
 
         if let Some(cp) = self.code_point_with_offset(0) {
-            if cp != '\\' && cp != ']' {
+            if cp != '\\' as u32 && cp != ']' as u32 {
+                let next = self.source().get(start + 1).copied();
+                if is_lead_surrogate(cp as i64)
+                    && next.is_some_and(|u| is_trail_surrogate(u as i64))
+                {
+                    self.record_u_change(UFlagChange::AstralCharacter, start..start + 2);
+                }
+                if self.v_changes.is_some() {
+                    self.record_v_class_character(cp, start);
+                }
                 self.advance();
                 self.last_int_value = cp as i64;
+                self.record_control_character(cp as i64, start);
                 return Ok(true);
             }
         }
@@ -840,8 +1182,9 @@ impl EcmaRegexValidator {
             if self.consume_class_escape()? {
                 return Ok(true);
             }
-            if !self.strict && self.code_point_with_offset(0) == Some('c') {
+            if !self.strict && self.code_point_with_offset(0) == Some('c' as u32) {
                 self.last_int_value = '\\' as i64;
+                self.record_annex_b(AnnexB::LoneControlEscape, start);
                 return Ok(true);
             }
             if self.strict || self.u_flag {
@@ -852,6 +1195,19 @@ impl EcmaRegexValidator {
         Ok(false)
     }
 
+    /// Notes a class character that has to be escaped with the `v` flag, for
+    /// `analyze_v_flag`.
+    fn record_v_class_character(&mut self, cp: u32, start: usize) {
+        let c = char::from_u32(cp).unwrap_or('\0');
+        if "()[{}/-|".contains(c) {
+            self.record_v_change(VFlagChange::ClassSyntaxCharacter, start..start + 1);
+        } else if "&!#$%*+,.:;<=>?@^`~".contains(c)
+            && self.source().get(start + 1) == Some(&(cp as u16))
+        {
+            self.record_v_change(VFlagChange::DoublePunctuator, start..start + 1);
+        }
+    }
+
     /// Validate the next characters as a RegExp `ClassEscape` production if possible.
     /// Set `self.last_int_value` if it consumed the next characters successfully.
     /// ```grammar
@@ -872,19 +1228,22 @@ impl EcmaRegexValidator {
             return Ok(true);
         }
 
-        // [+U] `-`
-        if self.u_flag && self.eat('-') {
+        // [+U] `-`, which without the `u` flag is an IdentityEscape of the same character
+        if self.eat('-') {
             self.last_int_value = '-' as i64;
             return Ok(true);
         }
 
         // [annexB][~U] `c` ClassControlLetter
-        if !self.strict && !self.u_flag && self.code_point_with_offset(0) == Some('c') {
+        if !self.strict && !self.u_flag && self.code_point_with_offset(0) == Some('c' as u32) {
             if let Some(cp) = self.code_point_with_offset(1) {
-                if cp.is_digit(10) || cp == '_' {
+                if is_decimal_digit(cp) || cp == '_' as u32 {
+                    let start = self.index() - 1;
                     self.advance();
                     self.advance();
                     self.last_int_value = cp as i64 % 0x20;
+                    self.record_annex_b(AnnexB::ClassControlDigit, start);
+                    self.record_control_character(self.last_int_value, start);
                     return Ok(true);
                 }
             }
@@ -921,12 +1280,12 @@ impl EcmaRegexValidator {
     /// Returns `true` if it ate the next characters successfully.
     fn eat_regexp_identifier_name(&mut self) -> Result<bool, String> {
         if self.eat_regexp_identifier_start()? {
-            self.last_str_value = std::char::from_u32(self.last_int_value as u32)
+            self.last_str_value = core::char::from_u32(self.last_int_value as u32)
                 .unwrap()
                 .to_string();
             while self.eat_regexp_identifier_part()? {
                 self.last_str_value
-                    .push(std::char::from_u32(self.last_int_value as u32).unwrap());
+                    .push(core::char::from_u32(self.last_int_value as u32).unwrap());
             }
             return Ok(true);
         }
@@ -952,21 +1311,21 @@ impl EcmaRegexValidator {
         if let Some(mut cp) = self.code_point_with_offset(0) {
             self.advance();
             let cp1 = self.code_point_with_offset(0);
-            if cp == '\\' && self.eat_regexp_unicode_escape_sequence(force_u_flag)? {
-                cp = std::char::from_u32(self.last_int_value as u32).unwrap();
+            if cp == '\\' as u32 && self.eat_regexp_unicode_escape_sequence(force_u_flag)? {
+                cp = self.last_int_value as u32;
             } else if force_u_flag
                 && is_lead_surrogate(cp as i64)
                 && cp1.is_some()
                 && is_trail_surrogate(cp1.unwrap() as i64)
             {
-                cp = std::char::from_u32(
-                    combine_surrogate_pair(cp as i64, cp1.unwrap() as i64) as u32,
-                )
-                .unwrap();
+                cp = combine_surrogate_pair(cp as i64, cp1.unwrap() as i64) as u32;
                 self.advance();
             }
 
-            if is_regexp_identifier_start(cp) {
+            if is_regexp_identifier_start(cp, self.unicode_version) {
+                if let Some(version) = large_id_start_version(cp) {
+                    self.record_unicode_data(version);
+                }
                 self.last_int_value = cp as i64;
                 return Ok(true);
             }
@@ -999,22 +1358,20 @@ impl EcmaRegexValidator {
         self.advance();
         let cp1 = self.code_point_with_offset(0);
 
-        if cp == Some('\\') && self.eat_regexp_unicode_escape_sequence(force_u_flag)? {
-            // TODO: convert unicode code point to char
-            cp = std::char::from_u32(self.last_int_value as u32);
-        } else if force_u_flag
-            && is_lead_surrogate(cp.unwrap() as i64)
-            && is_trail_surrogate(cp1.unwrap() as i64)
-        {
-            cp = std::char::from_u32(combine_surrogate_pair(
-                cp.unwrap() as i64,
-                cp1.unwrap() as i64,
-            ) as u32);
-            self.advance();
+        if cp == Some('\\' as u32) && self.eat_regexp_unicode_escape_sequence(force_u_flag)? {
+            cp = Some(self.last_int_value as u32);
+        } else if let (true, Some(lead), Some(trail)) = (force_u_flag, cp, cp1) {
+            if is_lead_surrogate(lead as i64) && is_trail_surrogate(trail as i64) {
+                cp = Some(combine_surrogate_pair(lead as i64, trail as i64) as u32);
+                self.advance();
+            }
         }
 
-        if cp.is_some() && is_regexp_identifier_part(cp.unwrap()) {
-            self.last_int_value = cp.unwrap() as i64;
+        if let Some(cp) = cp.filter(|&cp| is_regexp_identifier_part(cp, self.unicode_version)) {
+            if let Some(version) = large_id_continue_version(cp) {
+                self.record_unicode_data(version);
+            }
+            self.last_int_value = cp as i64;
             return Ok(true);
         }
 
@@ -1048,11 +1405,11 @@ impl EcmaRegexValidator {
     /// ```
     /// Returns `true` if it ate the next characters successfully.
     fn eat_zero(&mut self) -> bool {
-        if self.code_point_with_offset(0) != Some('0') {
+        if self.code_point_with_offset(0) != Some('0' as u32) {
             return false;
         }
         if let Some(cp) = self.code_point_with_offset(1) {
-            if cp.is_digit(10) {
+            if is_decimal_digit(cp) {
                 return false;
             }
         }
@@ -1103,7 +1460,7 @@ impl EcmaRegexValidator {
     /// Returns `true` if it ate the next characters successfully.
     fn eat_control_letter(&mut self) -> bool {
         if let Some(cp) = self.code_point_with_offset(0) {
-            if cp.is_ascii_alphabetic() {
+            if is_latin_letter(cp) {
                 self.advance();
                 self.last_int_value = cp as i64 % 0x20;
                 return true;
@@ -1212,21 +1569,38 @@ impl EcmaRegexValidator {
         if let Some(cp) = self.code_point_with_offset(0) {
             if self.is_valid_identity_escape(cp) {
                 self.last_int_value = cp as i64;
+                let start = self.index() - 1;
                 self.advance();
+                let end = self.index();
+                if let Some(escapes) = &mut self.identity_escapes {
+                    escapes.push((cp, start..end));
+                }
+                // Only identifier characters are left to Annex B, others like `\-` are core
+                // syntax without the `u` flag.
+                if !self.u_flag && is_id_continue(cp, self.unicode_version) {
+                    let kind = match core::char::from_u32(cp) {
+                        Some('8') | Some('9') => AnnexB::NonOctalDecimalEscape,
+                        Some('k') => AnnexB::KEscape,
+                        _ => AnnexB::IdentityEscape,
+                    };
+                    self.record_annex_b(kind, start);
+                } else if !self.u_flag && !is_syntax_character(cp) && cp != '/' as u32 {
+                    self.record_u_change(UFlagChange::InvalidEscape, start..end);
+                }
                 return true;
             }
         }
         return false;
     }
-    fn is_valid_identity_escape(&self, cp: char) -> bool {
+    fn is_valid_identity_escape(&self, cp: u32) -> bool {
         if self.u_flag {
-            return is_syntax_character(cp) || cp == '/';
+            return is_syntax_character(cp) || cp == '/' as u32;
         } else if self.strict {
-            return !is_id_continue(cp);
+            return !is_id_continue(cp, self.unicode_version);
         } else if self.n_flag {
-            return !(cp == 'c' || cp == 'k');
+            return !(cp == 'c' as u32 || cp == 'k' as u32);
         }
-        return cp != 'c';
+        return cp != 'c' as u32;
     }
 
     /// Eat the next characters as a RegExp `DecimalEscape` production if possible.
@@ -1239,14 +1613,14 @@ impl EcmaRegexValidator {
     fn eat_decimal_escape(&mut self) -> bool {
         self.last_int_value = 0;
         if let Some(cp) = self.code_point_with_offset(0) {
-            if cp.is_digit(10) {
-                self.last_int_value = 10 * self.last_int_value + cp.to_digit(10).unwrap() as i64;
+            if is_decimal_digit(cp) && cp != '0' as u32 {
+                self.last_int_value = 10 * self.last_int_value + digit_value(cp);
                 self.advance();
                 while let Some(cp) = self.code_point_with_offset(0) {
-                    if !cp.is_digit(10) {
+                    if !is_decimal_digit(cp) {
                         break;
                     }
-                    self.last_int_value = 10 * self.last_int_value + cp.to_digit(10).unwrap() as i64;
+                    self.last_int_value = 10 * self.last_int_value + digit_value(cp);
                     self.advance();
                 }
                 return true;
@@ -1264,7 +1638,7 @@ impl EcmaRegexValidator {
     ///      LoneUnicodePropertyNameOrValue
     /// ```
     /// Returns `true` if it ate the next characters successfully.
-    fn eat_unicode_property_value_expression(&mut self) -> Result<bool, &str> {
+    fn eat_unicode_property_value_expression(&mut self) -> Result<bool, String> {
         let start = self.index();
 
         // UnicodePropertyName `=` UnicodePropertyValue
@@ -1273,13 +1647,22 @@ impl EcmaRegexValidator {
             if self.eat_unicode_property_value() {
                 self.last_val_value = self.last_str_value.clone();
                 if is_valid_unicode_property(
-                    self.ecma_version,
+                    self.unicode_version,
                     &self.last_key_value,
                     &self.last_val_value,
                 ) {
+                    if let Some(version) =
+                        unicode_property_version(&self.last_key_value, &self.last_val_value)
+                    {
+                        self.record_unicode_data(version);
+                    }
                     return Ok(true);
                 }
-                return Err("Invalid property name");
+                return Err(property_error(
+                    self.unicode_version,
+                    &self.last_key_value,
+                    &self.last_val_value,
+                ));
             }
         }
         self.rewind(start);
@@ -1287,17 +1670,26 @@ impl EcmaRegexValidator {
         // LoneUnicodePropertyNameOrValue
         if self.eat_lone_unicode_property_name_or_value() {
             let name_or_value = self.last_str_value.clone();
-            if is_valid_unicode_property(self.ecma_version, "General_Category", &name_or_value) {
+            if is_valid_unicode_property(self.unicode_version, "General_Category", &name_or_value) {
+                if let Some(version) = lone_unicode_property_version(&name_or_value) {
+                    self.record_unicode_data(version);
+                }
                 self.last_key_value = "General_Category".to_string();
                 self.last_val_value = name_or_value;
                 return Ok(true);
             }
-            if is_valid_lone_unicode_property(self.ecma_version, &name_or_value) {
+            if is_valid_lone_unicode_property(self.unicode_version, &name_or_value) {
+                if let Some(version) = lone_unicode_property_version(&name_or_value) {
+                    self.record_unicode_data(version);
+                }
                 self.last_key_value = name_or_value;
                 self.last_val_value = "".to_string();
                 return Ok(true);
             }
-            return Err("Invalid property name");
+            if is_valid_string_property(self.unicode_version, &name_or_value) {
+                return Err("Properties of strings are only valid with the v flag".to_string());
+            }
+            return Err(lone_property_error(self.unicode_version, &name_or_value));
         }
         Ok(false)
     }
@@ -1315,10 +1707,10 @@ impl EcmaRegexValidator {
             if !is_unicode_property_name_character(cp) {
                 break;
             }
-            self.last_str_value.push(cp);
+            self.last_str_value.push(core::char::from_u32(cp).unwrap());
             self.advance();
         }
-        self.last_str_value != ""
+        !self.last_str_value.is_empty()
     }
 
     /// Eat the next characters as a RegExp `UnicodePropertyValue` production if possible.
@@ -1334,10 +1726,10 @@ impl EcmaRegexValidator {
             if !is_unicode_property_value_character(cp) {
                 break;
             }
-            self.last_str_value.push(cp);
+            self.last_str_value.push(core::char::from_u32(cp).unwrap());
             self.advance();
         }
-        self.last_str_value != ""
+        !self.last_str_value.is_empty()
     }
 
     /// Eat the next characters as a RegExp `UnicodePropertyValue` production if possible.
@@ -1389,15 +1781,10 @@ impl EcmaRegexValidator {
 
         self.last_int_value = 0;
         while let Some(cp) = self.code_point_with_offset(0) {
-            if !cp.is_digit(10) {
+            if !is_decimal_digit(cp) {
                 break;
             }
-            self.last_int_value = 10 * self.last_int_value
-                + self
-                    .code_point_with_offset(0)
-                    .unwrap()
-                    .to_digit(10)
-                    .unwrap() as i64;
+            self.last_int_value = 10 * self.last_int_value + digit_value(cp);
             self.advance();
         }
 
@@ -1418,10 +1805,10 @@ impl EcmaRegexValidator {
         let start = self.index();
         self.last_int_value = 0;
         while let Some(cp) = self.code_point_with_offset(0) {
-            if !cp.is_digit(16) {
+            if !is_hex_digit(cp) {
                 break;
             }
-            self.last_int_value = 16 * self.last_int_value + cp.to_digit(16).unwrap() as i64;
+            self.last_int_value = 16 * self.last_int_value + digit_value(cp);
             self.advance();
         }
         return self.index() != start;
@@ -1449,7 +1836,7 @@ impl EcmaRegexValidator {
             if self.eat_octal_digit() {
                 let n2 = self.last_int_value;
                 if n1 <= 3 && self.eat_octal_digit() {
-                    self.last_int_value = n1 * 64 + n2 * 8 + self.last_int_value
+                    self.last_int_value += n1 * 64 + n2 * 8;
                 } else {
                     self.last_int_value = n1 * 8 + n2;
                 }
@@ -1470,9 +1857,9 @@ impl EcmaRegexValidator {
     /// Returns `true` if it ate the next characters successfully.
     fn eat_octal_digit(&mut self) -> bool {
         if let Some(cp) = self.code_point_with_offset(0) {
-            if cp.is_digit(8) {
+            if is_octal_digit(cp) {
                 self.advance();
-                self.last_int_value = cp.to_digit(8).unwrap() as i64;
+                self.last_int_value = digit_value(cp);
                 return true;
             }
         }
@@ -1492,12 +1879,12 @@ impl EcmaRegexValidator {
         self.last_int_value = 0;
         for _ in 0..length {
             let cp = self.code_point_with_offset(0);
-            if cp.is_none() || !cp.unwrap().is_digit(16) {
+            if cp.is_none() || !is_hex_digit(cp.unwrap()) {
                 self.rewind(start);
                 return false;
             }
             self.last_int_value =
-                16 * self.last_int_value + cp.unwrap().to_digit(16).unwrap() as i64;
+                16 * self.last_int_value + digit_value(cp.unwrap());
             self.advance();
         }
         return true;
@@ -1512,18 +1899,18 @@ impl EcmaRegexValidator {
         while let Some(cp) = self.code_point_with_offset(0) {
             if escaped {
                 escaped = false;
-            } else if cp == '\\' {
+            } else if cp == '\\' as u32 {
                 escaped = true;
-            } else if cp == '[' {
+            } else if cp == '[' as u32 {
                 in_class = true;
-            } else if cp == ']' {
+            } else if cp == ']' as u32 {
                 in_class = false;
-            } else if cp == '('
+            } else if cp == '(' as u32
                 && !in_class
-                && (self.code_point_with_offset(1) != Some('?')
-                    || (self.code_point_with_offset(2) == Some('<')
-                        && self.code_point_with_offset(3) != Some('=')
-                        && self.code_point_with_offset(3) != Some('!')))
+                && (self.code_point_with_offset(1) != Some('?' as u32)
+                    || (self.code_point_with_offset(2) == Some('<' as u32)
+                        && self.code_point_with_offset(3) != Some('=' as u32)
+                        && self.code_point_with_offset(3) != Some('!' as u32)))
             {
                 count += 1
             }
@@ -1552,4 +1939,39 @@ mod tests {
         validator.reset(source, 0, source.len(), false);
         assert_eq!(validator.count_capturing_parens(), 3);
     }
+
+    #[cfg(feature = "unicode-properties")]
+    #[test]
+    fn properties_of_strings() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2021);
+        assert_eq!(
+            validator.validate_pattern("\\p{RGI_Emoji}", true),
+            Err("Properties of strings are only valid with the v flag".to_string())
+        );
+        validator.set_unicode_version(UnicodeVersion::V12_1);
+        assert_eq!(
+            validator.validate_pattern("\\p{RGI_Emoji}", true),
+            Err("Property RGI_Emoji requires Unicode 13.0 (configured: Unicode 12.1)".to_string())
+        );
+    }
+
+    #[cfg(not(feature = "unicode-properties"))]
+    #[test]
+    fn property_escapes_without_tables() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        assert_eq!(
+            validator.validate_pattern("\\p{L}", true),
+            Err("Property escapes are not supported in this build".to_string())
+        );
+        assert_eq!(validator.validate_pattern("\\p{L}", false), Ok(()));
+    }
+
+    #[cfg(not(feature = "unicode-identifiers"))]
+    #[test]
+    fn group_names_without_tables() {
+        let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+        assert_eq!(validator.validate_pattern("(?<名前>a)\\k<名前>", false), Ok(()));
+        assert_eq!(validator.validate_pattern("(?<α1>a)", false), Ok(()));
+        assert_ne!(validator.validate_pattern("(?<☃>a)", false), Ok(()));
+    }
 }
@@ -5,7 +5,7 @@
 
 extern crate js_regex;
 
-use js_regex::{EcmaRegexValidator, EcmaVersion};
+use js_regex::{EcmaRegexValidator, EcmaVersion, UnicodeVersion};
 
 #[test]
 fn basic_invalid() {
@@ -141,6 +141,7 @@ fn basic_invalid_2015_unicode() {
     assert_ne!(validator.validate_pattern("${1,2}", true), Ok(()));
     assert_ne!(validator.validate_pattern("${2,1}", true), Ok(()));
     assert_ne!(validator.validate_pattern("\\1", true), Ok(()));
+    assert_ne!(validator.validate_pattern("\\00", true), Ok(()));
     assert_ne!(validator.validate_pattern("\\2(a)(", true), Ok(()));
     assert_ne!(validator.validate_pattern("(?:a)\\1", true), Ok(()));
     assert_ne!(validator.validate_pattern("(a)\\2", true), Ok(()));
@@ -323,3 +324,70 @@ fn unicode_property_escape_invalid_2018() {
     assert_ne!(validator.validate_pattern("\\p{General_Category=Hiragana}", true), Ok(()));
     assert_ne!(validator.validate_pattern("[\\p{Script=Hiragana}-\\p{Script=Katakana}]", true), Ok(()));
 }
+
+#[test]
+fn unicode_version_invalid() {
+    let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2021);
+    assert_ne!(validator.validate_pattern("\\p{Script=Kawi}", true), Ok(()));
+    validator.set_unicode_version(UnicodeVersion::V14_0);
+    assert_ne!(validator.validate_pattern("\\p{Script=Kawi}", true), Ok(()));
+    validator.set_unicode_version(UnicodeVersion::V10_0);
+    assert_ne!(validator.validate_pattern("\\p{Extended_Pictographic}", true), Ok(()));
+}
+
+#[cfg(feature = "unicode-identifiers")]
+#[test]
+fn unicode_version_invalid_group_name() {
+    let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2021);
+    validator.set_unicode_version(UnicodeVersion::V14_0);
+    assert_ne!(validator.validate_pattern("(?<\u{11f04}>.)", true), Ok(()));
+}
+
+#[cfg(feature = "unicode-properties")]
+#[test]
+fn unicode_property_suggestions() {
+    let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+    assert_eq!(
+        validator.validate_pattern("\\p{Script=greek}", true),
+        Err("Invalid property value greek for Script (did you mean Greek?)".to_string())
+    );
+    assert_eq!(
+        validator.validate_pattern("\\p{General_Category=Hiragana}", true),
+        Err("Invalid property value Hiragana for General_Category (did you mean Script=Hiragana?)".to_string())
+    );
+    assert_eq!(
+        validator.validate_pattern("\\p{Letters}", true),
+        Err("Invalid property name Letters (did you mean Letter?)".to_string())
+    );
+    assert_eq!(
+        validator.validate_pattern("\\p{General_Category}", true),
+        Err("Property General_Category requires a value".to_string())
+    );
+    assert_eq!(
+        validator.validate_pattern("\\p{Script=Kawi}", true),
+        Err("Property Script=Kawi requires Unicode 15.0 (configured: Unicode 10.0)".to_string())
+    );
+}
+
+#[test]
+fn features_of_newer_versions() {
+    let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2017);
+    assert_eq!(
+        validator.validate_pattern("(?<=a)b", false),
+        Err("Lookbehind assertions require ES2018 (configured: ES2017)".to_string())
+    );
+    assert_eq!(
+        validator.validate_pattern("(?<!a)b", true),
+        Err("Lookbehind assertions require ES2018 (configured: ES2017)".to_string())
+    );
+    assert_eq!(
+        validator.validate_pattern("(?<year>\\d+)", false),
+        Err("Named capture groups require ES2018 (configured: ES2017)".to_string())
+    );
+    assert_eq!(
+        validator.validate_pattern("\\p{L}", true),
+        Err("Unicode property escapes require ES2018 (configured: ES2017)".to_string())
+    );
+    assert_eq!(validator.validate_pattern("\\p{L}", false), Ok(()));
+    assert_eq!(validator.validate_pattern("(?x)", false), Err("Invalid group".to_string()));
+}
@@ -5,7 +5,7 @@
 
 extern crate js_regex;
 
-use js_regex::{EcmaRegexValidator, EcmaVersion};
+use js_regex::{EcmaRegexValidator, EcmaVersion, UnicodeVersion};
 
 #[test]
 fn basic_valid() {
@@ -88,6 +88,7 @@ fn basic_valid() {
     assert_eq!(validator.validate_pattern("\\c1", false), Ok(()));
     assert_eq!(validator.validate_pattern("\\c", false), Ok(()));
     assert_eq!(validator.validate_pattern("\\0", false), Ok(()));
+    assert_eq!(validator.validate_pattern("\\00", false), Ok(()));
     assert_eq!(validator.validate_pattern("\\u", false), Ok(()));
     assert_eq!(validator.validate_pattern("\\u1", false), Ok(()));
     assert_eq!(validator.validate_pattern("\\u12", false), Ok(()));
@@ -412,6 +413,12 @@ fn basic_valid() {
     assert_eq!(validator.validate_pattern("\\p{", false), Ok(()));
     assert_eq!(validator.validate_pattern("\\p{ASCII", false), Ok(()));
     assert_eq!(validator.validate_pattern("\\p{ASCII}", false), Ok(()));
+}
+
+#[cfg(feature = "unicode-properties")]
+#[test]
+fn property_escapes_valid() {
+    let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
     assert_eq!(validator.validate_pattern("\\p{ASCII}", true), Ok(()));
     assert_eq!(validator.validate_pattern("\\p{Emoji}", true), Ok(()));
     assert_eq!(validator.validate_pattern("\\p{General_Category=Letter}", true), Ok(()));
@@ -419,3 +426,20 @@ fn basic_valid() {
     assert_eq!(validator.validate_pattern("[\\p{Script=Hiragana}\\-\\p{Script=Katakana}]", true), Ok(()));
     assert_eq!(validator.validate_pattern("\\P{Letter}", true), Ok(()));
 }
+
+#[cfg(feature = "unicode-properties")]
+#[test]
+fn unicode_version_valid() {
+    let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+    validator.set_unicode_version(UnicodeVersion::V15_0);
+    assert_eq!(validator.validate_pattern("\\p{Script=Kawi}", true), Ok(()));
+    assert_eq!(validator.validate_pattern("\\p{sc=Yezidi}", true), Ok(()));
+    assert_eq!(validator.validate_pattern("\\p{Extended_Pictographic}", true), Ok(()));
+}
+
+#[test]
+fn unicode_version_valid_group_name() {
+    let mut validator = EcmaRegexValidator::new(EcmaVersion::ES2018);
+    validator.set_unicode_version(UnicodeVersion::V15_0);
+    assert_eq!(validator.validate_pattern("(?<\u{11f04}>.)", true), Ok(()));
+}
@@ -0,0 +1,40 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+//! Generates the Unicode tables of `js-regex` from local copies of the Unicode Character
+//! Database (UCD).
+//!
+//! The UCD directory contains one subdirectory per Unicode version, named like the UCD
+//! release (e.g. `9.0.0`, `15.1.0`). Each one holds `PropertyAliases.txt`,
+//! `PropertyValueAliases.txt`, `DerivedCoreProperties.txt` and `emoji-sequences.txt` of that
+//! version. Every property value and code point is tagged with the oldest version that
+//! contains it, so the oldest directory also stands for all earlier versions.
+
+mod render;
+mod tables;
+mod ucd;
+
+use std::fs;
+use std::path::Path;
+
+pub use tables::Tables;
+pub use ucd::Ucd;
+
+/// The file names and contents of the generated modules.
+pub fn generate(ucd_dir: &Path) -> Result<Vec<(&'static str, String)>, String> {
+    let ucds = ucd::read_all(ucd_dir)?;
+    let tables = Tables::build(&ucds)?;
+    Ok(vec![
+        ("property_tables.rs", render::property_tables(&tables)),
+        ("id_tables.rs", render::id_tables(&tables)),
+    ])
+}
+
+/// Generates the modules from `ucd_dir` and writes them to `out_dir`.
+pub fn run(ucd_dir: &Path, out_dir: &Path) -> Result<(), String> {
+    for (name, contents) in generate(ucd_dir)? {
+        let path = out_dir.join(name);
+        fs::write(&path, contents).map_err(|e| format!("{}: {}", path.display(), e))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,30 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
+
+const USAGE: &str = "usage: unicode-gen <ucd-dir> [<output-dir>]
+
+Regenerates src/unicode/property_tables.rs and src/unicode/id_tables.rs of js-regex.
+<ucd-dir> contains one directory per Unicode version (e.g. 15.1.0) with the files
+PropertyAliases.txt, PropertyValueAliases.txt, DerivedCoreProperties.txt and
+emoji-sequences.txt of that version.";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() || args.len() > 2 || args[0] == "-h" || args[0] == "--help" {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+
+    let out_dir = match args.get(1) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../src/unicode"),
+    };
+    if let Err(e) = unicode_gen::run(Path::new(&args[0]), &out_dir) {
+        eprintln!("unicode-gen: {}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+//! Renders the tables as the Rust modules `property_tables.rs` and `id_tables.rs`.
+
+use std::fmt::Write;
+
+use crate::tables::{Alias, Tables, TaggedName, TaggedRange};
+
+const MAX_WIDTH: usize = 100;
+
+/// Renders the names of `General_Category`, `Script` and `Script_Extensions`, their values
+/// and the binary properties and properties of strings.
+pub fn property_tables(tables: &Tables) -> String {
+    let mut out = header(tables);
+    out += "use super::UnicodeVersion::{self, *};\n\n";
+    out += &names("GC_NAME_PATTERN", &tables.gc_names);
+    out += "\n";
+    out += &names("SC_NAME_PATTERN", &tables.sc_names);
+    out += "\n";
+    out += "// Property values with their canonical names, each tagged with the Unicode version that\n\
+            // introduced it.\n\n";
+    out += &tagged_names("GC_VALUE_PATTERNS", &tables.gc_values);
+    out += "\n";
+    out += &tagged_names("SC_VALUE_PATTERNS", &tables.sc_values);
+    out += "\n";
+    out += &tagged_names("BIN_PROPERTY_PATTERNS", &tables.binary_properties);
+    out += "\n";
+    out += &tagged_names("STRING_PROPERTY_PATTERNS", &tables.string_properties);
+    out
+}
+
+/// Renders the non-ASCII `ID_Start` and `ID_Continue` ranges.
+pub fn id_tables(tables: &Tables) -> String {
+    let mut out = header(tables);
+    out += "use super::UnicodeVersion::{self, *};\n\n";
+    out += "/// Non-ASCII `ID_Start` code points, tagged with the Unicode version they joined \
+            `ID_Start` in.\n";
+    out += &tagged_ranges("LARGE_ID_START_RANGES", &tables.id_start);
+    out += "\n";
+    out += "/// Non-ASCII code points that were in `ID_Continue` but not in `ID_Start` when they \
+            joined\n/// `ID_Continue`, tagged like `LARGE_ID_START_RANGES`.\n";
+    out += &tagged_ranges("LARGE_ID_CONTINUE_RANGES", &tables.id_continue);
+    out
+}
+
+fn header(tables: &Tables) -> String {
+    let mut out = String::from(
+        "// This file was generated by unicode-gen. Do not edit it by hand.\n\
+         //\n\
+         // Unicode Character Database versions:\n",
+    );
+    let mut line = String::from("//");
+    for version in &tables.versions {
+        if line.len() + version.len() + 1 > MAX_WIDTH {
+            out += &line;
+            out += "\n";
+            line = String::from("//");
+        }
+        line += " ";
+        line += version;
+    }
+    out += &line;
+    out += "\n\n";
+    out
+}
+
+fn names(name: &str, values: &[Alias]) -> String {
+    let mut out = format!("pub static {}: &[(&str, &str)] = &[\n", name);
+    for (value, canonical) in values {
+        let _ = writeln!(out, "    ({:?}, {:?}),", value, canonical);
+    }
+    out += "];\n";
+    out
+}
+
+fn tagged_names(name: &str, values: &[TaggedName]) -> String {
+    let mut out = format!(
+        "pub static {}: &[(&str, &str, UnicodeVersion)] = &[\n",
+        name
+    );
+    for (value, canonical, variant) in values {
+        let _ = writeln!(out, "    ({:?}, {:?}, {}),", value, canonical, variant);
+    }
+    out += "];\n";
+    out
+}
+
+fn tagged_ranges(name: &str, ranges: &[TaggedRange]) -> String {
+    let mut out = format!("pub static {}: &[(u32, u32, UnicodeVersion)] = &[\n", name);
+    let mut line = String::from("   ");
+    for (min, max, variant) in ranges {
+        let item = format!("(0x{:x}, 0x{:x}, {})", min, max, variant);
+        if line.len() + item.len() + 2 > MAX_WIDTH {
+            out += line.trim_end();
+            out += "\n";
+            line = String::from("   ");
+        }
+        let _ = write!(line, " {},", item);
+    }
+    out += line.trim_end();
+    out += "\n];\n";
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_ranges() {
+        let ranges: Vec<TaggedRange> = (0..5)
+            .map(|i| (0x10000 + i * 2, 0x10000 + i * 2, "V9_0".to_string()))
+            .collect();
+        assert_eq!(
+            tagged_ranges("R", &ranges),
+            "pub static R: &[(u32, u32, UnicodeVersion)] = &[\n    \
+             (0x10000, 0x10000, V9_0), (0x10002, 0x10002, V9_0), (0x10004, 0x10004, V9_0),\n    \
+             (0x10006, 0x10006, V9_0), (0x10008, 0x10008, V9_0),\n];\n"
+        );
+    }
+
+    #[test]
+    fn renders_names() {
+        let names = vec![("Grek".to_string(), "Greek".to_string(), "V9_0".to_string())];
+        assert_eq!(
+            tagged_names("SC", &names),
+            "pub static SC: &[(&str, &str, UnicodeVersion)] = &[\n    (\"Grek\", \"Greek\", V9_0),\n];\n"
+        );
+    }
+}
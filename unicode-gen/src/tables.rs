@@ -0,0 +1,461 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+//! Derives the version-tagged tables from the parsed UCD files.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::ucd::Ucd;
+
+/// Binary properties allowed in `\p{...}` by ECMA-262, with the aliases ECMA-262 lists for
+/// them and the Unicode version the first edition supporting them required.
+/// ECMA-262 does not accept every alias from `PropertyAliases.txt` (e.g. `WSpace`), so the
+/// aliases are given here instead of being read from the UCD.
+const BINARY_PROPERTIES: &[(&str, &[&str], (u32, u32))] = &[
+    ("ASCII", &[], (9, 0)),
+    ("ASCII_Hex_Digit", &["AHex"], (9, 0)),
+    ("Alphabetic", &["Alpha"], (9, 0)),
+    ("Any", &[], (9, 0)),
+    ("Assigned", &[], (9, 0)),
+    ("Bidi_Control", &["Bidi_C"], (9, 0)),
+    ("Bidi_Mirrored", &["Bidi_M"], (9, 0)),
+    ("Case_Ignorable", &["CI"], (9, 0)),
+    ("Cased", &[], (9, 0)),
+    ("Changes_When_Casefolded", &["CWCF"], (9, 0)),
+    ("Changes_When_Casemapped", &["CWCM"], (9, 0)),
+    ("Changes_When_Lowercased", &["CWL"], (9, 0)),
+    ("Changes_When_NFKC_Casefolded", &["CWKCF"], (9, 0)),
+    ("Changes_When_Titlecased", &["CWT"], (9, 0)),
+    ("Changes_When_Uppercased", &["CWU"], (9, 0)),
+    ("Dash", &[], (9, 0)),
+    ("Default_Ignorable_Code_Point", &["DI"], (9, 0)),
+    ("Deprecated", &["Dep"], (9, 0)),
+    ("Diacritic", &["Dia"], (9, 0)),
+    ("Emoji", &[], (9, 0)),
+    ("Emoji_Component", &[], (9, 0)),
+    ("Emoji_Modifier", &[], (9, 0)),
+    ("Emoji_Modifier_Base", &[], (9, 0)),
+    ("Emoji_Presentation", &[], (9, 0)),
+    ("Extended_Pictographic", &[], (11, 0)),
+    ("Extender", &["Ext"], (9, 0)),
+    ("Grapheme_Base", &["Gr_Base"], (9, 0)),
+    ("Grapheme_Extend", &["Gr_Ext"], (9, 0)),
+    ("Hex_Digit", &["Hex"], (9, 0)),
+    ("IDS_Binary_Operator", &["IDSB"], (9, 0)),
+    ("IDS_Trinary_Operator", &["IDST"], (9, 0)),
+    ("ID_Continue", &["IDC"], (9, 0)),
+    ("ID_Start", &["IDS"], (9, 0)),
+    ("Ideographic", &["Ideo"], (9, 0)),
+    ("Join_Control", &["Join_C"], (9, 0)),
+    ("Logical_Order_Exception", &["LOE"], (9, 0)),
+    ("Lowercase", &["Lower"], (9, 0)),
+    ("Math", &[], (9, 0)),
+    ("Noncharacter_Code_Point", &["NChar"], (9, 0)),
+    ("Pattern_Syntax", &["Pat_Syn"], (9, 0)),
+    ("Pattern_White_Space", &["Pat_WS"], (9, 0)),
+    ("Quotation_Mark", &["QMark"], (9, 0)),
+    ("Radical", &[], (9, 0)),
+    ("Regional_Indicator", &["RI"], (9, 0)),
+    ("Sentence_Terminal", &["STerm"], (9, 0)),
+    ("Soft_Dotted", &["SD"], (9, 0)),
+    ("Terminal_Punctuation", &["Term"], (9, 0)),
+    ("Unified_Ideograph", &["UIdeo"], (9, 0)),
+    ("Uppercase", &["Upper"], (9, 0)),
+    ("Variation_Selector", &["VS"], (9, 0)),
+    ("White_Space", &["space"], (9, 0)),
+    ("XID_Continue", &["XIDC"], (9, 0)),
+    ("XID_Start", &["XIDS"], (9, 0)),
+];
+
+/// Binary properties that ECMA-262 defines itself instead of taking them from the UCD.
+const ECMA_ONLY_PROPERTIES: &[&str] = &["ASCII", "Any", "Assigned"];
+
+/// Script values that ECMA-262 leaves out of `Script` and `Script_Extensions`.
+const EXCLUDED_SCRIPTS: &[&str] = &["Hrkt", "Katakana_Or_Hiragana", "Unknown", "Zzzz"];
+
+/// Properties of strings (only valid with the `v` flag) as listed by ECMA-262. The first
+/// five are the sequence types of `emoji-sequences.txt`. `RGI_Emoji_ZWJ_Sequence` and
+/// `RGI_Emoji` are defined in other files of UTS #51 and become available together with
+/// `RGI_Emoji_Flag_Sequence`.
+const STRING_PROPERTIES: &[&str] = &[
+    "Basic_Emoji",
+    "Emoji_Keycap_Sequence",
+    "RGI_Emoji_Flag_Sequence",
+    "RGI_Emoji_Modifier_Sequence",
+    "RGI_Emoji_Tag_Sequence",
+];
+const DERIVED_STRING_PROPERTIES: &[&str] = &["RGI_Emoji", "RGI_Emoji_ZWJ_Sequence"];
+
+/// A name and its canonical name.
+pub type Alias = (String, String);
+
+/// A name and its canonical name, tagged with the `UnicodeVersion` variant that introduced it.
+pub type TaggedName = (String, String, String);
+
+/// A code point range tagged with the `UnicodeVersion` variant that introduced it.
+pub type TaggedRange = (u32, u32, String);
+
+/// All generated tables, each sorted for binary search.
+#[derive(Debug, PartialEq)]
+pub struct Tables {
+    /// The UCD versions the tables were generated from, e.g. `15.1.0`.
+    pub versions: Vec<String>,
+    pub gc_names: Vec<Alias>,
+    pub sc_names: Vec<Alias>,
+    pub gc_values: Vec<TaggedName>,
+    pub sc_values: Vec<TaggedName>,
+    pub binary_properties: Vec<TaggedName>,
+    pub string_properties: Vec<TaggedName>,
+    pub id_start: Vec<TaggedRange>,
+    pub id_continue: Vec<TaggedRange>,
+}
+
+impl Tables {
+    /// Builds the tables from UCD versions ordered from oldest to newest.
+    /// Everything is tagged with the oldest version it appears in, so the oldest version
+    /// also stands for all versions before it.
+    pub fn build(ucds: &[Ucd]) -> Result<Self, String> {
+        let latest = ucds.last().ok_or("No UCD versions given")?;
+        let variants: Vec<String> = ucds.iter().map(Ucd::variant).collect();
+
+        let gc_names = property_names(ucds, &["General_Category"]);
+        let sc_names = property_names(ucds, &["Script", "Script_Extensions"]);
+        if gc_names.is_empty() || sc_names.is_empty() {
+            return Err("PropertyAliases.txt lacks General_Category or Script".to_string());
+        }
+        let gc_values = tag_names(&variants, ucds, |ucd| property_values(ucd, "gc"));
+        let sc_values = tag_names(&variants, ucds, |ucd| {
+            let mut values = property_values(ucd, "sc");
+            values.retain(|(value, _)| !EXCLUDED_SCRIPTS.contains(&value.as_str()));
+            values
+        });
+
+        let known: BTreeSet<&str> = latest
+            .property_aliases
+            .iter()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        let mut binary_properties = Vec::new();
+        for &(name, aliases, since) in BINARY_PROPERTIES {
+            if !known.contains(name) && !ECMA_ONLY_PROPERTIES.contains(&name) {
+                return Err(format!("Unknown binary property {}", name));
+            }
+            // properties newer than all given versions are left out
+            let first = match ucds
+                .iter()
+                .position(|ucd| (ucd.version.0, ucd.version.1) >= since)
+            {
+                Some(first) => first,
+                None => continue,
+            };
+            for &alias in Some(name).iter().chain(aliases) {
+                binary_properties.push((
+                    alias.to_string(),
+                    name.to_string(),
+                    variants[first].clone(),
+                ));
+            }
+        }
+        binary_properties.sort();
+
+        let mut string_properties = tag_names(&variants, ucds, |ucd| {
+            STRING_PROPERTIES
+                .iter()
+                .filter(|name| ucd.emoji_sequence_types.contains(**name))
+                .map(|name| (name.to_string(), name.to_string()))
+                .collect()
+        });
+        let flag_sequence = string_properties
+            .iter()
+            .find(|(name, _, _)| name == "RGI_Emoji_Flag_Sequence")
+            .map(|(_, _, variant)| variant.clone());
+        if let Some(variant) = flag_sequence {
+            for name in DERIVED_STRING_PROPERTIES {
+                string_properties.push((name.to_string(), name.to_string(), variant.clone()));
+            }
+            string_properties.sort();
+        }
+
+        // ASCII identifier characters are checked by the validator itself
+        let id_start = tag_code_points(&variants, ucds, |ucd| {
+            code_points(&ucd.id_start)
+                .into_iter()
+                .filter(|&cp| cp >= 0x80)
+                .collect()
+        });
+        let id_continue = tag_code_points(&variants, ucds, |ucd| {
+            let start = code_points(&ucd.id_start);
+            code_points(&ucd.id_continue)
+                .into_iter()
+                .filter(|&cp| cp >= 0x80 && !start.contains(&cp))
+                .collect()
+        });
+
+        Ok(Tables {
+            versions: ucds.iter().map(Ucd::version_name).collect(),
+            gc_names,
+            sc_names,
+            gc_values,
+            sc_values,
+            binary_properties,
+            string_properties,
+            id_start,
+            id_continue,
+        })
+    }
+}
+
+/// Returns all names of the given properties in any version, with their long names from
+/// the newest version as canonical names.
+/// Lines of `PropertyAliases.txt` list the short name first and the long name second.
+fn property_names(ucds: &[Ucd], properties: &[&str]) -> Vec<Alias> {
+    let mut names = BTreeMap::new();
+    for ucd in ucds {
+        for aliases in &ucd.property_aliases {
+            if aliases
+                .iter()
+                .any(|alias| properties.contains(&alias.as_str()))
+            {
+                let canonical = aliases.get(1).unwrap_or(&aliases[0]);
+                for alias in aliases {
+                    names.insert(alias.clone(), canonical.clone());
+                }
+            }
+        }
+    }
+    names.into_iter().collect()
+}
+
+/// Returns all value names of `property` (given by its short name) in `ucd` with their
+/// long names. Lines of `PropertyValueAliases.txt` list the property, the short value name
+/// and the long value name, followed by other aliases.
+fn property_values(ucd: &Ucd, property: &str) -> Vec<Alias> {
+    let mut values = Vec::new();
+    for fields in &ucd.property_value_aliases {
+        if fields.len() < 2 || fields[0] != property {
+            continue;
+        }
+        let canonical = fields.get(2).unwrap_or(&fields[1]);
+        for alias in &fields[1..] {
+            values.push((alias.clone(), canonical.clone()));
+        }
+    }
+    values
+}
+
+/// Tags every name returned by `names` for some version with the first version returning it.
+/// The canonical name is taken from the newest version.
+fn tag_names<F>(variants: &[String], ucds: &[Ucd], names: F) -> Vec<TaggedName>
+where
+    F: Fn(&Ucd) -> Vec<Alias>,
+{
+    let mut tagged: BTreeMap<String, (String, String)> = BTreeMap::new();
+    for (variant, ucd) in variants.iter().zip(ucds) {
+        for (name, canonical) in names(ucd) {
+            tagged
+                .entry(name)
+                .or_insert_with(|| (String::new(), variant.clone()))
+                .0 = canonical;
+        }
+    }
+    tagged
+        .into_iter()
+        .map(|(name, (canonical, variant))| (name, canonical, variant))
+        .collect()
+}
+
+/// Tags every code point returned by `code_points` for some version with the first version
+/// returning it, and merges adjacent code points with the same tag into ranges.
+fn tag_code_points<F>(variants: &[String], ucds: &[Ucd], code_points: F) -> Vec<TaggedRange>
+where
+    F: Fn(&Ucd) -> BTreeSet<u32>,
+{
+    let mut tagged = BTreeMap::new();
+    for (i, ucd) in ucds.iter().enumerate() {
+        for cp in code_points(ucd) {
+            tagged.entry(cp).or_insert(i);
+        }
+    }
+
+    let mut ranges: Vec<(u32, u32, usize)> = Vec::new();
+    for (cp, i) in tagged {
+        match ranges.last_mut() {
+            Some(last) if last.1 + 1 == cp && last.2 == i => last.1 = cp,
+            _ => ranges.push((cp, cp, i)),
+        }
+    }
+    ranges
+        .into_iter()
+        .map(|(min, max, i)| (min, max, variants[i].clone()))
+        .collect()
+}
+
+fn code_points(ranges: &[(u32, u32)]) -> BTreeSet<u32> {
+    ranges.iter().flat_map(|&(min, max)| min..=max).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROPERTY_ALIASES: &str = "gc ; General_Category\nsc ; Script\nscx ; Script_Extensions\n";
+
+    fn ucd(version: (u32, u32, u32), pva: &str, dcp: &str) -> Ucd {
+        let mut pa = PROPERTY_ALIASES.to_string();
+        for (name, _, _) in BINARY_PROPERTIES {
+            pa += &format!("{} ; {}\n", name, name);
+        }
+        Ucd::parse(version, &pa, pva, dcp, "").unwrap()
+    }
+
+    #[test]
+    fn tags_first_appearance() {
+        let ucds = vec![
+            ucd(
+                (9, 0, 0),
+                "sc ; Grek ; Greek\n",
+                "00AA ; ID_Start\n0300 ; ID_Continue\n",
+            ),
+            ucd(
+                (10, 0, 0),
+                "sc ; Grek ; Greek\nsc ; Nshu ; Nushu\n",
+                "00AA..00AB ; ID_Start\n0300 ; ID_Continue\n",
+            ),
+        ];
+        let tables = Tables::build(&ucds).unwrap();
+        assert_eq!(tables.versions, vec!["9.0.0", "10.0.0"]);
+        let names: Vec<(&str, &str)> = tables
+            .sc_names
+            .iter()
+            .map(|(n, c)| (n.as_str(), c.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("Script", "Script"),
+                ("Script_Extensions", "Script_Extensions"),
+                ("sc", "Script"),
+                ("scx", "Script_Extensions"),
+            ]
+        );
+        let values: Vec<(&str, &str, &str)> = tables
+            .sc_values
+            .iter()
+            .map(|(n, c, v)| (n.as_str(), c.as_str(), v.as_str()))
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                ("Greek", "Greek", "V9_0"),
+                ("Grek", "Greek", "V9_0"),
+                ("Nshu", "Nushu", "V10_0"),
+                ("Nushu", "Nushu", "V10_0"),
+            ]
+        );
+        assert_eq!(
+            tables.id_start,
+            vec![
+                (0xaa, 0xaa, "V9_0".to_string()),
+                (0xab, 0xab, "V10_0".to_string())
+            ]
+        );
+        assert_eq!(tables.id_continue, vec![(0x300, 0x300, "V9_0".to_string())]);
+    }
+
+    #[test]
+    fn continue_keeps_code_points_that_moved_to_start() {
+        let ucds = vec![
+            ucd((11, 0, 0), "", "1CF2 ; ID_Continue\n"),
+            ucd((12, 0, 0), "", "1CF2 ; ID_Start\n1CF2 ; ID_Continue\n"),
+        ];
+        let tables = Tables::build(&ucds).unwrap();
+        assert_eq!(tables.id_start, vec![(0x1cf2, 0x1cf2, "V12_0".to_string())]);
+        assert_eq!(
+            tables.id_continue,
+            vec![(0x1cf2, 0x1cf2, "V11_0".to_string())]
+        );
+    }
+
+    #[test]
+    fn excluded_scripts() {
+        let pva =
+            "sc ; Hrkt ; Katakana_Or_Hiragana\nsc ; Zzzz ; Unknown\nsc ; Zinh ; Inherited ; Qaai\n";
+        let tables = Tables::build(&[ucd((9, 0, 0), pva, "")]).unwrap();
+        let values: Vec<(&str, &str)> = tables
+            .sc_values
+            .iter()
+            .map(|(n, c, _)| (n.as_str(), c.as_str()))
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                ("Inherited", "Inherited"),
+                ("Qaai", "Inherited"),
+                ("Zinh", "Inherited")
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_binary_property() {
+        let pa = format!(
+            "{}AHex ; ASCII_Hex_Digit\nAlpha ; Alphabetic\n",
+            PROPERTY_ALIASES
+        );
+        let ucd = Ucd::parse((9, 0, 0), &pa, "", "", "").unwrap();
+        assert_eq!(
+            Tables::build(&[ucd]),
+            Err("Unknown binary property Bidi_Control".to_string())
+        );
+    }
+
+    #[test]
+    fn binary_properties_since() {
+        let tables = Tables::build(&[ucd((9, 0, 0), "", ""), ucd((11, 0, 0), "", "")]).unwrap();
+        let tag = |name: &str| {
+            let i = tables
+                .binary_properties
+                .binary_search_by(|(n, _, _)| n.as_str().cmp(name));
+            let (_, canonical, variant) = &tables.binary_properties[i.unwrap()];
+            (canonical.clone(), variant.clone())
+        };
+        assert_eq!(
+            tag("White_Space"),
+            ("White_Space".to_string(), "V9_0".to_string())
+        );
+        assert_eq!(
+            tag("space"),
+            ("White_Space".to_string(), "V9_0".to_string())
+        );
+        assert_eq!(tag("Extended_Pictographic").1, "V11_0");
+
+        let tables = Tables::build(&[ucd((10, 0, 0), "", "")]).unwrap();
+        assert!(!tables
+            .binary_properties
+            .iter()
+            .any(|(n, _, _)| n == "Extended_Pictographic"));
+    }
+
+    #[test]
+    fn string_properties() {
+        let mut ucds = vec![ucd((12, 0, 0), "", ""), ucd((13, 0, 0), "", "")];
+        ucds[1]
+            .emoji_sequence_types
+            .insert("RGI_Emoji_Flag_Sequence".to_string());
+        let tables = Tables::build(&ucds).unwrap();
+        let names: Vec<(&str, &str)> = tables
+            .string_properties
+            .iter()
+            .map(|(n, _, v)| (n.as_str(), v.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("RGI_Emoji", "V13_0"),
+                ("RGI_Emoji_Flag_Sequence", "V13_0"),
+                ("RGI_Emoji_ZWJ_Sequence", "V13_0"),
+            ]
+        );
+    }
+}
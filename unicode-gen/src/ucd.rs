@@ -0,0 +1,237 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+//! Parsers for the files of the Unicode Character Database.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+/// The files of one Unicode version that the tables are generated from.
+#[derive(Debug)]
+pub struct Ucd {
+    /// The version as numbers, e.g. `(15, 1, 0)`.
+    pub version: (u32, u32, u32),
+    /// Lines of `PropertyAliases.txt`, each a list of names for one property.
+    pub property_aliases: Vec<Vec<String>>,
+    /// Lines of `PropertyValueAliases.txt`, each a property name followed by value names.
+    pub property_value_aliases: Vec<Vec<String>>,
+    /// `ID_Start` ranges from `DerivedCoreProperties.txt`.
+    pub id_start: Vec<(u32, u32)>,
+    /// `ID_Continue` ranges from `DerivedCoreProperties.txt`.
+    pub id_continue: Vec<(u32, u32)>,
+    /// Names of the sequence types in `emoji-sequences.txt`.
+    pub emoji_sequence_types: BTreeSet<String>,
+}
+
+impl Ucd {
+    /// Returns the name of the `UnicodeVersion` variant for this version, e.g. `V15_1`.
+    pub fn variant(&self) -> String {
+        format!("V{}_{}", self.version.0, self.version.1)
+    }
+
+    /// Returns the version as it is written in the UCD, e.g. `15.1.0`.
+    pub fn version_name(&self) -> String {
+        format!("{}.{}.{}", self.version.0, self.version.1, self.version.2)
+    }
+
+    /// Parses the files of one version from their contents.
+    pub fn parse(
+        version: (u32, u32, u32),
+        property_aliases: &str,
+        property_value_aliases: &str,
+        derived_core_properties: &str,
+        emoji_sequences: &str,
+    ) -> Result<Self, String> {
+        let mut id_start = Vec::new();
+        let mut id_continue = Vec::new();
+        for fields in data_lines(derived_core_properties) {
+            if fields.len() < 2 {
+                return Err(format!(
+                    "Malformed line in DerivedCoreProperties.txt: {:?}",
+                    fields
+                ));
+            }
+            match fields[1] {
+                "ID_Start" => id_start.push(parse_range(fields[0])?),
+                "ID_Continue" => id_continue.push(parse_range(fields[0])?),
+                _ => {}
+            }
+        }
+
+        let mut emoji_sequence_types = BTreeSet::new();
+        for fields in data_lines(emoji_sequences) {
+            if fields.len() < 2 {
+                return Err(format!(
+                    "Malformed line in emoji-sequences.txt: {:?}",
+                    fields
+                ));
+            }
+            emoji_sequence_types.insert(fields[1].to_string());
+        }
+
+        Ok(Ucd {
+            version,
+            property_aliases: owned_lines(property_aliases),
+            property_value_aliases: owned_lines(property_value_aliases),
+            id_start,
+            id_continue,
+            emoji_sequence_types,
+        })
+    }
+
+    /// Reads the files of one version from `dir`.
+    pub fn read(version: (u32, u32, u32), dir: &Path) -> Result<Self, String> {
+        let read = |name: &str| {
+            let path = dir.join(name);
+            fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))
+        };
+        Ucd::parse(
+            version,
+            &read("PropertyAliases.txt")?,
+            &read("PropertyValueAliases.txt")?,
+            &read("DerivedCoreProperties.txt")?,
+            &read("emoji-sequences.txt")?,
+        )
+    }
+}
+
+/// Reads every version directory below `root`, ordered from oldest to newest.
+///
+/// Version directories are named like the UCD releases, e.g. `9.0.0` or `15.1.0`.
+/// Other entries of `root` are ignored.
+pub fn read_all(root: &Path) -> Result<Vec<Ucd>, String> {
+    let entries = fs::read_dir(root).map_err(|e| format!("{}: {}", root.display(), e))?;
+    let mut dirs = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| e.to_string())?;
+        if !entry.path().is_dir() {
+            continue;
+        }
+        if let Some(version) = entry.file_name().to_str().and_then(parse_version) {
+            dirs.push((version, entry.path()));
+        }
+    }
+    if dirs.is_empty() {
+        return Err(format!(
+            "No version directories found in {}",
+            root.display()
+        ));
+    }
+
+    dirs.sort();
+    for pair in dirs.windows(2) {
+        let (a, b) = (pair[0].0, pair[1].0);
+        if (a.0, a.1) == (b.0, b.1) {
+            return Err(format!(
+                "Unicode {}.{}.{} and {}.{}.{} map to the same UnicodeVersion",
+                a.0, a.1, a.2, b.0, b.1, b.2
+            ));
+        }
+    }
+    dirs.iter()
+        .map(|(version, dir)| Ucd::read(*version, dir))
+        .collect()
+}
+
+/// Parses a version like `15.1.0`.
+pub fn parse_version(name: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = name.split('.').map(|part| part.parse::<u32>().ok());
+    let version = (parts.next()??, parts.next()??, parts.next()??);
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(version)
+}
+
+/// Returns the `;`-separated fields of every line that is not empty or a comment.
+/// Trailing empty fields, as in `gc ; LC ; Cased_Letter ; # Ll | Lt | Lu`, are dropped.
+fn data_lines(text: &str) -> impl Iterator<Item = Vec<&str>> {
+    text.lines().filter_map(|line| {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            return None;
+        }
+        let mut fields: Vec<&str> = line.split(';').map(str::trim).collect();
+        while fields.last() == Some(&"") {
+            fields.pop();
+        }
+        Some(fields)
+    })
+}
+
+fn owned_lines(text: &str) -> Vec<Vec<String>> {
+    data_lines(text)
+        .map(|fields| fields.into_iter().map(String::from).collect())
+        .collect()
+}
+
+/// Parses a code point like `00AA` or a range like `0041..005A`.
+fn parse_range(field: &str) -> Result<(u32, u32), String> {
+    let parse =
+        |cp: &str| u32::from_str_radix(cp, 16).map_err(|_| format!("Invalid code point {:?}", cp));
+    match field.find("..") {
+        Some(i) => Ok((parse(&field[..i])?, parse(&field[i + 2..])?)),
+        None => {
+            let cp = parse(field)?;
+            Ok((cp, cp))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn data_lines_test() {
+        let text =
+            "# comment\n\ngc ; LC ; Cased_Letter ; # Ll | Lt | Lu\nsc ; Zinh ; Inherited ; Qaai\n";
+        let lines: Vec<Vec<&str>> = data_lines(text).collect();
+        assert_eq!(
+            lines,
+            vec![
+                vec!["gc", "LC", "Cased_Letter"],
+                vec!["sc", "Zinh", "Inherited", "Qaai"],
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_derived_core_properties() {
+        let dcp = "0041..005A    ; ID_Start # L&  [26] LATIN CAPITAL LETTER A..Z\n\
+                   00AA          ; ID_Start # Lo       FEMININE ORDINAL INDICATOR\n\
+                   0030..0039    ; ID_Continue # Nd  [10] DIGIT ZERO..DIGIT NINE\n\
+                   094D          ; InCB; Linker # Mn       DEVANAGARI SIGN VIRAMA\n";
+        let ucd = Ucd::parse((16, 0, 0), "", "", dcp, "").unwrap();
+        assert_eq!(ucd.id_start, vec![(0x41, 0x5a), (0xaa, 0xaa)]);
+        assert_eq!(ucd.id_continue, vec![(0x30, 0x39)]);
+        assert_eq!(ucd.variant(), "V16_0");
+    }
+
+    #[test]
+    fn parse_emoji_sequences() {
+        let seqs = "231A..231B    ; Basic_Emoji  ; watch..hourglass done # E0.6 [2] (⌚..⌛)\n\
+                    0023 FE0F 20E3; Emoji_Keycap_Sequence ; keycap: \\x{23} # E0.6 [1] (#️⃣)\n";
+        let ucd = Ucd::parse((15, 0, 0), "", "", "", seqs).unwrap();
+        let types: Vec<&str> = ucd
+            .emoji_sequence_types
+            .iter()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(types, vec!["Basic_Emoji", "Emoji_Keycap_Sequence"]);
+    }
+
+    #[test]
+    fn parse_version_test() {
+        assert_eq!(parse_version("15.1.0"), Some((15, 1, 0)));
+        assert_eq!(parse_version("9.0.0"), Some((9, 0, 0)));
+        assert_eq!(parse_version("15.1"), None);
+        assert_eq!(parse_version("emoji"), None);
+    }
+
+    #[test]
+    fn invalid_code_point() {
+        assert!(Ucd::parse((9, 0, 0), "", "", "00G1 ; ID_Start\n", "").is_err());
+    }
+}
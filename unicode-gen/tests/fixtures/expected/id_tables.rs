@@ -0,0 +1,17 @@
+// This file was generated by unicode-gen. Do not edit it by hand.
+//
+// Unicode Character Database versions:
+// 9.0.0 10.0.0
+
+use super::UnicodeVersion::{self, *};
+
+/// Non-ASCII `ID_Start` code points, tagged with the Unicode version they joined `ID_Start` in.
+pub static LARGE_ID_START_RANGES: &[(u32, u32, UnicodeVersion)] = &[
+    (0xaa, 0xaa, V9_0), (0x370, 0x373, V9_0), (0x1b170, 0x1b2fb, V10_0),
+];
+
+/// Non-ASCII code points that were in `ID_Continue` but not in `ID_Start` when they joined
+/// `ID_Continue`, tagged like `LARGE_ID_START_RANGES`.
+pub static LARGE_ID_CONTINUE_RANGES: &[(u32, u32, UnicodeVersion)] = &[
+    (0x300, 0x36f, V9_0),
+];
@@ -0,0 +1,142 @@
+// This file was generated by unicode-gen. Do not edit it by hand.
+//
+// Unicode Character Database versions:
+// 9.0.0 10.0.0
+
+use super::UnicodeVersion::{self, *};
+
+pub static GC_NAME_PATTERN: &[(&str, &str)] = &[
+    ("General_Category", "General_Category"),
+    ("gc", "General_Category"),
+];
+
+pub static SC_NAME_PATTERN: &[(&str, &str)] = &[
+    ("Script", "Script"),
+    ("Script_Extensions", "Script_Extensions"),
+    ("sc", "Script"),
+    ("scx", "Script_Extensions"),
+];
+
+// Property values with their canonical names, each tagged with the Unicode version that
+// introduced it.
+
+pub static GC_VALUE_PATTERNS: &[(&str, &str, UnicodeVersion)] = &[
+    ("C", "Other", V9_0),
+    ("Cased_Letter", "Cased_Letter", V9_0),
+    ("Decimal_Number", "Decimal_Number", V9_0),
+    ("LC", "Cased_Letter", V9_0),
+    ("Lu", "Uppercase_Letter", V9_0),
+    ("Nd", "Decimal_Number", V9_0),
+    ("Other", "Other", V9_0),
+    ("Uppercase_Letter", "Uppercase_Letter", V9_0),
+    ("digit", "Decimal_Number", V9_0),
+];
+
+pub static SC_VALUE_PATTERNS: &[(&str, &str, UnicodeVersion)] = &[
+    ("Greek", "Greek", V9_0),
+    ("Grek", "Greek", V9_0),
+    ("Inherited", "Inherited", V9_0),
+    ("Nshu", "Nushu", V10_0),
+    ("Nushu", "Nushu", V10_0),
+    ("Qaai", "Inherited", V9_0),
+    ("Zinh", "Inherited", V9_0),
+];
+
+pub static BIN_PROPERTY_PATTERNS: &[(&str, &str, UnicodeVersion)] = &[
+    ("AHex", "ASCII_Hex_Digit", V9_0),
+    ("ASCII", "ASCII", V9_0),
+    ("ASCII_Hex_Digit", "ASCII_Hex_Digit", V9_0),
+    ("Alpha", "Alphabetic", V9_0),
+    ("Alphabetic", "Alphabetic", V9_0),
+    ("Any", "Any", V9_0),
+    ("Assigned", "Assigned", V9_0),
+    ("Bidi_C", "Bidi_Control", V9_0),
+    ("Bidi_Control", "Bidi_Control", V9_0),
+    ("Bidi_M", "Bidi_Mirrored", V9_0),
+    ("Bidi_Mirrored", "Bidi_Mirrored", V9_0),
+    ("CI", "Case_Ignorable", V9_0),
+    ("CWCF", "Changes_When_Casefolded", V9_0),
+    ("CWCM", "Changes_When_Casemapped", V9_0),
+    ("CWKCF", "Changes_When_NFKC_Casefolded", V9_0),
+    ("CWL", "Changes_When_Lowercased", V9_0),
+    ("CWT", "Changes_When_Titlecased", V9_0),
+    ("CWU", "Changes_When_Uppercased", V9_0),
+    ("Case_Ignorable", "Case_Ignorable", V9_0),
+    ("Cased", "Cased", V9_0),
+    ("Changes_When_Casefolded", "Changes_When_Casefolded", V9_0),
+    ("Changes_When_Casemapped", "Changes_When_Casemapped", V9_0),
+    ("Changes_When_Lowercased", "Changes_When_Lowercased", V9_0),
+    ("Changes_When_NFKC_Casefolded", "Changes_When_NFKC_Casefolded", V9_0),
+    ("Changes_When_Titlecased", "Changes_When_Titlecased", V9_0),
+    ("Changes_When_Uppercased", "Changes_When_Uppercased", V9_0),
+    ("DI", "Default_Ignorable_Code_Point", V9_0),
+    ("Dash", "Dash", V9_0),
+    ("Default_Ignorable_Code_Point", "Default_Ignorable_Code_Point", V9_0),
+    ("Dep", "Deprecated", V9_0),
+    ("Deprecated", "Deprecated", V9_0),
+    ("Dia", "Diacritic", V9_0),
+    ("Diacritic", "Diacritic", V9_0),
+    ("Emoji", "Emoji", V9_0),
+    ("Emoji_Component", "Emoji_Component", V9_0),
+    ("Emoji_Modifier", "Emoji_Modifier", V9_0),
+    ("Emoji_Modifier_Base", "Emoji_Modifier_Base", V9_0),
+    ("Emoji_Presentation", "Emoji_Presentation", V9_0),
+    ("Ext", "Extender", V9_0),
+    ("Extender", "Extender", V9_0),
+    ("Gr_Base", "Grapheme_Base", V9_0),
+    ("Gr_Ext", "Grapheme_Extend", V9_0),
+    ("Grapheme_Base", "Grapheme_Base", V9_0),
+    ("Grapheme_Extend", "Grapheme_Extend", V9_0),
+    ("Hex", "Hex_Digit", V9_0),
+    ("Hex_Digit", "Hex_Digit", V9_0),
+    ("IDC", "ID_Continue", V9_0),
+    ("IDS", "ID_Start", V9_0),
+    ("IDSB", "IDS_Binary_Operator", V9_0),
+    ("IDST", "IDS_Trinary_Operator", V9_0),
+    ("IDS_Binary_Operator", "IDS_Binary_Operator", V9_0),
+    ("IDS_Trinary_Operator", "IDS_Trinary_Operator", V9_0),
+    ("ID_Continue", "ID_Continue", V9_0),
+    ("ID_Start", "ID_Start", V9_0),
+    ("Ideo", "Ideographic", V9_0),
+    ("Ideographic", "Ideographic", V9_0),
+    ("Join_C", "Join_Control", V9_0),
+    ("Join_Control", "Join_Control", V9_0),
+    ("LOE", "Logical_Order_Exception", V9_0),
+    ("Logical_Order_Exception", "Logical_Order_Exception", V9_0),
+    ("Lower", "Lowercase", V9_0),
+    ("Lowercase", "Lowercase", V9_0),
+    ("Math", "Math", V9_0),
+    ("NChar", "Noncharacter_Code_Point", V9_0),
+    ("Noncharacter_Code_Point", "Noncharacter_Code_Point", V9_0),
+    ("Pat_Syn", "Pattern_Syntax", V9_0),
+    ("Pat_WS", "Pattern_White_Space", V9_0),
+    ("Pattern_Syntax", "Pattern_Syntax", V9_0),
+    ("Pattern_White_Space", "Pattern_White_Space", V9_0),
+    ("QMark", "Quotation_Mark", V9_0),
+    ("Quotation_Mark", "Quotation_Mark", V9_0),
+    ("RI", "Regional_Indicator", V9_0),
+    ("Radical", "Radical", V9_0),
+    ("Regional_Indicator", "Regional_Indicator", V9_0),
+    ("SD", "Soft_Dotted", V9_0),
+    ("STerm", "Sentence_Terminal", V9_0),
+    ("Sentence_Terminal", "Sentence_Terminal", V9_0),
+    ("Soft_Dotted", "Soft_Dotted", V9_0),
+    ("Term", "Terminal_Punctuation", V9_0),
+    ("Terminal_Punctuation", "Terminal_Punctuation", V9_0),
+    ("UIdeo", "Unified_Ideograph", V9_0),
+    ("Unified_Ideograph", "Unified_Ideograph", V9_0),
+    ("Upper", "Uppercase", V9_0),
+    ("Uppercase", "Uppercase", V9_0),
+    ("VS", "Variation_Selector", V9_0),
+    ("Variation_Selector", "Variation_Selector", V9_0),
+    ("White_Space", "White_Space", V9_0),
+    ("XIDC", "XID_Continue", V9_0),
+    ("XIDS", "XID_Start", V9_0),
+    ("XID_Continue", "XID_Continue", V9_0),
+    ("XID_Start", "XID_Start", V9_0),
+    ("space", "White_Space", V9_0),
+];
+
+pub static STRING_PROPERTY_PATTERNS: &[(&str, &str, UnicodeVersion)] = &[
+    ("Basic_Emoji", "Basic_Emoji", V9_0),
+];
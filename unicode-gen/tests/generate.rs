@@ -0,0 +1,42 @@
+// Copyright (C) 2020 Quentin M. Kniep <hello@quentinkniep.com>
+// Distributed under terms of the MIT license.
+
+use std::fs;
+use std::path::Path;
+
+fn fixtures() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures"))
+}
+
+#[test]
+fn matches_expected_output() {
+    let files = unicode_gen::generate(&fixtures().join("ucd")).unwrap();
+    assert_eq!(files.len(), 2);
+    for (name, contents) in files {
+        let expected = fs::read_to_string(fixtures().join("expected").join(name)).unwrap();
+        assert_eq!(
+            contents, expected,
+            "{} differs from the expected output",
+            name
+        );
+    }
+}
+
+#[test]
+fn output_is_deterministic() {
+    let first = unicode_gen::generate(&fixtures().join("ucd")).unwrap();
+    let second = unicode_gen::generate(&fixtures().join("ucd")).unwrap();
+    assert_eq!(first, second);
+}
+
+#[test]
+fn missing_directory() {
+    assert!(unicode_gen::generate(&fixtures().join("missing")).is_err());
+    assert_eq!(
+        unicode_gen::generate(&fixtures().join("expected")),
+        Err(format!(
+            "No version directories found in {}",
+            fixtures().join("expected").display()
+        ))
+    );
+}